@@ -0,0 +1,51 @@
+//! Measures the throughput of decoding and encoding on inputs of typical shapes.
+//!
+//! Run with `cargo bench`. The benchmarks use the standard library only, so each one reports the
+//! median of several timed runs rather than a statistical analysis.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use unicode_escape::{decode, decode_lossy, encode};
+
+/// The number of timed runs of each benchmark.
+const RUNS: usize = 15;
+
+/// Runs `f` `RUNS` times and prints the median time and the throughput over `len` bytes.
+fn bench(name: &str, len: usize, mut f: impl FnMut()) {
+    f();
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let median = times[RUNS / 2];
+    let throughput = len as f64 / median.as_secs_f64() / (1 << 20) as f64;
+    println!("{name:24} {median:>12.2?} {throughput:>10.1} MiB/s");
+}
+
+fn main() {
+    let plain = "65480 LGM reading ok, sensor nominal ".repeat(30_000);
+    let sparse = format!("{plain}\\t");
+    let dense = r"\x02 65480\tLGM \u{21B5} caf\u{E9}\r\n".repeat(30_000);
+    let invalid = r"65480 \q LGM \xG0 ".repeat(30_000);
+    let text = "tab\there caf\u{E9} \u{1F600}\n".repeat(30_000);
+
+    bench("decode plain", plain.len(), || {
+        black_box(decode(black_box(&plain)).unwrap());
+    });
+    bench("decode sparse", sparse.len(), || {
+        black_box(decode(black_box(&sparse)).unwrap());
+    });
+    bench("decode dense", dense.len(), || {
+        black_box(decode(black_box(&dense)).unwrap());
+    });
+    bench("decode_lossy invalid", invalid.len(), || {
+        black_box(decode_lossy(black_box(&invalid)));
+    });
+    bench("encode", text.len(), || {
+        black_box(encode(black_box(&text)));
+    });
+}
@@ -0,0 +1,97 @@
+//! Decodes a shared sample through every dialect and prints where they differ from the default.
+//!
+//! Run it with `cargo run --example dialects`. The output is checked against
+//! `tests/snapshots/dialects.snap` by the snapshot tests, so it doubles as a regression test of
+//! the differences between the dialects.
+use unicode_escape::{decode_with, format_codepoint_notation, DecodeOptions, Dialect};
+
+/// The escaped strings decoded by every dialect, each showing off one difference.
+pub const SAMPLES: &[&str] = &[
+    r"\t\x41",
+    r"\x80",
+    r"\u{1F600}",
+    r"\u00E9",
+    r"\U0001F600",
+    r"\u{1_F600}",
+    r"\u{D83D}\u{DE00}",
+    r"\u{1F468 200D 1F4BB}",
+    r"\u{0000041}",
+    r"\/\b",
+    r"\uD83D\uDE00",
+    r"\101\q",
+    r"\x41BC\?",
+    r"\cA\e\u41",
+    r"\400\uD83Dx",
+    r"\0\101",
+    r"\u005cn\uu0041",
+    r"\65\z  \256",
+    r"it''s \'",
+];
+
+/// Returns the dialects to compare, by name. The first one is the reference for the others.
+pub fn dialects() -> Vec<(&'static str, DecodeOptions)> {
+    vec![
+        ("default", DecodeOptions::new()),
+        ("rustc", DecodeOptions::rustc_compat()),
+        (
+            "fixed-width",
+            DecodeOptions::new()
+                .braced_unicode(false)
+                .fixed_unicode(true)
+                .long_unicode(true),
+        ),
+        ("brace-groups", DecodeOptions::new().code_point_groups(true)),
+        ("json", DecodeOptions::dialect(Dialect::Json)),
+        ("python", DecodeOptions::dialect(Dialect::Python)),
+        ("c", DecodeOptions::dialect(Dialect::C)),
+        ("rust", DecodeOptions::dialect(Dialect::Rust)),
+        ("toml", DecodeOptions::dialect(Dialect::Toml)),
+        ("shell", DecodeOptions::dialect(Dialect::Shell)),
+        ("javascript", DecodeOptions::dialect(Dialect::JavaScript)),
+        ("go", DecodeOptions::dialect(Dialect::Go)),
+        ("java", DecodeOptions::dialect(Dialect::Java)),
+        ("csharp", DecodeOptions::dialect(Dialect::CSharp)),
+        ("lua", DecodeOptions::dialect(Dialect::Lua)),
+        ("postgresql", DecodeOptions::dialect(Dialect::PostgreSql)),
+        ("csv", DecodeOptions::dialect(Dialect::Csv)),
+        ("mysql", DecodeOptions::dialect(Dialect::MySql)),
+    ]
+}
+
+/// Describes the result of decoding a sample, e.g. `U+0041 U+0042` or `error: UnknownEscape('q')`.
+fn describe(input: &str, options: &DecodeOptions) -> String {
+    match decode_with(input, options) {
+        Ok(decoded) => {
+            let codes: Vec<String> = decoded.chars().map(format_codepoint_notation).collect();
+            codes.join(" ")
+        }
+        Err(error) => format!("error: {:?}", error.kind()),
+    }
+}
+
+/// Renders the results of every dialect for every sample, marking those that differ from the
+/// first dialect with `*`.
+pub fn report() -> String {
+    let dialects = dialects();
+    let width = dialects
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let mut report = String::new();
+    for sample in SAMPLES {
+        report.push_str(sample);
+        report.push('\n');
+        let reference = describe(sample, &dialects[0].1);
+        for (name, options) in &dialects {
+            let result = describe(sample, options);
+            let marker = if result == reference { ' ' } else { '*' };
+            report.push_str(&format!("  {marker} {name:width$}  {result}\n"));
+        }
+    }
+    report
+}
+
+pub fn main() {
+    print!("{}", report());
+}
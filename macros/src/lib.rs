@@ -0,0 +1,148 @@
+//! Decodes and escapes string literals at compile time.
+//!
+//! This crate contains the `decode!` and `escape!` procedural macros, which decode the escape
+//! sequences of a string literal like `unicode_escape::decode` and escape it like
+//! `unicode_escape::encode` while the crate using them is compiled. Both expand to a string
+//! literal, so they cost nothing at runtime, and an invalid escape sequence fails the build with
+//! an error at the literal that quotes the escape sequence and its byte offset.
+//!
+//! Unlike `unicode_escape::decode!`, which decodes in a const context, the macros report the
+//! position of an invalid escape sequence and encode as well, at the cost of a proc-macro
+//! dependency.
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use unicode_escape::{DecodeError, DecodeOptions, Dialect};
+
+/// Decodes a string literal like `unicode_escape::decode`, expanding to the decoded string
+/// literal.
+///
+/// The escape sequences are read from the value of the literal, so they are written as they are
+/// in a raw string literal such as `r"\x41"`, and with doubled backslashes in a string literal,
+/// such as `"\\x41"`. An invalid escape sequence fails the build.
+///
+/// ```
+/// const PROMPT: &str = unicode_escape_macros::decode!(r"\x02AT\u{B0}\r\n");
+/// assert_eq!(PROMPT, "\x02AT\u{B0}\r\n");
+/// ```
+///
+/// ```compile_fail
+/// // error: invalid escape sequence `\q` at byte 2 of the string: invalid escape '\q'
+/// //        help: write `\\` for a literal backslash
+/// const PROMPT: &str = unicode_escape_macros::decode!(r"AT\q");
+/// ```
+#[proc_macro]
+pub fn decode(input: TokenStream) -> TokenStream {
+    expand(input, |value| {
+        unicode_escape::decode(value).map_err(|error| describe(&error, value))
+    })
+}
+
+/// Escapes a string literal like `unicode_escape::encode`, expanding to the escaped string
+/// literal.
+///
+/// ```
+/// const SEPARATOR: &str = unicode_escape_macros::escape!("\x02AT\t|");
+/// assert_eq!(SEPARATOR, r"\x02AT\t|");
+/// ```
+#[proc_macro]
+pub fn escape(input: TokenStream) -> TokenStream {
+    expand(input, |value| Ok(unicode_escape::encode(value)))
+}
+
+/// Expands to the string literal `convert` turns the value of the string literal `input` into,
+/// or to a `compile_error!` at the literal with the message `convert` fails with.
+fn expand(input: TokenStream, convert: impl FnOnce(&str) -> Result<String, String>) -> TokenStream {
+    let (result, span) = match string_literal(input) {
+        Ok((value, span)) => (convert(&value), span),
+        Err((message, span)) => (Err(message), span),
+    };
+    match result {
+        Ok(output) => {
+            let mut literal = Literal::string(&output);
+            literal.set_span(span);
+            TokenTree::Literal(literal).into()
+        }
+        Err(message) => compile_error(&message, span),
+    }
+}
+
+/// Returns the value and the span of the single string literal in `input`, or an error message
+/// and the span to report it at if `input` is something else.
+fn string_literal(input: TokenStream) -> Result<(String, Span), (String, Span)> {
+    let mut tokens = input.into_iter();
+    let (token, rest) = (tokens.next(), tokens.next());
+    let token = match token {
+        // A literal passed through a `macro_rules!` fragment arrives in an invisible group
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::None => {
+            return string_literal(group.stream())
+        }
+        Some(token) => token,
+        None => return Err(("expected a string literal".to_string(), Span::call_site())),
+    };
+    if let Some(rest) = rest {
+        return Err(("expected a single string literal".to_string(), rest.span()));
+    }
+    let span = token.span();
+    let TokenTree::Literal(literal) = token else {
+        return Err(("expected a string literal".to_string(), span));
+    };
+    let source = literal.to_string();
+    // The body of a raw string literal is its value, that of a string literal is decoded the way
+    // rustc decodes it, which cannot fail for a literal rustc accepted
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw
+            .get(hashes + 1..raw.len() - hashes - 1)
+            .filter(|_| raw[hashes..].starts_with('"'));
+        if let Some(body) = body {
+            return Ok((body.to_string(), span));
+        }
+    } else if let Some(body) = source
+        .strip_prefix('"')
+        .and_then(|source| source.strip_suffix('"'))
+    {
+        let options = DecodeOptions::dialect(Dialect::Rust);
+        return unicode_escape::decode_with(body, &options)
+            .map(|value| (value, span))
+            .map_err(|error| (error.to_string(), span));
+    }
+    Err(("expected a string literal".to_string(), span))
+}
+
+/// Returns the message of a build failing on `error`, which decoding `value` failed with, quoting
+/// the invalid escape sequence and giving its byte offset, followed by the hint of
+/// `DecodeError::help` if there is one.
+fn describe(error: &DecodeError, value: &str) -> String {
+    let mut message = match error.span() {
+        Some(span) => format!(
+            "invalid escape sequence `{}` at byte {} of the string: {}",
+            &value[span.clone()],
+            span.start,
+            error.kind()
+        ),
+        None => error.kind().to_string(),
+    };
+    if let Some(help) = error.help(value) {
+        message.push_str("\nhelp: ");
+        message.push_str(help);
+    }
+    message
+}
+
+/// Returns the tokens of a `compile_error!` invocation with the given message, located at `span`.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let group = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+    let tokens: [TokenTree; 3] = [
+        Ident::new("compile_error", span).into(),
+        Punct::new('!', Spacing::Alone).into(),
+        group.into(),
+    ];
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
@@ -0,0 +1,29 @@
+use unicode_escape_macros::{decode, escape};
+
+#[test]
+fn test_decode_macro() {
+    const PROMPT: &str = decode!(r"\x02AT\u{B0}\r\n");
+    assert_eq!(PROMPT, "\x02AT\u{B0}\r\n");
+    assert_eq!(decode!(r#"say \"hi\""#), "say \"hi\"");
+    // The escape sequences of a string literal are read from its value
+    assert_eq!(decode!("\\u{1F600} \\\\"), "\u{1F600} \\");
+    assert_eq!(decode!("plain"), "plain");
+}
+
+#[test]
+fn test_escape_macro() {
+    const SEPARATOR: &str = escape!("\x02AT\t|");
+    assert_eq!(SEPARATOR, r"\x02AT\t|");
+    assert_eq!(escape!(r"C:\temp"), r"C:\\temp");
+    assert_eq!(decode!(r"caf\u{E9}\t|"), "caf\u{E9}\t|");
+}
+
+#[test]
+fn test_macros_in_macro_rules() {
+    macro_rules! decoded {
+        ($literal:literal) => {
+            decode!($literal)
+        };
+    }
+    assert_eq!(decoded!(r"\x41"), "A");
+}
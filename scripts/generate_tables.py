@@ -0,0 +1,372 @@
+#!/usr/bin/env python3
+"""Generates the Unicode data tables in `src/tables/` from the Unicode Character Database.
+
+Usage: scripts/generate_tables.py <ucd-directory> <unicode-version>
+
+The UCD directory must contain the files of https://www.unicode.org/Public/<version>/ucd/ that
+are read below. The generated tables are committed, so this only needs to be run when updating
+to a new Unicode version.
+"""
+import os
+import re
+import sys
+
+TABLES = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "src", "tables")
+
+
+def header(version):
+    return (
+        "# Generated by scripts/generate_tables.py from the Unicode Character Database "
+        f"{version}. Do not edit.\n"
+    )
+
+
+def rust_header(version):
+    return header(version).replace("#", "//", 1)
+
+
+def read_ucd(ucd, filename):
+    """Yields the semicolon separated fields of every data line of a UCD file."""
+    with open(os.path.join(ucd, filename), encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if line:
+                yield [field.strip() for field in line.split(";")]
+
+
+def range_label(label):
+    """Returns the name pattern of a `<..., First>` range of UnicodeData.txt, if it has names."""
+    if label.startswith("CJK Ideograph"):
+        return "CJK UNIFIED IDEOGRAPH-*"
+    if label.startswith("Tangut Ideograph"):
+        return "TANGUT IDEOGRAPH-*"
+    if label == "Hangul Syllable":
+        return "HANGUL SYLLABLE *"
+    return None
+
+
+def generate_names(ucd, version):
+    # Entries are (first, last, name). Ranges use `*` as a placeholder for the part of the name
+    # that is derived from the code point, as described in section 4.8 of the Unicode Standard.
+    entries = []
+    first = None
+    for fields in read_ucd(ucd, "UnicodeData.txt"):
+        cp, name = int(fields[0], 16), fields[1]
+        if name.endswith(", First>"):
+            first = cp
+        elif name.endswith(", Last>"):
+            pattern = range_label(name[1 : -len(", Last>")])
+            if pattern:
+                entries.append((first, cp, pattern))
+        elif not name.startswith("<"):
+            derived = re.fullmatch(r"(.*-)([0-9A-F]{4,6})", name)
+            if derived and int(derived.group(2), 16) == cp:
+                pattern = derived.group(1) + "*"
+                if entries and entries[-1][2] == pattern and entries[-1][1] == cp - 1:
+                    entries[-1] = (entries[-1][0], cp, pattern)
+                    continue
+                entries.append((cp, cp, pattern))
+            else:
+                entries.append((cp, cp, name))
+
+    with open(os.path.join(TABLES, "names.txt"), "w", encoding="utf-8") as out:
+        out.write(header(version))
+        for first, last, name in entries:
+            if first == last and "*" not in name:
+                out.write(f"{first:04X} {name}\n")
+            else:
+                out.write(f"{first:04X}..{last:04X} {name}\n")
+
+    with open(os.path.join(TABLES, "name_aliases.txt"), "w", encoding="utf-8") as out:
+        out.write(header(version))
+        for cp, alias, kind in read_ucd(ucd, "NameAliases.txt"):
+            out.write(f"{cp} {kind} {alias}\n")
+
+
+def read_ranges(ucd, filename):
+    """Yields (first, last, value) for every line of a UCD property file."""
+    for fields in read_ucd(ucd, filename):
+        first, _, last = fields[0].partition("..")
+        yield int(first, 16), int(last or first, 16), fields[1]
+
+
+def merge_ranges(ranges):
+    """Sorts ranges and merges adjacent ranges with the same value."""
+    merged = []
+    for first, last, value in sorted(ranges):
+        if merged and merged[-1][2] == value and merged[-1][1] + 1 == first:
+            merged[-1] = (merged[-1][0], last, value)
+        else:
+            if merged and merged[-1][1] >= first:
+                sys.exit(f"overlapping ranges at {first:04X}")
+            merged.append((first, last, value))
+    return merged
+
+
+def write_rust_ranges(version, filename, const, value_type, ranges):
+    with open(os.path.join(TABLES, filename), "w", encoding="utf-8") as out:
+        out.write(rust_header(version))
+        out.write(f"const {const}: &[(u32, u32, {value_type})] = &[\n")
+        for first, last, value in ranges:
+            out.write(f"    (0x{first:04X}, 0x{last:04X}, {value_type}::{value}),\n")
+        out.write("];\n")
+
+
+def generate_grapheme_break(ucd, version):
+    ranges = [
+        (first, last, value.replace("_", ""))
+        for first, last, value in read_ranges(ucd, "auxiliary/GraphemeBreakProperty.txt")
+    ]
+    ranges += [
+        (first, last, "ExtendedPictographic")
+        for first, last, value in read_ranges(ucd, "emoji/emoji-data.txt")
+        if value == "Extended_Pictographic"
+    ]
+    write_rust_ranges(
+        version, "grapheme_break.rs", "GRAPHEME_BREAK", "GraphemeBreak", merge_ranges(ranges)
+    )
+
+
+def write_rust_table(out, const, row_type, rows):
+    out.write(f"\nconst {const}: &[{row_type}] = &[\n")
+    for row in rows:
+        out.write(f"    {row},\n")
+    out.write("];\n")
+
+
+def rust_string(text):
+    return '"' + text.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def hex_list(code_points):
+    return "&[" + ", ".join(f"0x{cp:04X}" for cp in code_points) + "]"
+
+
+def generate_normalization(ucd, version):
+    # Hangul syllables have no entries, they are decomposed and composed algorithmically
+    combining_classes = []
+    canonical = []
+    compatibility = []
+    for fields in read_ucd(ucd, "UnicodeData.txt"):
+        cp, combining_class, decomposition = int(fields[0], 16), int(fields[3]), fields[5]
+        if combining_class:
+            combining_classes.append((cp, cp, combining_class))
+        if decomposition.startswith("<"):
+            compatibility.append((cp, [int(d, 16) for d in decomposition.split()[1:]]))
+        elif decomposition:
+            canonical.append((cp, [int(d, 16) for d in decomposition.split()]))
+
+    excluded = set()
+    for first, last, value in read_ranges(ucd, "DerivedNormalizationProps.txt"):
+        if value == "Full_Composition_Exclusion":
+            excluded.update(range(first, last + 1))
+    compositions = sorted(
+        (parts[0], parts[1], cp)
+        for cp, parts in canonical
+        if len(parts) == 2 and cp not in excluded
+    )
+
+    with open(os.path.join(TABLES, "normalization.rs"), "w", encoding="utf-8") as out:
+        out.write(rust_header(version))
+        write_rust_table(
+            out,
+            "COMBINING_CLASS",
+            "(u32, u32, u8)",
+            (f"(0x{a:04X}, 0x{b:04X}, {v})" for a, b, v in merge_ranges(combining_classes)),
+        )
+        write_rust_table(
+            out,
+            "CANONICAL_DECOMPOSITION",
+            "(u32, &[u32])",
+            (f"(0x{cp:04X}, {hex_list(parts)})" for cp, parts in canonical),
+        )
+        write_rust_table(
+            out,
+            "COMPATIBILITY_DECOMPOSITION",
+            "(u32, &[u32])",
+            (f"(0x{cp:04X}, {hex_list(parts)})" for cp, parts in compatibility),
+        )
+        write_rust_table(
+            out,
+            "COMPOSITION",
+            "(u32, u32, u32)",
+            (f"(0x{a:04X}, 0x{b:04X}, 0x{cp:04X})" for a, b, cp in compositions),
+        )
+
+
+def generate_age(ucd, version):
+    ranges = [
+        (first, last, tuple(int(part) for part in value.split(".")))
+        for first, last, value in read_ranges(ucd, "DerivedAge.txt")
+    ]
+    major, minor = (int(part) for part in version.split(".")[:2])
+    with open(os.path.join(TABLES, "age.rs"), "w", encoding="utf-8") as out:
+        out.write(rust_header(version))
+        out.write(f"\nconst UNICODE_VERSION: (u8, u8) = ({major}, {minor});\n")
+        write_rust_table(
+            out,
+            "AGE",
+            "(u32, u32, (u8, u8))",
+            (f"(0x{a:04X}, 0x{b:04X}, ({v[0]}, {v[1]}))" for a, b, v in merge_ranges(ranges)),
+        )
+
+
+def generate_general_category(ucd, version):
+    # Unlisted code points are unassigned, which the lookup handles as `Cn`
+    ranges = []
+    first = None
+    for fields in read_ucd(ucd, "UnicodeData.txt"):
+        cp, name, category = int(fields[0], 16), fields[1], fields[2]
+        if name.endswith(", First>"):
+            first = cp
+        elif name.endswith(", Last>"):
+            ranges.append((first, cp, category))
+        else:
+            ranges.append((cp, cp, category))
+    write_rust_ranges(
+        version,
+        "general_category.rs",
+        "GENERAL_CATEGORY",
+        "GeneralCategory",
+        merge_ranges(ranges),
+    )
+
+
+def generate_case_folding(ucd, version):
+    # Rows are (code point, simple folding, full folding), where an empty full folding means
+    # that it is the same as the simple one. Turkic foldings (status T) are not included.
+    simple = {}
+    full = {}
+    for fields in read_ucd(ucd, "CaseFolding.txt"):
+        cp, status, mapping = int(fields[0], 16), fields[1], fields[2]
+        mapping = [int(part, 16) for part in mapping.split()]
+        if status in ("C", "S"):
+            simple[cp] = mapping[0]
+        if status == "F":
+            full[cp] = mapping
+    with open(os.path.join(TABLES, "case_folding.rs"), "w", encoding="utf-8") as out:
+        out.write(rust_header(version))
+        write_rust_table(
+            out,
+            "CASE_FOLDING",
+            "(u32, u32, &[u32])",
+            (
+                f"(0x{cp:04X}, 0x{simple.get(cp, cp):04X}, {hex_list(full.get(cp, []))})"
+                for cp in sorted(simple.keys() | full.keys())
+            ),
+        )
+
+
+# Transliterations that cannot be derived from decompositions or character names
+TRANSLITERATION_OVERRIDES = {
+    0x00A1: "!", 0x00A2: "c", 0x00A3: "GBP", 0x00A5: "JPY", 0x00A9: "(C)", 0x00AB: "<<",
+    0x00AE: "(R)", 0x00B1: "+/-", 0x00B7: ".", 0x00BB: ">>", 0x00BF: "?", 0x00D0: "D",
+    0x00D7: "x", 0x00DE: "Th", 0x00DF: "ss", 0x00F0: "d", 0x00F7: "/", 0x00FE: "th",
+    0x1E9E: "SS", 0x2010: "-", 0x2011: "-", 0x2012: "-", 0x2013: "-", 0x2014: "--",
+    0x2015: "--", 0x2018: "'", 0x2019: "'", 0x201A: "'", 0x201B: "'", 0x201C: '"',
+    0x201D: '"', 0x201E: '"', 0x201F: '"', 0x2022: "*", 0x2039: "<", 0x203A: ">",
+    0x20AC: "EUR", 0x2212: "-",
+}
+
+GREEK_LETTERS = {
+    "ALPHA": "a", "BETA": "b", "GAMMA": "g", "DELTA": "d", "EPSILON": "e", "ZETA": "z",
+    "ETA": "e", "THETA": "th", "IOTA": "i", "KAPPA": "k", "LAMDA": "l", "MU": "m", "NU": "n",
+    "XI": "x", "OMICRON": "o", "PI": "p", "RHO": "r", "SIGMA": "s", "FINAL SIGMA": "s",
+    "TAU": "t", "UPSILON": "y", "PHI": "ph", "CHI": "kh", "PSI": "ps", "OMEGA": "o",
+}
+
+CYRILLIC_LETTERS = {
+    "A": "a", "BE": "b", "VE": "v", "GHE": "g", "DE": "d", "IE": "e", "ZHE": "zh", "ZE": "z",
+    "I": "i", "SHORT I": "y", "KA": "k", "EL": "l", "EM": "m", "EN": "n", "O": "o", "PE": "p",
+    "ER": "r", "ES": "s", "TE": "t", "U": "u", "EF": "f", "HA": "kh", "TSE": "ts", "CHE": "ch",
+    "SHA": "sh", "SHCHA": "shch", "HARD SIGN": "", "YERU": "y", "SOFT SIGN": "", "E": "e",
+    "YU": "yu", "YA": "ya", "IO": "yo", "UKRAINIAN IE": "ye", "BYELORUSSIAN-UKRAINIAN I": "i",
+    "YI": "yi", "JE": "j", "LJE": "lj", "NJE": "nj", "DZE": "dz", "DZHE": "dzh", "SHORT U": "u",
+    "TSHE": "c", "DJE": "d", "GHE WITH UPTURN": "g",
+}
+
+LATIN_PREFIXES = ("SMALL CAPITAL ", "TURNED ", "REVERSED ", "DOTLESS ", "SCRIPT ", "OPEN ")
+
+
+def transliterate_name(name):
+    """Returns the transliteration of a Latin, Greek or Cyrillic letter from its name."""
+    match = re.fullmatch(r"(LATIN|GREEK|CYRILLIC) (SMALL|CAPITAL) (?:LETTER|LIGATURE) (.*)", name)
+    if not match:
+        return None
+    script, case, letter = match.groups()
+    if script == "LATIN":
+        for prefix in LATIN_PREFIXES:
+            letter = letter.removeprefix(prefix)
+        letter = re.fullmatch(r"([A-Z]{1,2})(?: WITH .*)?", letter)
+        ascii = letter and letter.group(1).lower()
+        if ascii and case == "CAPITAL":
+            return ascii.upper()
+    else:
+        letters = GREEK_LETTERS if script == "GREEK" else CYRILLIC_LETTERS
+        ascii = letters.get(letter) or letters.get(letter.split(" WITH ")[0])
+        if ascii is None and letter in letters:
+            ascii = ""
+    if ascii is None:
+        return None
+    return ascii.capitalize() if case == "CAPITAL" else ascii
+
+
+def generate_transliteration(ucd, version):
+    names = {}
+    categories = {}
+    decompositions = {}
+    combining = set()
+    for fields in read_ucd(ucd, "UnicodeData.txt"):
+        cp = int(fields[0], 16)
+        names[cp], categories[cp] = fields[1], fields[2]
+        if fields[3] != "0":
+            combining.add(cp)
+        if fields[5]:
+            decompositions[cp] = [int(part, 16) for part in fields[5].split() if part[0] != "<"]
+
+    def decompose(cp):
+        if cp not in decompositions:
+            return [cp]
+        return [part for code in decompositions[cp] for part in decompose(code)]
+
+    entries = []
+    for cp in sorted(names):
+        if cp < 0x80 or names[cp].startswith("<"):
+            continue
+        ascii = TRANSLITERATION_OVERRIDES.get(cp)
+        if ascii is None:
+            parts = [part for part in decompose(cp) if part not in combining]
+            if parts and all(0x20 <= part < 0x7F for part in parts):
+                ascii = "".join(map(chr, parts))
+                if not ascii.strip() and categories[cp] != "Zs":
+                    ascii = None
+        if ascii is None:
+            ascii = transliterate_name(names[cp])
+        if ascii is not None:
+            entries.append((cp, ascii))
+
+    with open(os.path.join(TABLES, "transliteration.rs"), "w", encoding="utf-8") as out:
+        out.write(rust_header(version))
+        write_rust_table(
+            out,
+            "TRANSLITERATION",
+            "(u32, &str)",
+            (f"(0x{cp:04X}, {rust_string(ascii)})" for cp, ascii in entries),
+        )
+
+
+def main():
+    if len(sys.argv) != 3:
+        sys.exit(__doc__)
+    ucd, version = sys.argv[1:]
+    generate_names(ucd, version)
+    generate_grapheme_break(ucd, version)
+    generate_normalization(ucd, version)
+    generate_age(ucd, version)
+    generate_general_category(ucd, version)
+    generate_case_folding(ucd, version)
+    generate_transliteration(ucd, version)
+
+
+if __name__ == "__main__":
+    main()
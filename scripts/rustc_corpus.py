@@ -0,0 +1,95 @@
+#!/usr/bin/env python3
+"""Generates `tests/data/rustc_corpus.txt`, the expected results of decoding Rust string literals.
+
+Usage: scripts/rustc_corpus.py [<count>]
+
+Random string literal bodies are built from escape sequence fragments and compiled with `rustc`,
+which is the reference for `DecodeOptions::rustc_compat`. Every line of the corpus holds a
+literal body, a tab, and either the code points of the string rustc produces, in hexadecimal, or
+`error` if rustc rejects the literal. The corpus is committed, so this only needs to be run with
+a new Rust version or to extend the corpus.
+"""
+import os
+import random
+import re
+import subprocess
+import sys
+import tempfile
+
+CORPUS = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "tests", "data")
+
+FRAGMENTS = [
+    r"\n", r"\t", r"\r", r"\0", r"\\", r"\'", r"\"", r"\x", r"\x41", r"\x7F", r"\x7f", r"\x80",
+    r"\xFF", r"\x4", r"\xG1", r"\u", r"\u{", r"\u{41}", r"\u{e9}", r"\u{1F600}", r"\u{1_F600}",
+    r"\u{1__}", r"\u{_41}", r"\u{D800}", r"\u{DFFF}", r"\u{110000}", r"\u{10FFFF}",
+    r"\u{0000041}", r"\u{000041}", r"\u{}", r"\u{FFFFFFFFF}", r"\U0001F600", r"\a", r"\e",
+    r"\N{BULLET}", r"\q", r"\ ", "\\", "}", "{", "_", "0", "7", "F", "a", "u", "x", " ", "é",
+    "\U0001F600",
+]
+
+
+def is_literal_body(body):
+    """Checks that a string does not end the literal it is placed in before its end."""
+    escaped = False
+    for c in body:
+        if escaped:
+            escaped = False
+        elif c == "\\":
+            escaped = True
+        elif c == '"':
+            return False
+    return not escaped
+
+
+def main():
+    count = int(sys.argv[1]) if len(sys.argv) > 1 else 3000
+    rng = random.Random(234)
+    bodies = set()
+    while len(bodies) < count:
+        body = "".join(rng.choice(FRAGMENTS) for _ in range(rng.randint(1, 5)))
+        if is_literal_body(body):
+            bodies.add(body)
+    bodies = sorted(bodies)
+
+    with tempfile.TemporaryDirectory() as tmp:
+        # Find the literals rustc rejects, one literal per line
+        source = os.path.join(tmp, "check.rs")
+        with open(source, "w", encoding="utf-8") as out:
+            out.write("fn main() {\n")
+            for body in bodies:
+                out.write(f'    let _ = "{body}";\n')
+            out.write("}\n")
+        result = subprocess.run(
+            ["rustc", "--error-format=short", "--emit=metadata", "-o", os.path.join(tmp, "x"),
+             source],
+            capture_output=True,
+            text=True,
+        )
+        rejected = {int(line) - 2 for line in re.findall(r"check\.rs:(\d+):\d+: error", result.stderr)}
+
+        # Print the code points of the accepted ones
+        source = os.path.join(tmp, "print.rs")
+        accepted = [body for i, body in enumerate(bodies) if i not in rejected]
+        with open(source, "w", encoding="utf-8") as out:
+            out.write("fn main() {\n    for s in [\n")
+            for body in accepted:
+                out.write(f'        "{body}",\n')
+            out.write("    ] {\n")
+            out.write('        let codes: Vec<String> = s.chars().map(|c| format!("{:X}", c as u32)).collect();\n')
+            out.write('        println!("{}", codes.join(" "));\n')
+            out.write("    }\n}\n")
+        binary = os.path.join(tmp, "print")
+        subprocess.run(["rustc", "-o", binary, source], check=True)
+        decoded = subprocess.run([binary], capture_output=True, text=True, check=True).stdout
+        decoded = dict(zip(accepted, decoded.splitlines()))
+
+    os.makedirs(CORPUS, exist_ok=True)
+    with open(os.path.join(CORPUS, "rustc_corpus.txt"), "w", encoding="utf-8") as out:
+        version = subprocess.run(["rustc", "--version"], capture_output=True, text=True).stdout
+        out.write(f"# Generated by scripts/rustc_corpus.py with {version.strip()}. Do not edit.\n")
+        for body in bodies:
+            out.write(f"{body}\t{decoded.get(body, 'error')}\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,81 @@
+//! Looks up the Unicode version in which code points were assigned.
+//!
+//! This module is only available with the `unicode-age` feature, which embeds the Age property of
+//! the Unicode Character Database. The feature also enables `DecodeOptions::assigned_only`, which
+//! rejects escape sequences producing code points that are unassigned in a given Unicode version.
+use std::fmt;
+
+include!("tables/age.rs");
+
+/// A version of the Unicode Standard, e.g. 14.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl UnicodeVersion {
+    /// The Unicode version of the embedded tables, and the latest version `age` knows about.
+    pub const LATEST: UnicodeVersion = UnicodeVersion::new(UNICODE_VERSION.0, UNICODE_VERSION.1);
+
+    /// Creates a Unicode version from its major and minor version numbers.
+    pub const fn new(major: u8, minor: u8) -> UnicodeVersion {
+        UnicodeVersion { major, minor }
+    }
+
+    /// Returns the major version number.
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    /// Returns the minor version number.
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+}
+
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Returns the Unicode version in which a character was assigned.
+///
+/// Noncharacters, surrogates and private use characters count as assigned, in the version that
+/// designated them.
+///
+/// # Parameters
+///
+/// * `c`: The character to look up.
+///
+/// # Returns
+///
+/// The version that assigned the character, or `None` if it is unassigned in
+/// `UnicodeVersion::LATEST`.
+pub fn age(c: char) -> Option<UnicodeVersion> {
+    let code = u32::from(c);
+    let index = AGE.partition_point(|&(_, last, _)| last < code);
+    match AGE.get(index) {
+        Some(&(first, _, (major, minor))) if first <= code => {
+            Some(UnicodeVersion::new(major, minor))
+        }
+        _ => None,
+    }
+}
+
+/// Checks whether a character is assigned in the given Unicode version.
+///
+/// Versions newer than `UnicodeVersion::LATEST` are treated like `UnicodeVersion::LATEST`.
+///
+/// # Parameters
+///
+/// * `c`: The character to check.
+/// * `version`: The Unicode version to check against.
+///
+/// # Returns
+///
+/// `true` if the character was assigned in `version` or earlier, `false` otherwise.
+pub fn is_assigned(c: char, version: UnicodeVersion) -> bool {
+    age(c).is_some_and(|age| age <= version)
+}
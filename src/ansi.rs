@@ -0,0 +1,279 @@
+//! Detects, strips and tokenizes ANSI terminal escape sequences in decoded text.
+//!
+//! Text decoded from device logs and terminal captures often contains escape sequences such as
+//! `\x1b[1;32m` that color or position the output. This module splits such text into plain runs
+//! and `AnsiSequence`s with `ansi_tokens`, and removes the sequences with `strip_ansi`.
+//!
+//! The following sequences are recognized, in their 7-bit form introduced by ESC (U+001B) as well
+//! as their 8-bit form introduced by a C1 control character:
+//!
+//! * Control Sequence Introducer (CSI) sequences, e.g. `\x1b[2J`, and among them the Select
+//!   Graphic Rendition (SGR) sequences ending in `m`, e.g. `\x1b[1;32m`,
+//! * Operating System Commands (OSC), e.g. `\x1b]0;title\x07`,
+//! * the control strings DCS, SOS, PM and APC, which end like OSC with a String Terminator,
+//! * every other escape sequence, e.g. `\x1b7` or `\x1b(B`.
+//!
+//! A sequence that is cut off by the end of the text extends to the end of the text, so that
+//! `strip_ansi` never leaves a partial sequence behind.
+use std::ops::Range;
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+/// The 8-bit Control Sequence Introducer.
+const CSI: char = '\u{9B}';
+/// The 8-bit Operating System Command.
+const OSC: char = '\u{9D}';
+/// The 8-bit String Terminator.
+const ST: char = '\u{9C}';
+
+/// The type of an ANSI escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiKind {
+    /// A control sequence other than SGR, e.g. `\x1b[2J`.
+    Csi,
+    /// A Select Graphic Rendition control sequence setting colors and styles, e.g. `\x1b[1;32m`.
+    Sgr,
+    /// An Operating System Command, e.g. `\x1b]0;title\x07`.
+    Osc,
+    /// A Device Control String, Start of String, Privacy Message or Application Program Command.
+    ControlString,
+    /// Any other escape sequence, e.g. `\x1b7`, or an ESC that does not start a valid one.
+    Escape,
+}
+
+/// An ANSI escape sequence found in a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnsiSequence<'a> {
+    /// The type of the sequence.
+    pub kind: AnsiKind,
+    /// The byte range of the sequence in the text.
+    pub range: Range<usize>,
+    /// The text of the sequence, including its introducer and terminator.
+    pub source: &'a str,
+}
+
+impl<'a> AnsiSequence<'a> {
+    /// Returns the numeric parameters of a CSI or SGR sequence, e.g. `[1, 32]` for `\x1b[1;32m`.
+    ///
+    /// Empty parameters count as 0, so `\x1b[m` has the single parameter 0 like `\x1b[0m`.
+    /// Subparameters separated by colons, as in `\x1b[38:5:196m`, are returned as separate
+    /// parameters. Parameters too large for a `u16` are clamped.
+    ///
+    /// # Returns
+    ///
+    /// The parameters, or an empty vector for any other kind of sequence.
+    pub fn parameters(&self) -> Vec<u16> {
+        if !matches!(self.kind, AnsiKind::Csi | AnsiKind::Sgr) {
+            return Vec::new();
+        }
+        let body = self.body();
+        let end = body
+            .find(|c: char| !matches!(c, '0'..='9' | ';' | ':'))
+            .unwrap_or(body.len());
+        body[..end]
+            .split([';', ':'])
+            .map(|parameter| {
+                parameter.bytes().fold(0u16, |value, b| {
+                    value.saturating_mul(10).saturating_add(u16::from(b - b'0'))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the payload of an OSC sequence or control string, e.g. `0;title` for
+    /// `\x1b]0;title\x07`.
+    ///
+    /// # Returns
+    ///
+    /// The text between the introducer and the terminator, or an empty string for any other kind
+    /// of sequence.
+    pub fn payload(&self) -> &'a str {
+        if !matches!(self.kind, AnsiKind::Osc | AnsiKind::ControlString) {
+            return "";
+        }
+        let body = self.body();
+        for terminator in ["\x1b\\", "\x07", "\u{9C}"] {
+            if let Some(payload) = body.strip_suffix(terminator) {
+                return payload;
+            }
+        }
+        body
+    }
+
+    /// Returns the sequence without its introducer.
+    fn body(&self) -> &'a str {
+        let mut chars = self.source.chars();
+        if chars.next() == Some(ESC) {
+            chars.next();
+        }
+        chars.as_str()
+    }
+}
+
+/// A piece of text, either a run of plain text or an ANSI escape sequence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnsiToken<'a> {
+    /// A run of text without any escape sequences.
+    Text(&'a str),
+    /// An escape sequence.
+    Sequence(AnsiSequence<'a>),
+}
+
+/// An iterator over the plain text runs and ANSI escape sequences of a string.
+///
+/// This struct is created by `ansi_tokens`.
+#[derive(Debug, Clone)]
+pub struct AnsiTokens<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+/// Checks whether a character starts an ANSI escape sequence.
+fn is_introducer(c: char) -> bool {
+    matches!(
+        c,
+        ESC | CSI | OSC | '\u{90}' | '\u{98}' | '\u{9E}' | '\u{9F}'
+    )
+}
+
+/// Returns the kind and length of the control sequence following a CSI introducer, up to and
+/// including its final byte.
+fn csi(rest: &str) -> (AnsiKind, usize) {
+    // Parameter bytes, then intermediate bytes, then a single final byte
+    let params = rest
+        .find(|c: char| !('\x30'..='\x3f').contains(&c))
+        .unwrap_or(rest.len());
+    let intermediates = rest[params..]
+        .find(|c: char| !('\x20'..='\x2f').contains(&c))
+        .map_or(rest.len(), |i| params + i);
+    match rest[intermediates..].chars().next() {
+        Some('m') => (AnsiKind::Sgr, intermediates + 1),
+        Some('\x40'..='\x7e') => (AnsiKind::Csi, intermediates + 1),
+        // A sequence interrupted by any other character ends before it
+        _ => (AnsiKind::Csi, intermediates),
+    }
+}
+
+/// Returns the length of a control string following its introducer, up to and including its
+/// terminator. OSC may also be terminated by BEL.
+fn string_len(rest: &str, bel: bool) -> usize {
+    for (i, c) in rest.char_indices() {
+        match c {
+            BEL if bel => return i + 1,
+            ST => return i + c.len_utf8(),
+            ESC if rest[i + 1..].starts_with('\\') => return i + 2,
+            _ => {}
+        }
+    }
+    rest.len()
+}
+
+/// Returns the kind and length of the escape sequence at the start of `rest`, which starts with
+/// an introducer.
+fn sequence(rest: &str) -> (AnsiKind, usize) {
+    let mut chars = rest.chars();
+    let Some(introducer) = chars.next() else {
+        return (AnsiKind::Escape, 0);
+    };
+    let after = chars.as_str();
+    let (kind, len) = match introducer {
+        CSI => csi(after),
+        OSC => (AnsiKind::Osc, string_len(after, true)),
+        ESC => match after.chars().next() {
+            Some('[') => {
+                let (kind, len) = csi(&after[1..]);
+                (kind, 1 + len)
+            }
+            Some(']') => (AnsiKind::Osc, 1 + string_len(&after[1..], true)),
+            Some('P' | 'X' | '^' | '_') => {
+                (AnsiKind::ControlString, 1 + string_len(&after[1..], false))
+            }
+            _ => {
+                let intermediates = after
+                    .find(|c: char| !('\x20'..='\x2f').contains(&c))
+                    .unwrap_or(after.len());
+                match after[intermediates..].chars().next() {
+                    Some('\x30'..='\x7e') => (AnsiKind::Escape, intermediates + 1),
+                    // Not a valid escape sequence, only the ESC itself is taken
+                    _ => (AnsiKind::Escape, 0),
+                }
+            }
+        },
+        // The 8-bit DCS, SOS, PM and APC introducers
+        _ => (AnsiKind::ControlString, string_len(after, false)),
+    };
+    (kind, introducer.len_utf8() + len)
+}
+
+impl<'a> Iterator for AnsiTokens<'a> {
+    type Item = AnsiToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.pos..];
+        match rest.find(is_introducer) {
+            None if rest.is_empty() => None,
+            None => {
+                self.pos = self.text.len();
+                Some(AnsiToken::Text(rest))
+            }
+            Some(0) => {
+                let (kind, len) = sequence(rest);
+                let start = self.pos;
+                self.pos += len;
+                Some(AnsiToken::Sequence(AnsiSequence {
+                    kind,
+                    range: start..self.pos,
+                    source: &rest[..len],
+                }))
+            }
+            Some(offset) => {
+                self.pos += offset;
+                Some(AnsiToken::Text(&rest[..offset]))
+            }
+        }
+    }
+}
+
+/// Splits a string into plain text runs and ANSI escape sequences.
+///
+/// # Parameters
+///
+/// * `text`: The text to split, typically the output of `decode`.
+///
+/// # Returns
+///
+/// An iterator yielding the pieces of the text in order. Concatenating them yields the text.
+pub fn ansi_tokens(text: &str) -> AnsiTokens<'_> {
+    AnsiTokens { text, pos: 0 }
+}
+
+/// Removes every ANSI escape sequence from a string.
+///
+/// # Parameters
+///
+/// * `text`: The text to strip, typically the output of `decode`.
+///
+/// # Returns
+///
+/// The plain text runs of the text.
+pub fn strip_ansi(text: &str) -> String {
+    ansi_tokens(text)
+        .filter_map(|token| match token {
+            AnsiToken::Text(text) => Some(text),
+            AnsiToken::Sequence(_) => None,
+        })
+        .collect()
+}
+
+/// Checks whether a string contains any ANSI escape sequences.
+///
+/// # Parameters
+///
+/// * `text`: The text to check.
+///
+/// # Returns
+///
+/// `true` if `strip_ansi` would change the text, `false` otherwise.
+pub fn contains_ansi(text: &str) -> bool {
+    text.contains(is_introducer)
+}
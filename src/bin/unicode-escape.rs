@@ -0,0 +1,332 @@
+//! Decodes and encodes escape sequences in shell pipelines.
+//!
+//! `unicode-escape decode` decodes the escape sequences of its input, and `unicode-escape encode`
+//! escapes it, reading the given files in order, or standard input if there are none, and writing
+//! the result to standard output. Errors are reported on standard error, quoting the input around
+//! the invalid escape sequence, or with `--format json` as one JSON object per line for editors
+//! and CI jobs.
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use unicode_escape::{
+    decode_all_with, decode_lossy_with, decode_with, DecodeError, DecodeOptions, Dialect,
+    EscapingWriter, UnknownEscapePolicy,
+};
+
+const USAGE: &str = "\
+Usage: unicode-escape decode [--dialect NAME] [--errors POLICY] [--format FORMAT] [FILE...]
+       unicode-escape encode [FILE...]
+
+Decodes or escapes the escape sequences of the files, or of standard input
+if there are none or a file is `-`, and writes the result to standard output.
+
+Options:
+  --dialect NAME    The string literal syntax to decode: default, rustc, json,
+                    python, c, rust, toml, shell, javascript, go, java, csharp,
+                    lua, postgresql, csv or mysql. Defaults to `default`.
+  --errors POLICY   What happens to invalid escape sequences: `reject` them,
+                    failing the file, `replace` them with U+FFFD, or
+                    `preserve` unknown ones such as `\\q` and reject malformed
+                    ones such as `\\x4`. Defaults to `reject`.
+  --format FORMAT   How errors are reported on standard error: as `text`
+                    quoting the input, or as `json` with one object per line
+                    and every invalid escape sequence of a file, holding its
+                    `file`, byte `offset` and `end`, error `kind`, `message`
+                    and `snippet`. Defaults to `text`.
+  -h, --help        Prints this help.
+";
+
+/// What happens to invalid escape sequences when decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorPolicy {
+    Reject,
+    Replace,
+    Preserve,
+}
+
+/// How errors are reported on standard error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// The command selected by the arguments.
+#[derive(Debug)]
+enum Command {
+    Decode {
+        options: DecodeOptions,
+        errors: ErrorPolicy,
+        format: Format,
+        files: Vec<String>,
+    },
+    Encode {
+        files: Vec<String>,
+    },
+    Help,
+}
+
+/// Returns the options of the dialect with the given name.
+fn dialect(name: &str) -> Option<DecodeOptions> {
+    let dialect = match name {
+        "default" => return Some(DecodeOptions::new()),
+        "rustc" => return Some(DecodeOptions::rustc_compat()),
+        "json" => Dialect::Json,
+        "python" => Dialect::Python,
+        "c" => Dialect::C,
+        "rust" => Dialect::Rust,
+        "toml" => Dialect::Toml,
+        "shell" => Dialect::Shell,
+        "javascript" => Dialect::JavaScript,
+        "go" => Dialect::Go,
+        "java" => Dialect::Java,
+        "csharp" => Dialect::CSharp,
+        "lua" => Dialect::Lua,
+        "postgresql" => Dialect::PostgreSql,
+        "csv" => Dialect::Csv,
+        "mysql" => Dialect::MySql,
+        _ => return None,
+    };
+    Some(DecodeOptions::dialect(dialect))
+}
+
+/// Parses the command line arguments, without the name of the program.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = args.next().ok_or("missing command")?;
+    let mut options = DecodeOptions::new();
+    let mut errors = ErrorPolicy::Reject;
+    let mut format = Format::Text;
+    let mut files = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--dialect" if command == "decode" => {
+                let name = args.next().ok_or("missing dialect name")?;
+                options = dialect(&name).ok_or_else(|| format!("unknown dialect `{name}`"))?;
+            }
+            "--errors" if command == "decode" => {
+                errors = match args.next().as_deref() {
+                    Some("reject") => ErrorPolicy::Reject,
+                    Some("replace") => ErrorPolicy::Replace,
+                    Some("preserve") => ErrorPolicy::Preserve,
+                    Some(policy) => return Err(format!("unknown error policy `{policy}`")),
+                    None => return Err("missing error policy".to_string()),
+                };
+            }
+            "--format" if command == "decode" => {
+                format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(format) => return Err(format!("unknown format `{format}`")),
+                    None => return Err("missing format".to_string()),
+                };
+            }
+            "--" => files.extend(args.by_ref()),
+            option if option.starts_with('-') && option != "-" => {
+                return Err(format!("unknown option `{option}`"));
+            }
+            _ => files.push(arg),
+        }
+    }
+    if files.is_empty() {
+        files.push("-".to_string());
+    }
+    match command.as_str() {
+        "decode" => Ok(Command::Decode {
+            options,
+            errors,
+            format,
+            files,
+        }),
+        "encode" => Ok(Command::Encode { files }),
+        "-h" | "--help" | "help" => Ok(Command::Help),
+        _ => Err(format!("unknown command `{command}`")),
+    }
+}
+
+/// Reads the whole file at `path`, or standard input if it is `-`.
+fn read(path: &str) -> io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+    if path == "-" {
+        io::stdin().lock().read_to_end(&mut input)?;
+    } else {
+        input = fs::read(path)?;
+    }
+    Ok(input)
+}
+
+/// Appends `text` to `out` as a JSON string.
+fn push_json_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\0'..='\x1F' | '\u{7F}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Returns the JSON object reporting an error of `kind` in the file at `path`, with the start, end
+/// and input text of its location if it has one.
+fn json_diagnostic(
+    path: &str,
+    span: Option<(usize, usize, &str)>,
+    kind: &str,
+    message: &str,
+) -> String {
+    let mut out = String::from("{\"file\":");
+    push_json_string(&mut out, path);
+    match span {
+        Some((offset, end, snippet)) => {
+            out.push_str(&format!(",\"offset\":{offset},\"end\":{end},\"snippet\":"));
+            push_json_string(&mut out, snippet);
+        }
+        None => out.push_str(",\"offset\":null,\"end\":null,\"snippet\":null"),
+    }
+    out.push_str(",\"kind\":");
+    push_json_string(&mut out, kind);
+    out.push_str(",\"message\":");
+    push_json_string(&mut out, message);
+    out.push('}');
+    out
+}
+
+/// Reports the errors decoding the file at `path` fails with in the given format.
+fn report(format: Format, path: &str, input: &str, errors: &[DecodeError]) {
+    for error in errors {
+        match format {
+            Format::Text => eprint!("{path}: {}", error.render(input)),
+            Format::Json => {
+                let span = error.span().map(|span| {
+                    let snippet = input.get(span.clone()).unwrap_or_default();
+                    (span.start, span.end, snippet)
+                });
+                let message = error.kind().to_string();
+                eprintln!(
+                    "{}",
+                    json_diagnostic(path, span, error.kind().code(), &message)
+                );
+            }
+        }
+    }
+}
+
+/// Reports an error reading the file at `path` in the given format.
+fn report_read_error(format: Format, path: &str, span: Option<usize>, kind: &str, message: &str) {
+    match format {
+        Format::Text => eprintln!("{path}: {message}"),
+        Format::Json => {
+            let span = span.map(|offset| (offset, offset, ""));
+            eprintln!("{}", json_diagnostic(path, span, kind, message));
+        }
+    }
+}
+
+/// Decodes the files, returning whether all of them were decoded.
+///
+/// With `Format::Json`, every invalid escape sequence of a file is reported rather than the first.
+fn decode_files(
+    options: &DecodeOptions,
+    errors: ErrorPolicy,
+    format: Format,
+    files: &[String],
+) -> bool {
+    let options = match errors {
+        ErrorPolicy::Preserve => options
+            .clone()
+            .unknown_escapes(UnknownEscapePolicy::Preserve),
+        ErrorPolicy::Reject | ErrorPolicy::Replace => options.clone(),
+    };
+    let mut stdout = io::stdout().lock();
+    let mut success = true;
+    for path in files {
+        let input = match read(path).map(String::from_utf8) {
+            Ok(Ok(input)) => input,
+            Ok(Err(error)) => {
+                let error = error.utf8_error();
+                let offset = Some(error.valid_up_to());
+                report_read_error(format, path, offset, "InvalidUtf8", &error.to_string());
+                success = false;
+                continue;
+            }
+            Err(error) => {
+                report_read_error(format, path, None, "Io", &error.to_string());
+                success = false;
+                continue;
+            }
+        };
+        let decoded = match errors {
+            ErrorPolicy::Replace => decode_lossy_with(&input, &options, "\u{FFFD}"),
+            ErrorPolicy::Reject | ErrorPolicy::Preserve => {
+                let result = match format {
+                    Format::Text => decode_with(&input, &options).map_err(|error| vec![error]),
+                    Format::Json => decode_all_with(&input, &options),
+                };
+                match result {
+                    Ok(decoded) => decoded,
+                    Err(errors) => {
+                        report(format, path, &input, &errors);
+                        success = false;
+                        continue;
+                    }
+                }
+            }
+        };
+        if let Err(error) = stdout.write_all(decoded.as_bytes()) {
+            eprintln!("{error}");
+            return false;
+        }
+    }
+    success
+}
+
+/// Escapes the files, returning whether all of them could be read.
+fn encode_files(files: &[String]) -> bool {
+    let mut writer = EscapingWriter::new(io::stdout().lock());
+    let mut success = true;
+    for path in files {
+        // Bytes that are not valid UTF-8 are written as hexadecimal escape sequences
+        if let Err(error) = read(path).and_then(|input| writer.write_all(&input)) {
+            eprintln!("{path}: {error}");
+            success = false;
+        }
+    }
+    if let Err(error) = writer.finish() {
+        eprintln!("{error}");
+        return false;
+    }
+    success
+}
+
+fn main() -> ExitCode {
+    let success = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Decode {
+            options,
+            errors,
+            format,
+            files,
+        }) => decode_files(&options, errors, format, &files),
+        Ok(Command::Encode { files }) => encode_files(&files),
+        Ok(Command::Help) => {
+            print!("{USAGE}");
+            true
+        }
+        Err(message) => {
+            eprint!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
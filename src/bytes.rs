@@ -0,0 +1,92 @@
+//! Decodes escaped strings into raw bytes.
+//!
+//! Device protocols such as `\x02 65480\r\n` are byte streams rather than text, where `\xFF` is
+//! the single byte 0xFF and not the two-byte UTF-8 encoding of U+00FF. This module contains the
+//! `decode_bytes` function, which decodes such strings into a byte vector, and the
+//! `decode_bytes_to` function, which streams the bytes into a writer instead.
+use std::io::{self, Write};
+
+use crate::parser::{Escape, Token, Tokens};
+use crate::scan::EscapeKind;
+use crate::DecodeError;
+
+/// Decodes a string with escape sequences into bytes.
+///
+/// Hexadecimal escape sequences produce the byte they denote, e.g. `\xFF` the byte 0xFF. Every
+/// other escape sequence and all literal text produce the UTF-8 encoding of their characters,
+/// e.g. `\u{FF}` the bytes 0xC3 0xBF.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Result` containing the decoded bytes, or an error if an escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode`.
+pub fn decode_bytes(input: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut result = Vec::with_capacity(input.len());
+    for token in Tokens::new(input) {
+        match token? {
+            Token::Literal(text) => result.extend_from_slice(text.as_bytes()),
+            Token::Escape(escape) => push_escape(&mut result, &escape),
+        }
+    }
+    Ok(result)
+}
+
+/// The number of decoded bytes `decode_bytes_to` collects before writing them.
+const CHUNK_SIZE: usize = 8192;
+
+/// Decodes a string with escape sequences into bytes like `decode_bytes`, writing them into a
+/// writer as the input is read.
+///
+/// The bytes are written in chunks of a few kilobytes, and literal runs longer than that directly
+/// from the input, so decoding large payloads does not hold a copy of them in memory. If an escape
+/// sequence is invalid, the bytes before it may have been written already.
+///
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `out`: The writer receiving the decoded bytes.
+///
+/// # Returns
+///
+/// `Ok(())` if the whole input was decoded and written.
+///
+/// # Errors
+///
+/// This function returns the errors of the writer, and an error of kind
+/// `io::ErrorKind::InvalidData` wrapping the `DecodeError` of `decode_bytes` if an escape sequence
+/// is invalid.
+pub fn decode_bytes_to(input: &str, out: &mut impl Write) -> io::Result<()> {
+    let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+    for token in Tokens::new(input) {
+        match token.map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))? {
+            Token::Literal(text) if text.len() >= CHUNK_SIZE => {
+                out.write_all(&chunk)?;
+                chunk.clear();
+                out.write_all(text.as_bytes())?;
+            }
+            Token::Literal(text) => chunk.extend_from_slice(text.as_bytes()),
+            Token::Escape(escape) => push_escape(&mut chunk, &escape),
+        }
+        if chunk.len() >= CHUNK_SIZE {
+            out.write_all(&chunk)?;
+            chunk.clear();
+        }
+    }
+    out.write_all(&chunk)
+}
+
+/// Appends the bytes an escape sequence decodes to.
+fn push_escape(out: &mut Vec<u8>, escape: &Escape) {
+    match escape.kind {
+        // Hexadecimal escape sequences decode to U+0000 to U+00FF, one for every byte value
+        EscapeKind::Hex => out.push(escape.value as u8),
+        _ => out.extend_from_slice(escape.value.encode_utf8(&mut [0; 4]).as_bytes()),
+    }
+}
@@ -0,0 +1,48 @@
+//! Folds the case of decoded text.
+//!
+//! This module contains the `fold_case` function, which `decode_with` applies to the decoded
+//! string when `DecodeOptions::case_folding` is set, so that identifiers can be decoded and made
+//! caseless in one pass. The folding tables in `src/tables/` are generated by
+//! `scripts/generate_tables.py`.
+
+include!("tables/case_folding.rs");
+
+/// A kind of Unicode case folding, as defined by `CaseFolding.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseFolding {
+    /// Simple case folding, which maps every character to a single character and preserves the
+    /// length of the text in characters, e.g. `ß` stays `ß`.
+    Simple,
+    /// Full case folding, which may map a character to several ones, e.g. `ß` to `ss`.
+    Full,
+}
+
+/// Folds the case of a string.
+///
+/// Turkic foldings are not applied, so `I` folds to `i` and `İ` to `i̇`.
+///
+/// # Parameters
+///
+/// * `text`: The text to fold, typically a decoded string.
+/// * `folding`: The kind of case folding to apply.
+///
+/// # Returns
+///
+/// The case folded string.
+pub fn fold_case(text: &str, folding: CaseFolding) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        let code = u32::from(c);
+        let Ok(index) = CASE_FOLDING.binary_search_by_key(&code, |&(code, _, _)| code) else {
+            result.push(c);
+            continue;
+        };
+        let (_, simple, full) = CASE_FOLDING[index];
+        if folding == CaseFolding::Full && !full.is_empty() {
+            result.extend(full.iter().filter_map(|&code| char::from_u32(code)));
+        } else {
+            result.extend(char::from_u32(simple));
+        }
+    }
+    result
+}
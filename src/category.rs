@@ -0,0 +1,180 @@
+//! Looks up the General_Category of characters.
+//!
+//! This module is only available with the `unicode-categories` feature, which embeds the
+//! General_Category property of the Unicode Character Database. The feature also enables
+//! `EncodeOptions::escape_categories`, which escapes every character of a `CategorySet`, e.g. all
+//! control and format characters with `CategorySet::parse("Cc Cf")`.
+
+/// The General_Category of a character, named by its abbreviation as in `\p{Lu}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// Uppercase_Letter
+    Lu,
+    /// Lowercase_Letter
+    Ll,
+    /// Titlecase_Letter
+    Lt,
+    /// Modifier_Letter
+    Lm,
+    /// Other_Letter
+    Lo,
+    /// Nonspacing_Mark
+    Mn,
+    /// Spacing_Mark
+    Mc,
+    /// Enclosing_Mark
+    Me,
+    /// Decimal_Number
+    Nd,
+    /// Letter_Number
+    Nl,
+    /// Other_Number
+    No,
+    /// Connector_Punctuation
+    Pc,
+    /// Dash_Punctuation
+    Pd,
+    /// Open_Punctuation
+    Ps,
+    /// Close_Punctuation
+    Pe,
+    /// Initial_Punctuation
+    Pi,
+    /// Final_Punctuation
+    Pf,
+    /// Other_Punctuation
+    Po,
+    /// Math_Symbol
+    Sm,
+    /// Currency_Symbol
+    Sc,
+    /// Modifier_Symbol
+    Sk,
+    /// Other_Symbol
+    So,
+    /// Space_Separator
+    Zs,
+    /// Line_Separator
+    Zl,
+    /// Paragraph_Separator
+    Zp,
+    /// Control
+    Cc,
+    /// Format
+    Cf,
+    /// Surrogate
+    Cs,
+    /// Private_Use
+    Co,
+    /// Unassigned
+    Cn,
+}
+
+include!("tables/general_category.rs");
+
+const ALL: [GeneralCategory; 30] = {
+    use GeneralCategory::*;
+    [
+        Lu, Ll, Lt, Lm, Lo, Mn, Mc, Me, Nd, Nl, No, Pc, Pd, Ps, Pe, Pi, Pf, Po, Sm, Sc, Sk, So, Zs,
+        Zl, Zp, Cc, Cf, Cs, Co, Cn,
+    ]
+};
+
+impl GeneralCategory {
+    /// Returns the abbreviation of the category, e.g. `Cc`.
+    pub fn abbreviation(self) -> &'static str {
+        const ABBREVIATIONS: [&str; 30] = [
+            "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps",
+            "Pe", "Pi", "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs",
+            "Co", "Cn",
+        ];
+        ABBREVIATIONS[self as usize]
+    }
+}
+
+/// Returns the General_Category of a character.
+///
+/// # Parameters
+///
+/// * `c`: The character to look up.
+///
+/// # Returns
+///
+/// The category of the character, `GeneralCategory::Cn` if it is unassigned.
+pub fn general_category(c: char) -> GeneralCategory {
+    let code = u32::from(c);
+    let index = GENERAL_CATEGORY.partition_point(|&(_, last, _)| last < code);
+    match GENERAL_CATEGORY.get(index) {
+        Some(&(first, _, value)) if first <= code => value,
+        _ => GeneralCategory::Cn,
+    }
+}
+
+/// A set of general categories.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CategorySet {
+    bits: u32,
+}
+
+impl CategorySet {
+    /// Creates an empty set.
+    pub const fn new() -> CategorySet {
+        CategorySet { bits: 0 }
+    }
+
+    /// Returns the set with the given category added.
+    pub const fn with(self, category: GeneralCategory) -> CategorySet {
+        CategorySet {
+            bits: self.bits | 1 << category as u32,
+        }
+    }
+
+    /// Parses a list of category abbreviations separated by spaces or commas, e.g. `Cc Cf Zl Zp`.
+    ///
+    /// A single letter selects all categories of a group, e.g. `C` for `Cc Cf Cs Co Cn`, and
+    /// every abbreviation may be written as `\p{...}`.
+    ///
+    /// # Parameters
+    ///
+    /// * `spec`: The list of categories.
+    ///
+    /// # Returns
+    ///
+    /// The set of the listed categories, or `None` if the list contains an unknown category.
+    pub fn parse(spec: &str) -> Option<CategorySet> {
+        let mut set = CategorySet::new();
+        for item in spec.split([' ', ',']).filter(|item| !item.is_empty()) {
+            let item = item
+                .strip_prefix(r"\p{")
+                .and_then(|item| item.strip_suffix('}'))
+                .unwrap_or(item);
+            let mut found = false;
+            for category in ALL {
+                let abbreviation = category.abbreviation();
+                if abbreviation == item || (item.len() == 1 && abbreviation.starts_with(item)) {
+                    set = set.with(category);
+                    found = true;
+                }
+            }
+            if !found {
+                return None;
+            }
+        }
+        Some(set)
+    }
+
+    /// Checks whether the set contains a category.
+    pub fn contains(&self, category: GeneralCategory) -> bool {
+        self.bits & 1 << category as u32 != 0
+    }
+
+    /// Checks whether the set contains the category of a character.
+    pub fn contains_char(&self, c: char) -> bool {
+        self.bits != 0 && self.contains(general_category(c))
+    }
+
+    /// Checks whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
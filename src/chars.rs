@@ -0,0 +1,68 @@
+//! Decodes strings lazily, one character at a time.
+//!
+//! This module contains the `decode_chars` function and the `DecodeChars` iterator it returns,
+//! for callers that only inspect or count the decoded characters and do not need them collected
+//! into a `String`.
+use std::iter::FusedIterator;
+use std::str::Chars;
+
+use crate::parser::{Token, Tokens};
+use crate::DecodeError;
+
+/// An iterator over the decoded characters of a string.
+///
+/// This struct is created by `decode_chars`.
+#[derive(Debug, Clone)]
+pub struct DecodeChars<'a> {
+    tokens: Tokens<'a>,
+    /// The remaining characters of the current literal run.
+    literal: Chars<'a>,
+    /// Whether an error has been yielded, which ends the iteration.
+    failed: bool,
+}
+
+impl Iterator for DecodeChars<'_> {
+    type Item = Result<char, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.literal.next() {
+            return Some(Ok(c));
+        }
+        if self.failed {
+            return None;
+        }
+        match self.tokens.next()? {
+            Ok(Token::Literal(text)) => {
+                self.literal = text.chars();
+                self.literal.next().map(Ok)
+            }
+            Ok(Token::Escape(escape)) => Some(Ok(escape.value)),
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl FusedIterator for DecodeChars<'_> {}
+
+/// Decodes a string with escape sequences lazily, like `decode`.
+///
+/// The input is only decoded as far as the iterator is advanced, and nothing is allocated.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// An iterator yielding the decoded characters in order. If an escape sequence is invalid, the
+/// iterator yields the error `decode` fails with and then ends.
+pub fn decode_chars(input: &str) -> DecodeChars<'_> {
+    DecodeChars {
+        tokens: Tokens::new(input),
+        literal: "".chars(),
+        failed: false,
+    }
+}
@@ -0,0 +1,296 @@
+//! Decodes escaped strings at compile time.
+//!
+//! This module contains `ConstDecoded`, a string of at most `N` bytes that is decoded by a
+//! `const fn`, so that the escaped constants of a protocol definition are decoded while the crate
+//! is compiled instead of at startup. A constant with an invalid escape sequence fails the build.
+//! The `decode!` macro wraps it for string literals.
+use crate::{DecodeError, ErrorKind};
+
+/// A string decoded in a const context, stored inline in a buffer of `N` bytes.
+///
+/// The escape sequences of `decode` are decoded, except named ones such as `\N{BULLET}`: the
+/// simple escape sequences of `SimpleEscapes::DEFAULT`, `\x` with two hexadecimal digits, and
+/// `\u{...}` without combining surrogate pairs, like `decode` does.
+///
+/// ```
+/// use unicode_escape::ConstDecoded;
+///
+/// const PROMPT: ConstDecoded<8> = ConstDecoded::new(r"\x02AT\r\n");
+/// assert_eq!(PROMPT.as_str(), "\x02AT\r\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstDecoded<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ConstDecoded<N> {
+    /// Decodes a string like `decode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an escape sequence is invalid or the decoded string is longer than `N` bytes,
+    /// which fails the build in a const context.
+    pub const fn new(input: &str) -> ConstDecoded<N> {
+        match ConstDecoded::try_new(input) {
+            Ok(decoded) => decoded,
+            Err(error) => panic!("{}", message(error.kind())),
+        }
+    }
+
+    /// Decodes a string like `decode`, returning the error instead of panicking.
+    ///
+    /// # Parameters
+    ///
+    /// * `input`: A string slice or raw string slice
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the decoded string, or the error `decode` fails with, or
+    /// `ErrorKind::BufferTooSmall` if the decoded string is longer than `N` bytes.
+    pub const fn try_new(input: &str) -> Result<ConstDecoded<N>, DecodeError> {
+        let input = input.as_bytes();
+        let mut decoded = ConstDecoded {
+            bytes: [0; N],
+            len: 0,
+        };
+        let mut pos = 0;
+        while pos < input.len() {
+            let start = pos;
+            let (c, len) = if input[pos] == b'\\' {
+                match decode_escape(input, pos) {
+                    Ok(escape) => escape,
+                    Err(kind) => return Err(error_at(input, start, kind)),
+                }
+            } else {
+                char_at(input, pos)
+            };
+            pos += len;
+            let mut utf8 = [0; 4];
+            let utf8 = c.encode_utf8(&mut utf8).as_bytes();
+            if decoded.len + utf8.len() > N {
+                return Err(DecodeError::spanned(ErrorKind::BufferTooSmall, start..pos));
+            }
+            let mut i = 0;
+            while i < utf8.len() {
+                decoded.bytes[decoded.len + i] = utf8[i];
+                i += 1;
+            }
+            decoded.len += utf8.len();
+        }
+        Ok(decoded)
+    }
+
+    /// Returns the decoded string.
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.as_bytes()) {
+            Ok(text) => text,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the UTF-8 encoding of the decoded string.
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    /// Returns the length of the decoded string in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the decoded string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Returns the error of kind `kind` for the escape sequence whose backslash is at `start`, which
+/// covers the backslash, the character following it and the digits or braced body of the rest
+/// like the errors of `decode`.
+const fn error_at(input: &[u8], start: usize, kind: ErrorKind) -> DecodeError {
+    if start + 1 == input.len() {
+        return DecodeError::spanned(kind, start..input.len());
+    }
+    let mut end = start + 1 + char_at(input, start + 1).1;
+    match input[start + 1] {
+        b'x' => {
+            while end < input.len() && end < start + 4 && input[end].is_ascii_hexdigit() {
+                end += 1;
+            }
+        }
+        introducer @ (b'u' | b'N') if end < input.len() && input[end] == b'{' => {
+            end += 1;
+            while end < input.len() {
+                let byte = input[end];
+                let name = introducer == b'N' && (byte == b' ' || byte == b'-');
+                if !(byte.is_ascii_alphanumeric() || byte == b'_' || name) {
+                    break;
+                }
+                end += 1;
+            }
+            if end < input.len() && input[end] == b'}' {
+                end += 1;
+            }
+        }
+        _ => {}
+    }
+    DecodeError::spanned(kind, start..end)
+}
+
+/// Returns the character starting at byte `pos` of the valid UTF-8 `input` and its length.
+const fn char_at(input: &[u8], pos: usize) -> (char, usize) {
+    let first = input[pos] as u32;
+    let (mut code, len) = match first {
+        0x00..=0x7F => (first, 1),
+        0xC0..=0xDF => (first & 0x1F, 2),
+        0xE0..=0xEF => (first & 0x0F, 3),
+        _ => (first & 0x07, 4),
+    };
+    let mut i = 1;
+    while i < len {
+        code = code << 6 | (input[pos + i] as u32 & 0x3F);
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) => (c, len),
+        None => unreachable!(),
+    }
+}
+
+/// Returns the value of the hexadecimal digit at byte `pos`, or the error of the character there.
+const fn hex_digit(input: &[u8], pos: usize) -> Result<u32, ErrorKind> {
+    if pos == input.len() {
+        return Err(ErrorKind::UnexpectedEof);
+    }
+    match input[pos] {
+        digit @ b'0'..=b'9' => Ok((digit - b'0') as u32),
+        digit @ b'a'..=b'f' => Ok((digit - b'a' + 10) as u32),
+        digit @ b'A'..=b'F' => Ok((digit - b'A' + 10) as u32),
+        _ => Err(ErrorKind::InvalidHexDigit(char_at(input, pos).0)),
+    }
+}
+
+/// Decodes the escape sequence whose backslash is at byte `pos`, returning the character and the
+/// length of the escape sequence.
+const fn decode_escape(input: &[u8], pos: usize) -> Result<(char, usize), ErrorKind> {
+    if pos + 1 == input.len() {
+        return Err(ErrorKind::UnexpectedEof);
+    }
+    let c = match input[pos + 1] {
+        b't' => '\t',
+        b'n' => '\n',
+        b'r' => '\r',
+        b'0' => '\0',
+        b'\\' => '\\',
+        b'"' => '"',
+        b'\'' => '\'',
+        b'x' => {
+            let high = match hex_digit(input, pos + 2) {
+                Ok(digit) => digit,
+                Err(kind) => return Err(kind),
+            };
+            let low = match hex_digit(input, pos + 3) {
+                Ok(digit) => digit,
+                Err(kind) => return Err(kind),
+            };
+            match char::from_u32(high << 4 | low) {
+                Some(c) => return Ok((c, 4)),
+                None => unreachable!(),
+            }
+        }
+        b'u' => return decode_unicode(input, pos),
+        _ => return Err(ErrorKind::UnknownEscape(char_at(input, pos + 1).0)),
+    };
+    Ok((c, 2))
+}
+
+/// Decodes the braced Unicode escape sequence whose backslash is at byte `pos`.
+const fn decode_unicode(input: &[u8], pos: usize) -> Result<(char, usize), ErrorKind> {
+    let (code, len) = match braced_code(input, pos) {
+        Ok(code) => code,
+        Err(kind) => return Err(kind),
+    };
+    match code {
+        0xD800..=0xDFFF => Err(ErrorKind::SurrogateCodePoint(code)),
+        _ => match char::from_u32(code) {
+            Some(c) => Ok((c, len)),
+            None => Err(ErrorKind::CodePointOutOfRange(code)),
+        },
+    }
+}
+
+/// Reads the code point of the `\u{...}` at byte `pos`, returning it and the length of the escape
+/// sequence. The errors are checked in the order `decode` checks them, so that values too large
+/// for a `u32` are reported as `ErrorKind::CodePointOutOfRange` with `u32::MAX` before a
+/// character following the digits that is not the closing brace.
+const fn braced_code(input: &[u8], pos: usize) -> Result<(u32, usize), ErrorKind> {
+    if pos + 2 > input.len() || input[pos] != b'\\' || input[pos + 1] != b'u' {
+        return Err(ErrorKind::UnexpectedEof);
+    }
+    let mut end = pos + 2;
+    if end == input.len() {
+        return Err(ErrorKind::UnexpectedEof);
+    }
+    if input[end] != b'{' {
+        return Err(ErrorKind::UnterminatedUnicode);
+    }
+    end += 1;
+    let mut code: u32 = 0;
+    let mut digits = 0;
+    let mut overflow = false;
+    while let Ok(digit) = hex_digit(input, end) {
+        match code.checked_mul(16) {
+            Some(shifted) => code = shifted | digit,
+            None => overflow = true,
+        }
+        digits += 1;
+        end += 1;
+    }
+    if digits == 0 && end < input.len() && input[end] == b'}' {
+        return Err(ErrorKind::EmptyUnicode);
+    }
+    if overflow {
+        return Err(ErrorKind::CodePointOutOfRange(u32::MAX));
+    }
+    if end == input.len() {
+        return Err(ErrorKind::UnterminatedUnicode);
+    }
+    if input[end] != b'}' {
+        return Err(ErrorKind::InvalidHexDigit(char_at(input, end).0));
+    }
+    Ok((code, end + 1 - pos))
+}
+
+/// Returns the message `ConstDecoded::new` panics with for an error of the given kind.
+const fn message(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::BufferTooSmall => "the decoded string does not fit into the buffer",
+        ErrorKind::UnexpectedEof => "unexpected end of input in escape sequence",
+        ErrorKind::UnknownEscape(_) => "invalid escape",
+        ErrorKind::InvalidHexDigit(_) => "invalid digit in escape sequence",
+        ErrorKind::UnterminatedUnicode => "unterminated Unicode escape",
+        ErrorKind::EmptyUnicode => "empty Unicode escape",
+        ErrorKind::CodePointOutOfRange(_) => "escape value out of range",
+        ErrorKind::SurrogateCodePoint(_) => "escape of a surrogate code point",
+        _ => "invalid escape sequence",
+    }
+}
+
+/// Decodes a string literal at compile time, expanding to the decoded `&'static str`.
+///
+/// The literal is decoded like `ConstDecoded::new` does, so an invalid escape sequence fails the
+/// build at the invocation of the macro, and the expansion can initialize constants of its own.
+///
+/// ```
+/// const PROMPT: &str = unicode_escape::decode!(r"\x02AT\u{B0}\r\n");
+/// assert_eq!(PROMPT, "\x02AT\u{B0}\r\n");
+/// ```
+#[macro_export]
+macro_rules! decode {
+    ($input:expr $(,)?) => {{
+        const INPUT: &str = $input;
+        const DECODED: &$crate::ConstDecoded<{ INPUT.len() }> = &$crate::ConstDecoded::new(INPUT);
+        DECODED.as_str()
+    }};
+}
@@ -0,0 +1,112 @@
+//! Pairs the escaped and decoded forms of a string.
+//!
+//! This module contains the `decode_diff` function, which splits the input into literal runs and
+//! escape sequences and pairs each escape sequence with the character it decodes to. Its `Display`
+//! output lists one segment per line, which makes it useful in test failure messages.
+use std::fmt;
+
+use crate::parser::{Token, Tokens};
+use crate::{DecodeError, EscapeKind};
+
+/// A single segment of a `Diff`.
+#[derive(Debug)]
+pub enum DiffSegment<'a> {
+    /// A run of literal text, which decodes to itself.
+    Literal(&'a str),
+    /// A valid escape sequence.
+    Escape {
+        /// The escape sequence as it appears in the input.
+        source: &'a str,
+        /// The family the escape sequence belongs to.
+        kind: EscapeKind,
+        /// The character the escape sequence decodes to.
+        decoded: char,
+    },
+    /// An invalid escape sequence.
+    Invalid {
+        /// The invalid escape sequence as it appears in the input.
+        source: &'a str,
+        /// The error decoding the escape sequence produced.
+        error: DecodeError,
+    },
+}
+
+/// The escaped and decoded forms of a string, paired segment by segment.
+///
+/// This struct is created by the `decode_diff` function.
+#[derive(Debug)]
+pub struct Diff<'a> {
+    segments: Vec<DiffSegment<'a>>,
+}
+
+impl<'a> Diff<'a> {
+    /// Returns the segments of the input, in input order.
+    pub fn segments(&self) -> &[DiffSegment<'a>] {
+        &self.segments
+    }
+
+    /// Returns `true` if the input contains no invalid escape sequences.
+    pub fn is_valid(&self) -> bool {
+        !self
+            .segments
+            .iter()
+            .any(|segment| matches!(segment, DiffSegment::Invalid { .. }))
+    }
+}
+
+impl fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                DiffSegment::Literal(text) => writeln!(f, "{:<8} {:?}", "literal", text)?,
+                DiffSegment::Escape {
+                    source,
+                    kind,
+                    decoded,
+                } => {
+                    let kind = format!("{:?}", kind).to_lowercase();
+                    writeln!(f, "{:<8} {} -> {:?}", kind, source, decoded)?
+                }
+                DiffSegment::Invalid { source, error } => {
+                    writeln!(f, "{:<8} {} -> {}", "invalid", source, error)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pairs every escape sequence of a string with the character it decodes to.
+///
+/// Unlike `decode`, this function does not stop at the first invalid escape sequence, so the
+/// result describes the whole input.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Diff` containing the literal runs and escape sequences of the input, in input order.
+pub fn decode_diff(input: &str) -> Diff<'_> {
+    let mut segments = Vec::new();
+    let mut tokens = Tokens::new(input);
+    loop {
+        let start = tokens.offset();
+        let segment = match tokens.next() {
+            None => break,
+            Some(Ok(Token::Literal(text))) => DiffSegment::Literal(text),
+            Some(Ok(Token::Escape(escape))) => DiffSegment::Escape {
+                source: &input[start..escape.end],
+                kind: escape.kind,
+                decoded: escape.value,
+            },
+            Some(Err(error)) => DiffSegment::Invalid {
+                source: &input[start..tokens.offset()],
+                error,
+            },
+        };
+        segments.push(segment);
+    }
+    Diff { segments }
+}
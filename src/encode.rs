@@ -0,0 +1,365 @@
+//! Encodes strings into their escaped form.
+//!
+//! This module contains the `encode` function, the counterpart of `decode`, and the
+//! `EncodeOptions` used to customise it through `encode_with`.
+use std::fmt::{self, Write};
+
+#[cfg(feature = "unicode-categories")]
+use crate::category::CategorySet;
+use crate::notation::format_codepoint_notation;
+
+/// How `encode_with` escapes emoji sequences, such as ZWJ sequences, flags and emoji with skin
+/// tone modifiers.
+///
+/// An emoji sequence is an extended grapheme cluster containing an Extended_Pictographic or a
+/// Regional_Indicator character. Requires the `unicode-segmentation` feature.
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EmojiSequences {
+    /// Escapes the characters of emoji sequences individually, like any other character, e.g.
+    /// `👨\u{200D}💻`.
+    #[default]
+    Scatter,
+    /// Keeps emoji sequences intact, without escaping any of their characters, e.g. `👨‍💻`.
+    Keep,
+    /// Escapes emoji sequences that contain a character needing escaping as a single brace group,
+    /// e.g. `\u{1F468 200D 1F4BB}`. Decoding the result requires
+    /// `DecodeOptions::code_point_groups`.
+    Group,
+}
+
+/// Options controlling how `encode_with` escapes characters.
+///
+/// The default options produce the same output as `encode`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EncodeOptions {
+    pub(crate) byte_order_mark: bool,
+    codepoint_notation: bool,
+    message_format: bool,
+    #[cfg(feature = "names")]
+    named_escapes: bool,
+    #[cfg(feature = "unicode-segmentation")]
+    emoji_sequences: EmojiSequences,
+    #[cfg(feature = "unicode-categories")]
+    escape_categories: CategorySet,
+    #[cfg(feature = "transliteration")]
+    transliterate: bool,
+}
+
+impl EncodeOptions {
+    /// Creates the default options.
+    pub fn new() -> EncodeOptions {
+        EncodeOptions::default()
+    }
+
+    /// Starts the output with a literal byte order mark, U+FEFF, for consumers that expect one.
+    /// A byte order mark in the input is still escaped.
+    pub fn byte_order_mark(mut self, byte_order_mark: bool) -> EncodeOptions {
+        self.byte_order_mark = byte_order_mark;
+        self
+    }
+
+    /// Writes the characters that would be escaped in `U+XXXX` notation, e.g. `U+200B`
+    /// for a zero width space, for pasting into specifications and bug reports. Backslashes and
+    /// quotes are copied unchanged. The output is meant for humans and cannot be decoded.
+    pub fn codepoint_notation(mut self, codepoint_notation: bool) -> EncodeOptions {
+        self.codepoint_notation = codepoint_notation;
+        self
+    }
+
+    /// Keeps the output usable as an ICU MessageFormat pattern: apostrophes, which quote literal
+    /// text in patterns, are copied unchanged, and Unicode escape sequences are written without
+    /// braces, e.g. `\u00E9` and `\U0001F600`, so that braces only appear in the placeholders
+    /// of the input. Named escape sequences and brace groups of emoji sequences are not used.
+    /// Decoding the output requires `DecodeOptions::fixed_unicode` and
+    /// `DecodeOptions::long_unicode`.
+    pub fn message_format(mut self, message_format: bool) -> EncodeOptions {
+        self.message_format = message_format;
+        self
+    }
+
+    /// Emits characters that have a Unicode name as named escape sequences, e.g. `\N{BULLET}`,
+    /// instead of hexadecimal or Unicode escape sequences. Characters with a simple escape
+    /// sequence keep using it. Requires the `names` feature.
+    #[cfg(feature = "names")]
+    pub fn named_escapes(mut self, named_escapes: bool) -> EncodeOptions {
+        self.named_escapes = named_escapes;
+        self
+    }
+
+    /// Sets how emoji sequences are escaped. Defaults to `EmojiSequences::Scatter`. Requires the
+    /// `unicode-segmentation` feature.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn emoji_sequences(mut self, emoji_sequences: EmojiSequences) -> EncodeOptions {
+        self.emoji_sequences = emoji_sequences;
+        self
+    }
+
+    /// Additionally escapes every character whose general category is in the set, e.g. all
+    /// format characters with `CategorySet::new().with(GeneralCategory::Cf)`. Requires the
+    /// `unicode-categories` feature.
+    #[cfg(feature = "unicode-categories")]
+    pub fn escape_categories(mut self, escape_categories: CategorySet) -> EncodeOptions {
+        self.escape_categories = escape_categories;
+        self
+    }
+
+    /// Replaces non-ASCII characters with their closest ASCII spelling, e.g. `Munchen` for
+    /// `München`, and escapes those that have none, so that the output is pure ASCII. The output
+    /// no longer decodes to the input. Requires the `transliteration` feature.
+    #[cfg(feature = "transliteration")]
+    pub fn transliterate(mut self, transliterate: bool) -> EncodeOptions {
+        self.transliterate = transliterate;
+        self
+    }
+
+    /// Checks whether a character has to be escaped with these options.
+    fn escapes(&self, c: char) -> bool {
+        #[cfg(feature = "transliteration")]
+        if self.transliterate && !c.is_ascii() {
+            return true;
+        }
+        #[cfg(feature = "unicode-categories")]
+        if self.escape_categories.contains_char(c) {
+            return true;
+        }
+        needs_escape(c)
+    }
+}
+
+/// Checks whether a character has to be escaped to survive being written as plain text.
+///
+/// This covers backslashes and quotes, control characters, the whitespace and formatting
+/// characters that are invisible or would change the layout of the text, including the
+/// bidirectional controls that could reorder it, and noncharacters, which are not meant to appear
+/// in text at all.
+fn needs_escape(c: char) -> bool {
+    match c {
+        '\\' | '"' | '\'' => true,
+        ' ' => false,
+        '\u{AD}'
+        | '\u{61C}'
+        | '\u{180E}'
+        | '\u{200B}'..='\u{200F}'
+        | '\u{202A}'..='\u{202E}'
+        | '\u{2060}'..='\u{2064}'
+        | '\u{2066}'..='\u{2069}'
+        | '\u{FDD0}'..='\u{FDEF}'
+        | '\u{FEFF}'
+        | '\u{FFF9}'..='\u{FFFB}' => true,
+        _ if c as u32 & 0xFFFE == 0xFFFE => true,
+        _ => c.is_control() || c.is_whitespace(),
+    }
+}
+
+/// Appends the preferred escaped spelling of a character to `out`.
+///
+/// This is the simple escape sequence if one exists, the character itself if it is printable
+/// ASCII, a hexadecimal escape sequence for the remaining ASCII characters and a Unicode escape
+/// sequence for everything else, always using uppercase hexadecimal digits.
+///
+/// `encode_canonical` guarantees this spelling, so changing it requires a new
+/// `CANONICAL_ENCODING_VERSION`.
+pub(crate) fn push_canonical(out: &mut String, c: char) {
+    // Writing into a `String` cannot fail
+    let _ = write_canonical(out, c);
+}
+
+/// Writes the spelling of `push_canonical` into any `fmt::Write` sink, without allocating.
+fn write_canonical(out: &mut impl fmt::Write, c: char) -> fmt::Result {
+    match c {
+        '\t' => out.write_str(r"\t"),
+        '\n' => out.write_str(r"\n"),
+        '\r' => out.write_str(r"\r"),
+        '\0' => out.write_str(r"\0"),
+        '\\' => out.write_str(r"\\"),
+        '"' => out.write_str(r#"\""#),
+        '\'' => out.write_str(r"\'"),
+        ' '..='~' => out.write_char(c),
+        '\0'..='\x7f' => write!(out, r"\x{:02X}", c as u32),
+        _ => write!(out, r"\u{{{:X}}}", c as u32),
+    }
+}
+
+/// Encodes a string by escaping the characters that cannot be written as plain text.
+///
+/// Backslashes, quotes, control characters, invisible formatting characters and noncharacters are
+/// replaced by escape sequences (e.g. '\n', '\x1B', '\u{200B}'), while all other characters,
+/// including printable non-ASCII characters, are copied unchanged. Decoding the result yields the
+/// input again.
+///
+/// # Parameters
+///
+/// * &str: The string to encode
+///
+/// # Returns
+///
+/// The escaped string.
+pub fn encode(input: &str) -> String {
+    encode_with(input, &EncodeOptions::default())
+}
+
+/// A string slice displayed in the escaped form `encode` produces.
+///
+/// Formatting writes the escaped text straight into the formatter, without building a `String`,
+/// e.g. for `println!("{}", Escaped(payload))` in logging code. `Debug` additionally encloses it
+/// in double quotes, which makes it a valid string literal since quotes are escaped.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Escaped<'a>(pub &'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Runs of characters that need no escaping are written as a whole
+        let mut start = 0;
+        for (i, c) in self.0.char_indices() {
+            if needs_escape(c) {
+                f.write_str(&self.0[start..i])?;
+                write_canonical(f, c)?;
+                start = i + c.len_utf8();
+            }
+        }
+        f.write_str(&self.0[start..])
+    }
+}
+
+impl fmt::Debug for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+/// The version of the output format of `encode_canonical`.
+///
+/// Version 1 writes every character of the input in the spelling of these rules:
+///
+/// * `\t`, `\n`, `\r`, `\0`, `\\`, `\"` and `\'` for the characters they stand for,
+/// * printable ASCII characters, U+0020 to U+007E, unchanged,
+/// * the remaining ASCII characters as `\xHH` with two uppercase hexadecimal digits,
+/// * every other character as `\u{H}` with uppercase hexadecimal digits and without leading
+///   zeros, e.g. `\u{E9}` and `\u{1F600}`.
+///
+/// The output of a version never changes; a release changing it increments the version.
+pub const CANONICAL_ENCODING_VERSION: u32 = 1;
+
+/// Encodes a string into a stable, canonical escaped form, for hashing or signing escaped
+/// strings and verifying them later.
+///
+/// Unlike `encode`, which leaves printable non-ASCII characters unchanged and consults the
+/// Unicode tables of the standard library to find invisible characters, this function escapes
+/// every character outside of printable ASCII. Its output only depends on the code points of the
+/// input, so it is byte-for-byte identical on every platform and in every release with the same
+/// `CANONICAL_ENCODING_VERSION`, and being pure ASCII, it cannot be altered by transcoding or
+/// Unicode normalization in transit. Decoding the result yields the input again.
+///
+/// # Parameters
+///
+/// * &str: The string to encode
+///
+/// # Returns
+///
+/// The escaped string, in the format of `CANONICAL_ENCODING_VERSION`.
+pub fn encode_canonical(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        push_canonical(&mut result, c);
+    }
+    result
+}
+
+/// Encodes a string like `encode`, using the given options.
+///
+/// # Parameters
+///
+/// * `input`: The string to encode.
+/// * `options`: The options controlling how characters are escaped.
+///
+/// # Returns
+///
+/// The escaped string.
+pub fn encode_with(input: &str, options: &EncodeOptions) -> String {
+    let mut result = String::with_capacity(input.len());
+    if options.byte_order_mark {
+        result.push('\u{FEFF}');
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    if options.emoji_sequences != EmojiSequences::Scatter {
+        let boundaries = crate::segmentation::grapheme_boundaries(input);
+        for (i, &start) in boundaries.iter().enumerate() {
+            let end = boundaries.get(i + 1).copied().unwrap_or(input.len());
+            let cluster = &input[start..end];
+            if !crate::segmentation::is_emoji_sequence(cluster) {
+                cluster
+                    .chars()
+                    .for_each(|c| push_encoded(&mut result, c, options));
+            } else if options.emoji_sequences == EmojiSequences::Keep
+                || !cluster.chars().any(|c| options.escapes(c))
+            {
+                result.push_str(cluster);
+            } else if options.message_format {
+                cluster
+                    .chars()
+                    .for_each(|c| push_encoded(&mut result, c, options));
+            } else {
+                let codes: Vec<String> =
+                    cluster.chars().map(|c| format!("{:X}", c as u32)).collect();
+                result.push_str(&format!(r"\u{{{}}}", codes.join(" ")));
+            }
+        }
+        return result;
+    }
+
+    for c in input.chars() {
+        push_encoded(&mut result, c, options);
+    }
+    result
+}
+
+/// Appends a character to `out`, escaping it if needed.
+pub(crate) fn push_encoded(out: &mut String, c: char, options: &EncodeOptions) {
+    if !options.escapes(c)
+        || (options.codepoint_notation && matches!(c, '\\' | '"' | '\''))
+        || (options.message_format && c == '\'')
+    {
+        out.push(c);
+        return;
+    }
+    #[cfg(feature = "transliteration")]
+    if options.transliterate {
+        if let Some(ascii) = crate::transliteration::transliterate(c) {
+            ascii.chars().for_each(|c| push_encoded(out, c, options));
+            return;
+        }
+    }
+    if options.codepoint_notation {
+        out.push_str(&format_codepoint_notation(c));
+        return;
+    }
+    #[cfg(feature = "names")]
+    if options.named_escapes
+        && !options.message_format
+        && !matches!(c, '\t' | '\n' | '\r' | '\0' | '\\' | '"' | '\'')
+    {
+        if let Some(name) = crate::names::char_name(c) {
+            out.push_str(r"\N{");
+            out.push_str(&name);
+            out.push('}');
+            return;
+        }
+    }
+    match c {
+        // Printable ASCII characters are only escaped when selected by their category
+        ' '..='~' if !matches!(c, '\\' | '"' | '\'') => {
+            let _ = write!(out, r"\x{:02X}", c as u32);
+        }
+        _ if options.message_format && !c.is_ascii() => {
+            // Writing to a String cannot fail
+            let _ = if c <= '\u{FFFF}' {
+                write!(out, r"\u{:04X}", c as u32)
+            } else {
+                write!(out, r"\U{:08X}", c as u32)
+            };
+        }
+        _ => push_canonical(out, c),
+    }
+}
@@ -1,25 +1,331 @@
 /// Defines error types and implementations for decoding escape sequences.
 ///
-/// This module contains the `DecodeError` enum and its associated implementations for displaying
-/// and handling decoding errors.
+/// This module contains the `DecodeError` struct, which tells why and where decoding failed, the
+/// `ErrorKind` enum of the reasons, and the `SpannedError` additionally quoting the input around
+/// the cause of a `DecodeError`.
 use std::error::Error;
 use std::fmt;
+use std::ops::Range;
+
+use crate::render;
 
 /// Represents the different types of errors that can occur during decoding.
-#[derive(Debug)]
-pub enum DecodeError {
-    /// Indicates an invalid escape sequence was encountered.
-    InvalidEscape,
-    /// Indicates an invalid hexadecimal character was encountered.
-    InvalidHexChar,
-    /// Indicates an invalid Unicode escape sequence was encountered.
-    InvalidUnicode,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Indicates the input ended inside an escape sequence, e.g. after a trailing backslash or in
+    /// `\x4`.
+    UnexpectedEof,
+    /// Indicates a backslash was followed by a character that does not start an escape sequence
+    /// the options accept, such as `q` in `\q`, or by `c` that is not followed by a control
+    /// character name.
+    UnknownEscape(char),
+    /// Indicates the given character appeared where an escape sequence requires a digit, e.g. `G`
+    /// in `\x4G` or `\u{4G}`.
+    InvalidHexDigit(char),
+    /// Indicates a Unicode escape sequence is missing an opening or closing brace, e.g. `\u41`
+    /// where braces are required, or `\u{41` at the end of the input.
+    UnterminatedUnicode,
+    /// Indicates a braced Unicode escape sequence without any digits, `\u{}`.
+    EmptyUnicode,
+    /// Indicates a braced Unicode escape sequence with more digits than the options accept, e.g.
+    /// `\u{0000041}` in Rust.
+    OverlongUnicode,
+    /// Indicates an escape sequence produced the given value, which is above U+10FFFF or beyond
+    /// the range the options accept, e.g. `\x80` in Rust. Values too large for a `u32` are
+    /// reported as `u32::MAX`.
+    CodePointOutOfRange(u32),
+    /// Indicates an escape sequence produced the given surrogate code point, which is not a
+    /// character, without forming a surrogate pair the options combine.
+    SurrogateCodePoint(u32),
+    /// Indicates a named Unicode escape sequence such as `\N{BULLET}` named no character.
+    UnknownCharacterName,
+    /// Indicates an escape sequence produced a noncharacter, such as U+FFFF, that the options
+    /// reject.
+    Noncharacter,
+    /// Indicates an escape sequence produced a code point that is unassigned in the Unicode
+    /// version the options require.
+    Unassigned,
+    /// Indicates the decoded string starts with a byte order mark, U+FEFF, that the options
+    /// reject.
+    ByteOrderMark,
+    /// Indicates a quote character that the options read written twice appeared on its own.
+    UnpairedQuote,
+    /// Indicates that the sink decoded text was written to failed, e.g. the formatter of
+    /// `decode_to`.
+    WriteFailed,
+    /// Indicates the decoded text did not fit into the buffer of `decode_into`.
+    BufferTooSmall,
+    /// Indicates a code point notation passed to `parse_codepoint_notation` did not start with
+    /// `U+`, e.g. `1F600`.
+    MissingNotationPrefix,
+}
+
+impl ErrorKind {
+    /// Returns the name of the kind without its field, e.g. `UnknownEscape` for
+    /// `ErrorKind::UnknownEscape('q')`, which is stable across releases, for reporting errors in a
+    /// machine readable form such as JSON.
+    pub fn code(self) -> &'static str {
+        match self {
+            ErrorKind::UnexpectedEof => "UnexpectedEof",
+            ErrorKind::UnknownEscape(_) => "UnknownEscape",
+            ErrorKind::InvalidHexDigit(_) => "InvalidHexDigit",
+            ErrorKind::UnterminatedUnicode => "UnterminatedUnicode",
+            ErrorKind::EmptyUnicode => "EmptyUnicode",
+            ErrorKind::OverlongUnicode => "OverlongUnicode",
+            ErrorKind::CodePointOutOfRange(_) => "CodePointOutOfRange",
+            ErrorKind::SurrogateCodePoint(_) => "SurrogateCodePoint",
+            ErrorKind::UnknownCharacterName => "UnknownCharacterName",
+            ErrorKind::Noncharacter => "Noncharacter",
+            ErrorKind::Unassigned => "Unassigned",
+            ErrorKind::ByteOrderMark => "ByteOrderMark",
+            ErrorKind::UnpairedQuote => "UnpairedQuote",
+            ErrorKind::WriteFailed => "WriteFailed",
+            ErrorKind::BufferTooSmall => "BufferTooSmall",
+            ErrorKind::MissingNotationPrefix => "MissingNotationPrefix",
+        }
+    }
+
+    /// Returns a hint on how to fix the input, for diagnostics such as those of
+    /// `DecodeError::render` or of a diagnostic reporting library, or `None` if there is no
+    /// general one. `UnterminatedUnicode` has none, since it depends on the escape sequence
+    /// whether the opening or the closing brace is missing; `DecodeError::help` tells them apart.
+    pub fn help(self) -> Option<&'static str> {
+        match self {
+            ErrorKind::UnexpectedEof => {
+                Some(r"complete the escape sequence, or write `\\` for a literal backslash")
+            }
+            ErrorKind::UnknownEscape(_) => Some(r"write `\\` for a literal backslash"),
+            ErrorKind::EmptyUnicode => {
+                Some(r"write the code point between the braces, e.g. `\u{41}`")
+            }
+            ErrorKind::OverlongUnicode => Some("remove the leading zeros of the code point"),
+            ErrorKind::CodePointOutOfRange(_) => {
+                Some("Unicode code points range from U+0000 to U+10FFFF")
+            }
+            ErrorKind::SurrogateCodePoint(_) => {
+                Some(r"escape the character instead of its surrogates, e.g. `\u{1F600}`")
+            }
+            ErrorKind::UnpairedQuote => Some("write the quote character twice"),
+            ErrorKind::MissingNotationPrefix => Some("write `U+` before the digits, e.g. `U+0041`"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::UnexpectedEof => write!(f, "unexpected end of input in escape sequence"),
+            ErrorKind::UnknownEscape(c) => write!(f, "invalid escape '\\{}'", c.escape_debug()),
+            ErrorKind::InvalidHexDigit(c) => {
+                write!(f, "invalid digit '{}' in escape sequence", c.escape_debug())
+            }
+            ErrorKind::UnterminatedUnicode => write!(f, "unterminated Unicode escape"),
+            ErrorKind::EmptyUnicode => write!(f, "empty Unicode escape"),
+            ErrorKind::OverlongUnicode => write!(f, "overlong Unicode escape"),
+            ErrorKind::CodePointOutOfRange(code) => {
+                write!(f, "escape value {code:#X} out of range")
+            }
+            ErrorKind::SurrogateCodePoint(code) => {
+                write!(f, "escape of the surrogate code point U+{code:04X}")
+            }
+            ErrorKind::UnknownCharacterName => write!(f, "unknown character name"),
+            ErrorKind::Noncharacter => write!(f, "escape of a noncharacter"),
+            ErrorKind::Unassigned => write!(f, "escape of an unassigned code point"),
+            ErrorKind::ByteOrderMark => write!(f, "the string starts with a byte order mark"),
+            ErrorKind::UnpairedQuote => write!(f, "unpaired quote"),
+            ErrorKind::WriteFailed => write!(f, "the decoded text could not be written"),
+            ErrorKind::BufferTooSmall => write!(f, "the decoded text does not fit into the buffer"),
+            ErrorKind::MissingNotationPrefix => write!(f, "code point notation without `U+`"),
+        }
+    }
+}
+
+/// An error that occurred during decoding, together with the position of its cause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    kind: ErrorKind,
+    span: Option<Range<usize>>,
+}
+
+impl DecodeError {
+    /// Creates an error of the given kind without a position, e.g. for an `EscapeHandler`
+    /// rejecting an escape sequence. The decoding function returning it adds the position.
+    pub fn new(kind: ErrorKind) -> DecodeError {
+        DecodeError { kind, span: None }
+    }
+
+    /// Creates an error of the given kind located at `span`, in const contexts.
+    pub(crate) const fn spanned(kind: ErrorKind, span: Range<usize>) -> DecodeError {
+        DecodeError {
+            kind,
+            span: Some(span),
+        }
+    }
+
+    /// Returns the reason decoding failed.
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns a hint on how to fix the escape sequence of `source` this error concerns, like
+    /// `ErrorKind::help`, but also for an `ErrorKind::UnterminatedUnicode`, telling to add the
+    /// closing brace of `\u{12` and to enclose the code point of `\u12` in braces.
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The input whose decoding failed with this error.
+    pub fn help(&self, source: &str) -> Option<&'static str> {
+        match self.kind {
+            ErrorKind::UnterminatedUnicode => {
+                let braced = self
+                    .span
+                    .clone()
+                    .and_then(|span| source.get(span))
+                    .is_some_and(|escape| escape.contains('{'));
+                Some(if braced {
+                    "add the closing `}`"
+                } else {
+                    r"enclose the code point in braces, e.g. `\u{41}`"
+                })
+            }
+            kind => kind.help(),
+        }
+    }
+
+    /// Returns the byte range of the invalid escape sequence in the input, or `None` if the error
+    /// does not concern a part of the input, such as `ErrorKind::WriteFailed`. Errors concerning
+    /// the whole string, such as `ErrorKind::ByteOrderMark`, have an empty span at the start.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Returns the byte offset of the invalid escape sequence in the input, if any.
+    pub fn offset(&self) -> Option<usize> {
+        self.span.as_ref().map(|span| span.start)
+    }
+
+    /// Renders the error as a compiler style diagnostic, quoting the line of the input it occurred
+    /// in and underlining the invalid escape sequence with carets, like `render_report`, followed
+    /// by the hint of `help` if there is one.
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The input whose decoding failed with this error.
+    ///
+    /// # Returns
+    ///
+    /// The diagnostic, ending with a newline. Errors without a span within `source` are rendered
+    /// as their message alone.
+    pub fn render(&self, source: &str) -> String {
+        render::render_error(self, source)
+    }
+
+    /// Locates the error at `span` unless it already has a position.
+    pub(crate) fn at(mut self, span: Range<usize>) -> DecodeError {
+        self.span.get_or_insert(span);
+        self
+    }
+
+    /// Moves the position of the error `offset` bytes further, e.g. from a part of the input to
+    /// the whole input.
+    pub(crate) fn shifted(mut self, offset: usize) -> DecodeError {
+        if let Some(span) = &mut self.span {
+            *span = span.start + offset..span.end + offset;
+        }
+        self
+    }
+}
+
+impl From<ErrorKind> for DecodeError {
+    fn from(kind: ErrorKind) -> DecodeError {
+        DecodeError::new(kind)
+    }
 }
 
 impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self.offset() {
+            Some(offset) => write!(f, "{} at byte {offset}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
     }
 }
 
 impl Error for DecodeError {}
+
+/// A `DecodeError` together with the position of the escape sequence that caused it.
+///
+/// This error is returned by `decode_spanned` and `validate_spanned`.
+#[derive(Debug)]
+pub struct SpannedError {
+    /// The reason decoding failed.
+    pub error: DecodeError,
+    /// The byte range of the invalid escape sequence in the input. Errors concerning the whole
+    /// string, such as `ErrorKind::ByteOrderMark`, have an empty span at the start.
+    pub span: Range<usize>,
+    /// The index of the character the invalid escape sequence starts at, counted in characters
+    /// of the input, e.g. for placing a cursor in an editor.
+    pub char_index: usize,
+    /// The input around the invalid escape sequence, at most `CONTEXT_CHARS` characters on
+    /// either side, with `...` where it was cut off and control characters escaped.
+    pub context: String,
+}
+
+/// The number of characters `SpannedError::context` keeps on either side of the span.
+const CONTEXT_CHARS: usize = 16;
+
+impl SpannedError {
+    /// Creates the error for `error` caused by the escape sequence at `span` in `input`.
+    pub(crate) fn new(error: DecodeError, input: &str, span: Range<usize>) -> SpannedError {
+        let before = input[..span.start]
+            .char_indices()
+            .rev()
+            .nth(CONTEXT_CHARS - 1);
+        let after = input[span.end..].char_indices().nth(CONTEXT_CHARS);
+        let start = before.map_or(0, |(i, _)| i);
+        let end = after.map_or(input.len(), |(i, _)| span.end + i);
+        let mut context = String::new();
+        if start > 0 {
+            context.push_str("...");
+        }
+        for c in input[start..end].chars() {
+            if c.is_control() {
+                context.extend(c.escape_default());
+            } else {
+                context.push(c);
+            }
+        }
+        if end < input.len() {
+            context.push_str("...");
+        }
+        SpannedError {
+            error: error.at(span.clone()),
+            char_index: input[..span.start].chars().count(),
+            span,
+            context,
+        }
+    }
+
+    /// Returns the byte offset of the invalid escape sequence in the input.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+}
+
+impl fmt::Display for SpannedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} in \"{}\"",
+            self.error.kind, self.span.start, self.context
+        )
+    }
+}
+
+impl Error for SpannedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,85 @@
+//! Exposes the entry points of the crate as methods of string slices.
+//!
+//! This module contains the `StrExt` extension trait, which lets callers write
+//! `raw.decode_escapes()?` and `text.escape_sequences()` instead of calling the free functions.
+//! Every method forwards to the function of the same behavior, so the methods and the functions
+//! can be mixed freely. Since `String` dereferences to `str`, the methods work on both.
+use std::borrow::Cow;
+
+use crate::{
+    decode, decode_cow, decode_lossy, decode_with, encode, encode_with, validate, DecodeError,
+    DecodeOptions, EncodeOptions, Escaped,
+};
+
+/// Methods decoding, validating and encoding the escape sequences of a string slice.
+///
+/// The trait is implemented for `str` only and is sealed, so that methods can be added to it
+/// without breaking downstream crates.
+pub trait StrExt: private::Sealed {
+    /// Decodes the escape sequences of the string, like `decode`.
+    fn decode_escapes(&self) -> Result<String, DecodeError>;
+
+    /// Decodes the escape sequences of the string with the given options, like `decode_with`.
+    fn decode_escapes_with(&self, options: &DecodeOptions) -> Result<String, DecodeError>;
+
+    /// Decodes the escape sequences of the string, borrowing it if it contains none, like
+    /// `decode_cow`.
+    fn decode_escapes_cow(&self) -> Result<Cow<'_, str>, DecodeError>;
+
+    /// Decodes the escape sequences of the string, replacing invalid ones with U+FFFD, like
+    /// `decode_lossy`.
+    fn decode_escapes_lossy(&self) -> String;
+
+    /// Checks that every escape sequence of the string is valid, like `validate`.
+    fn validate_escapes(&self) -> Result<(), DecodeError>;
+
+    /// Escapes the characters of the string that cannot be written as plain text, like `encode`.
+    fn escape_sequences(&self) -> String;
+
+    /// Escapes the string with the given options, like `encode_with`.
+    fn escape_sequences_with(&self, options: &EncodeOptions) -> String;
+
+    /// Returns an `Escaped` displaying the string in the escaped form of `escape_sequences`,
+    /// without allocating.
+    fn escaped(&self) -> Escaped<'_>;
+}
+
+impl StrExt for str {
+    fn decode_escapes(&self) -> Result<String, DecodeError> {
+        decode(self)
+    }
+
+    fn decode_escapes_with(&self, options: &DecodeOptions) -> Result<String, DecodeError> {
+        decode_with(self, options)
+    }
+
+    fn decode_escapes_cow(&self) -> Result<Cow<'_, str>, DecodeError> {
+        decode_cow(self)
+    }
+
+    fn decode_escapes_lossy(&self) -> String {
+        decode_lossy(self)
+    }
+
+    fn validate_escapes(&self) -> Result<(), DecodeError> {
+        validate(self)
+    }
+
+    fn escape_sequences(&self) -> String {
+        encode(self)
+    }
+
+    fn escape_sequences_with(&self, options: &EncodeOptions) -> String {
+        encode_with(self, options)
+    }
+
+    fn escaped(&self) -> Escaped<'_> {
+        Escaped(self)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+}
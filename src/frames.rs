@@ -0,0 +1,225 @@
+//! Decodes the frames of STX/ETX-delimited protocols.
+//!
+//! Devices such as weight indicators send their readings as frames, e.g. `\x02 65480 LGM\x03`,
+//! where the payload between the start character (STX, U+0002) and the end character (ETX,
+//! U+0003) is escaped text. `decode_frames` splits a stream into such frames and decodes the
+//! payload of each one, optionally verifying a checksum sent after the end character.
+//!
+//! The framing characters must appear literally in the stream; escape sequences in the payload,
+//! such as `\x03`, never end a frame. Text outside of frames, e.g. line breaks between them, is
+//! skipped.
+use std::ops::Range;
+
+use crate::{decode_with, DecodeError, DecodeOptions};
+
+/// The algorithm computing the checksum of a frame.
+///
+/// The checksum is computed over the UTF-8 encoding of the decoded payload and sent as two
+/// hexadecimal digits directly after the end character, e.g. `\x02AB\x0303` for the XOR checksum
+/// of `AB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Checksum {
+    /// The exclusive or of all bytes, also known as a block check character (BCC).
+    Xor,
+    /// The sum of all bytes, modulo 256.
+    Sum,
+}
+
+impl Checksum {
+    /// Computes the checksum of a payload.
+    pub fn compute(self, payload: &[u8]) -> u8 {
+        match self {
+            Checksum::Xor => payload.iter().fold(0, |acc, &b| acc ^ b),
+            Checksum::Sum => payload.iter().fold(0, |acc: u8, &b| acc.wrapping_add(b)),
+        }
+    }
+}
+
+/// Options controlling how `decode_frames` splits and decodes a stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameOptions {
+    pub(crate) start: char,
+    pub(crate) end: char,
+    pub(crate) checksum: Option<Checksum>,
+    pub(crate) decode: DecodeOptions,
+}
+
+impl Default for FrameOptions {
+    fn default() -> FrameOptions {
+        FrameOptions::new()
+    }
+}
+
+impl FrameOptions {
+    /// Creates the default options, framing with STX and ETX without a checksum.
+    pub const fn new() -> FrameOptions {
+        FrameOptions {
+            start: '\x02',
+            end: '\x03',
+            checksum: None,
+            decode: DecodeOptions::new(),
+        }
+    }
+
+    /// Sets the character starting a frame. Defaults to STX, U+0002.
+    pub fn start(mut self, start: char) -> FrameOptions {
+        self.start = start;
+        self
+    }
+
+    /// Sets the character ending a frame. Defaults to ETX, U+0003.
+    pub fn end(mut self, end: char) -> FrameOptions {
+        self.end = end;
+        self
+    }
+
+    /// Verifies the checksum following the end character of every frame, or expects no checksum
+    /// if `None`. Defaults to `None`.
+    pub fn checksum(mut self, checksum: Option<Checksum>) -> FrameOptions {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Sets the options used to decode the payloads. Defaults to `DecodeOptions::new()`.
+    pub fn decode_options(mut self, decode: DecodeOptions) -> FrameOptions {
+        self.decode = decode;
+        self
+    }
+}
+
+/// A decoded frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame<'a> {
+    /// The byte range of the frame in the stream, from the start character up to and including
+    /// the end character and the checksum.
+    pub range: Range<usize>,
+    /// The escaped payload between the start and end characters.
+    pub payload: &'a str,
+    /// The decoded payload.
+    pub decoded: String,
+}
+
+/// The reason a frame could not be decoded.
+#[derive(Debug)]
+pub enum FrameErrorKind {
+    /// The payload contains an invalid escape sequence.
+    Decode(DecodeError),
+    /// The stream ended, or a new frame started, before the end character.
+    Unterminated,
+    /// The end character is not followed by two hexadecimal digits.
+    MissingChecksum,
+    /// The checksum sent with the frame does not match the one computed from its payload.
+    ChecksumMismatch {
+        /// The checksum sent with the frame.
+        expected: u8,
+        /// The checksum of the decoded payload.
+        actual: u8,
+    },
+}
+
+/// An error reported by `decode_frames` for a frame that could not be decoded.
+#[derive(Debug)]
+pub struct FrameError {
+    /// The byte range of the frame in the stream.
+    pub range: Range<usize>,
+    /// The reason the frame could not be decoded.
+    pub kind: FrameErrorKind,
+}
+
+/// An iterator over the frames of a stream.
+///
+/// This struct is created by `decode_frames`.
+#[derive(Debug, Clone)]
+pub struct Frames<'a> {
+    input: &'a str,
+    pos: usize,
+    options: &'a FrameOptions,
+}
+
+impl<'a> Frames<'a> {
+    /// Reads and verifies the checksum at the start of `rest`.
+    fn read_checksum(&self, rest: &str, payload: &str) -> Result<usize, FrameErrorKind> {
+        let Some(algorithm) = self.options.checksum else {
+            return Ok(0);
+        };
+        let expected = rest
+            .get(..2)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .ok_or(FrameErrorKind::MissingChecksum)?;
+        let actual = algorithm.compute(payload.as_bytes());
+        if expected != actual {
+            return Err(FrameErrorKind::ChecksumMismatch { expected, actual });
+        }
+        Ok(2)
+    }
+}
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = Result<Frame<'a>, FrameError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start_char, end_char) = (self.options.start, self.options.end);
+        let start = self.pos + self.input[self.pos..].find(start_char)?;
+        let body = start + start_char.len_utf8();
+        let rest = &self.input[body..];
+        let end = match rest.find([start_char, end_char]) {
+            Some(offset) if rest[offset..].starts_with(end_char) => body + offset,
+            // The frame is cut off by the start of the next one or by the end of the stream
+            unterminated => {
+                self.pos = unterminated.map_or(self.input.len(), |offset| body + offset);
+                return Some(Err(FrameError {
+                    range: start..self.pos,
+                    kind: FrameErrorKind::Unterminated,
+                }));
+            }
+        };
+        let payload = &self.input[body..end];
+        let after = end + end_char.len_utf8();
+        let result = decode_with(payload, &self.options.decode)
+            .map_err(FrameErrorKind::Decode)
+            .and_then(|decoded| {
+                let len = self.read_checksum(&self.input[after..], &decoded)?;
+                Ok((decoded, len))
+            });
+        Some(match result {
+            Ok((decoded, len)) => {
+                self.pos = after + len;
+                Ok(Frame {
+                    range: start..self.pos,
+                    payload,
+                    decoded,
+                })
+            }
+            Err(kind) => {
+                self.pos = after;
+                Err(FrameError {
+                    range: start..after,
+                    kind,
+                })
+            }
+        })
+    }
+}
+
+/// Splits a stream into frames and decodes the escaped payload of each one.
+///
+/// A frame that cannot be decoded is reported as an error, and decoding resumes behind it, so
+/// that a single corrupted frame does not hide the following ones.
+///
+/// # Parameters
+///
+/// * `input`: The stream, with literal framing characters around escaped payloads.
+/// * `options`: The framing characters, checksum and decoding options.
+///
+/// # Returns
+///
+/// An iterator yielding every frame of the stream in order, or a `FrameError` for every frame
+/// that is unterminated, contains an invalid escape sequence or has a wrong checksum.
+pub fn decode_frames<'a>(input: &'a str, options: &'a FrameOptions) -> Frames<'a> {
+    Frames {
+        input,
+        pos: 0,
+        options,
+    }
+}
@@ -0,0 +1,194 @@
+//! Generates random escaped strings for property tests.
+//!
+//! This module is only available with the `generators` feature. `EscapeGenerator` produces valid
+//! escaped strings together with their decoded form, and invalid ones that `decode` must reject,
+//! from a seed. The generators are deterministic and have no dependencies, so they can back the
+//! strategies of any property testing framework, e.g. with proptest:
+//!
+//! ```ignore
+//! any::<u64>().prop_map(|seed| EscapeGenerator::new(seed).valid(32))
+//! ```
+
+/// Literal characters mixed into generated strings, besides random code points.
+const LITERALS: &[char] = &[
+    'a',
+    'Z',
+    '0',
+    ' ',
+    '{',
+    '}',
+    'x',
+    'u',
+    '\t',
+    '\n',
+    '\u{E9}',
+    '\u{2011}',
+    '\u{200B}',
+    '\u{1F600}',
+    '\u{FEFF}',
+];
+
+/// Invalid escape sequences, each of which makes `decode` fail.
+const INVALID_ESCAPES: &[&str] = &[
+    r"\q",
+    r"\xG1",
+    r"\x4",
+    r"\x",
+    r"\u{110000}",
+    r"\u{D800}",
+    r"\u{",
+    r"\u{}",
+    r"\u{12",
+    r"\u123",
+    r"\u",
+    r"\U0001F600",
+    r"\",
+    r"\ ",
+];
+
+/// A deterministic generator of escaped strings.
+#[derive(Debug, Clone)]
+pub struct EscapeGenerator {
+    state: u64,
+}
+
+impl EscapeGenerator {
+    /// Creates a generator from a seed. Equal seeds produce equal strings.
+    pub fn new(seed: u64) -> EscapeGenerator {
+        // The xorshift state must not be zero
+        EscapeGenerator {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15 | 1,
+        }
+    }
+
+    /// Returns the next pseudo-random number.
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns a pseudo-random number below `bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Returns a random character that is not a backslash.
+    fn literal(&mut self) -> char {
+        if self.below(2) == 0 {
+            return LITERALS[self.below(LITERALS.len())];
+        }
+        let max = [0x80, 0x800, 0x1_0000, 0x11_0000][self.below(4)];
+        loop {
+            match char::from_u32(self.below(max) as u32) {
+                Some('\\') | None => continue,
+                Some(c) => return c,
+            }
+        }
+    }
+
+    /// Appends a random valid escape sequence of `c` to `out`.
+    fn push_escape(&mut self, out: &mut String, c: char) {
+        let simple = match c {
+            '\t' => Some('t'),
+            '\n' => Some('n'),
+            '\r' => Some('r'),
+            '\0' => Some('0'),
+            '\\' | '"' | '\'' => Some(c),
+            _ => None,
+        };
+        let code = c as u32;
+        match self.below(3) {
+            0 if simple.is_some() => {
+                out.push('\\');
+                out.extend(simple);
+            }
+            1 if code <= 0xFF => out.push_str(&self.hex_case(format!(r"\x{code:02X}"))),
+            _ => {
+                let zeros = "0".repeat(self.below(3));
+                out.push_str(&self.hex_case(format!(r"\u{{{zeros}{code:X}}}")));
+            }
+        }
+    }
+
+    /// Lowercases the hexadecimal digits of an escape sequence half of the time.
+    fn hex_case(&mut self, escape: String) -> String {
+        if self.below(2) == 0 {
+            escape
+        } else {
+            escape.to_ascii_lowercase()
+        }
+    }
+
+    /// Generates a valid escaped string of up to `max_pieces` literal characters and escape
+    /// sequences.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_pieces`: The maximum number of characters of the decoded string.
+    ///
+    /// # Returns
+    ///
+    /// The escaped string and the string it decodes to.
+    pub fn valid(&mut self, max_pieces: usize) -> (String, String) {
+        let (mut escaped, mut decoded) = (String::new(), String::new());
+        for _ in 0..self.below(max_pieces + 1) {
+            let c = if self.below(8) == 0 {
+                ['\\', '"', '\'', '\0', '\r'][self.below(5)]
+            } else {
+                self.literal()
+            };
+            if c == '\\' || self.below(3) == 0 {
+                self.push_escape(&mut escaped, c);
+            } else {
+                escaped.push(c);
+            }
+            decoded.push(c);
+        }
+        (escaped, decoded)
+    }
+
+    /// Generates an escaped string containing at least one invalid escape sequence.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_pieces`: The maximum number of characters and escape sequences around the invalid
+    ///   escape sequence.
+    ///
+    /// # Returns
+    ///
+    /// The escaped string, which `decode` rejects.
+    pub fn invalid(&mut self, max_pieces: usize) -> String {
+        let (mut before, _) = self.valid(max_pieces / 2);
+        let (after, _) = self.valid(max_pieces / 2);
+        // The space keeps the rest of the string from completing the invalid escape sequence
+        before.push_str(INVALID_ESCAPES[self.below(INVALID_ESCAPES.len())]);
+        before.push(' ');
+        before.push_str(&after);
+        before
+    }
+
+    /// Generates an arbitrary string that mixes valid and invalid escape sequences with stray
+    /// backslashes, for checking that no function panics.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_pieces`: The maximum number of pieces of the string.
+    ///
+    /// # Returns
+    ///
+    /// The generated string.
+    pub fn arbitrary(&mut self, max_pieces: usize) -> String {
+        let mut result = String::new();
+        for _ in 0..self.below(max_pieces + 1) {
+            match self.below(4) {
+                0 => result.push_str(&self.valid(2).0),
+                1 => result.push_str(INVALID_ESCAPES[self.below(INVALID_ESCAPES.len())]),
+                2 => result.push('\\'),
+                _ => result.push(self.literal()),
+            }
+        }
+        result
+    }
+}
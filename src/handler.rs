@@ -0,0 +1,94 @@
+//! Extends decoding with escape sequences of other syntaxes.
+//!
+//! This module contains the `EscapeHandler` trait, which decodes the escape sequences of a syntax
+//! the crate does not know, such as those of a proprietary log format, and the `EscapeRegistry`
+//! decoding a string with a set of handlers on top of the escape sequences `DecodeOptions`
+//! accepts. Handlers are plain values, so downstream crates can ship them and applications can
+//! combine handlers of several crates in one registry.
+use std::fmt;
+
+use crate::{decode_replacing, DecodeError, DecodeOptions};
+
+/// Decodes escape sequences that `DecodeOptions` does not accept.
+///
+/// Closures with the signature of `EscapeHandler::decode` are handlers as well.
+pub trait EscapeHandler {
+    /// Decodes the escape sequence introduced by a backslash followed by `rest`, e.g. `d{7}` for
+    /// `\d{7}`, appending what it stands for to `out`. A handler that does not know the escape
+    /// sequence leaves `out` unchanged.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the handler does not know the escape sequence, otherwise a `Result` containing
+    /// the number of bytes of `rest` the escape sequence covers, or the error decoding fails with
+    /// if the escape sequence is malformed.
+    fn decode(&self, rest: &str, out: &mut String) -> Option<Result<usize, DecodeError>>;
+}
+
+impl<F> EscapeHandler for F
+where
+    F: Fn(&str, &mut String) -> Option<Result<usize, DecodeError>>,
+{
+    fn decode(&self, rest: &str, out: &mut String) -> Option<Result<usize, DecodeError>> {
+        self(rest, out)
+    }
+}
+
+/// A set of escape handlers decoding strings together with the escape sequences of the options.
+///
+/// A backslash that starts an escape sequence the options accept is decoded by the options, so
+/// handlers cannot change the meaning of `\n`. Every other backslash is offered to the handlers
+/// in the order they were added until one of them knows the escape sequence, and rejected with
+/// `ErrorKind::UnknownEscape` if none does.
+#[derive(Default)]
+pub struct EscapeRegistry<'a> {
+    handlers: Vec<Box<dyn EscapeHandler + 'a>>,
+}
+
+impl<'a> EscapeRegistry<'a> {
+    /// Creates a registry without any handlers, which decodes like `decode_with`.
+    pub fn new() -> EscapeRegistry<'a> {
+        EscapeRegistry::default()
+    }
+
+    /// Adds a handler, which is asked after the handlers added before it.
+    pub fn with(mut self, handler: impl EscapeHandler + 'a) -> EscapeRegistry<'a> {
+        self.handlers.push(Box::new(handler));
+        self
+    }
+
+    /// Decodes a string with the escape sequences of the options and those of the handlers.
+    ///
+    /// The handlers are only asked with `UnknownEscapePolicy::Reject`, since the other policies
+    /// accept every escape sequence.
+    ///
+    /// # Parameters
+    ///
+    /// * `input`: A string slice or raw string slice
+    /// * `options`: The options controlling how escape sequences are interpreted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the decoded string, or the first error decoding or a handler fails
+    /// with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handler reports a length that is not a character boundary of `rest`.
+    pub fn decode(&self, input: &str, options: &DecodeOptions) -> Result<String, DecodeError> {
+        let mut handler = |rest: &str, out: &mut String| {
+            self.handlers
+                .iter()
+                .find_map(|handler| handler.decode(rest, out))
+        };
+        decode_replacing(input, options, None, Some(&mut handler), None)
+    }
+}
+
+impl fmt::Debug for EscapeRegistry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EscapeRegistry")
+            .field("handlers", &self.handlers.len())
+            .finish()
+    }
+}
@@ -0,0 +1,210 @@
+//! Keeps a decoded string up to date while its input is edited.
+//!
+//! Editors and language servers highlighting escape sequences decode the same document after
+//! every keystroke. `IncrementalDecoder` keeps the decoded string and its `SourceMap` from the
+//! previous edit and only decodes the input again from the character the edit touches up to the
+//! first character boundary behind it where decoding falls back into step with the previous
+//! result, so the cost of an edit depends on its size rather than on the size of the document.
+use std::ops::Range;
+
+use crate::invariants::invariant;
+use crate::parser::{Token, Tokens};
+use crate::{decode_with_map, DecodeError, SourceMap};
+
+/// A decoded string and its source map, updated in place by edits of the input.
+///
+/// The input is decoded like `decode_with_map` does, and after every edit the decoded string and
+/// the source map are the same as those of decoding the whole input again.
+#[derive(Debug)]
+pub struct IncrementalDecoder {
+    input: String,
+    decoded: String,
+    map: SourceMap,
+    error: Option<DecodeError>,
+}
+
+/// Returns the number of indices below `len` at the start of `0..len` satisfying `pred`.
+fn partition(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Returns the byte offset of the character at `index` in `text`, or the length of `text` if
+/// `index` is the number of characters.
+fn byte_offset(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
+impl IncrementalDecoder {
+    /// Decodes a string, keeping the result for later edits.
+    ///
+    /// # Parameters
+    ///
+    /// * `input`: The escaped input.
+    ///
+    /// # Returns
+    ///
+    /// The decoder, holding either the decoded string or the error decoding failed with.
+    pub fn new(input: impl Into<String>) -> IncrementalDecoder {
+        let mut decoder = IncrementalDecoder {
+            input: input.into(),
+            decoded: String::new(),
+            map: SourceMap::default(),
+            error: None,
+        };
+        decoder.decode_all();
+        decoder
+    }
+
+    /// Returns the current escaped input.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the decoded string, or the error decoding the current input fails with.
+    pub fn decoded(&self) -> Result<&str, &DecodeError> {
+        match &self.error {
+            Some(error) => Err(error),
+            None => Ok(&self.decoded),
+        }
+    }
+
+    /// Returns the source map of the decoded string, or `None` if the current input is invalid.
+    pub fn source_map(&self) -> Option<&SourceMap> {
+        match self.error {
+            Some(_) => None,
+            None => Some(&self.map),
+        }
+    }
+
+    /// Replaces a range of the input and updates the decoded string and its source map.
+    ///
+    /// If the previous input was invalid, the whole input is decoded again, otherwise only the
+    /// part affected by the edit is.
+    ///
+    /// # Parameters
+    ///
+    /// * `range`: The byte range of the input to replace.
+    /// * `replacement`: The text replacing the range.
+    ///
+    /// # Returns
+    ///
+    /// The decoded string of the edited input, or the error decoding it fails with.
+    ///
+    /// # Panics
+    ///
+    /// Panics like `String::replace_range` if the range is out of bounds or does not lie on
+    /// character boundaries of the input.
+    pub fn edit(&mut self, range: Range<usize>, replacement: &str) -> Result<&str, &DecodeError> {
+        self.input.replace_range(range.clone(), replacement);
+        if self.error.is_some() {
+            self.decode_all();
+        } else if let Err(error) = self.redecode(range, replacement.len()) {
+            self.fail(error);
+        }
+        invariant!(
+            match (decode_with_map(&self.input), &self.error) {
+                (Ok((decoded, map)), None) => decoded == self.decoded && map == self.map,
+                (Err(expected), Some(error)) => format!("{expected:?}") == format!("{error:?}"),
+                _ => false,
+            },
+            "the incremental decoding of {:?} differs from decoding it at once",
+            self.input
+        );
+        self.decoded()
+    }
+
+    /// Decodes the whole input.
+    fn decode_all(&mut self) {
+        match decode_with_map(&self.input) {
+            Ok((decoded, map)) => {
+                self.decoded = decoded;
+                self.map = map;
+                self.error = None;
+            }
+            Err(error) => self.fail(error),
+        }
+    }
+
+    /// Records that the current input is invalid.
+    fn fail(&mut self, error: DecodeError) {
+        self.decoded.clear();
+        self.map = SourceMap::default();
+        self.error = Some(error);
+    }
+
+    /// Decodes the part of the input affected by replacing the bytes `range` of the previous
+    /// input with `inserted` bytes, and splices it into the decoded string and the source map.
+    fn redecode(&mut self, range: Range<usize>, inserted: usize) -> Result<(), DecodeError> {
+        let map = &self.map;
+        let shift = inserted as isize - range.len() as isize;
+        // Decoding restarts at the first character whose source reaches into the edit, which
+        // also covers an edit completing an escape sequence, such as inserting `u` into `\{41}`
+        let first = partition(map.len(), |i| {
+            map.input_range(i)
+                .is_some_and(|source| source.end <= range.start)
+        });
+        let restart = map.input_offset(first).unwrap_or(range.start);
+        let edit_end = range.start + inserted;
+        // Decoding is back in step once it reaches a previous character boundary behind the edit
+        let resume = |pos: usize| -> Option<usize> {
+            if pos < edit_end {
+                return None;
+            }
+            let old = pos.wrapping_add_signed(-shift);
+            let index = partition(map.len(), |i| {
+                map.input_offset(i).is_some_and(|offset| offset < old)
+            });
+            (map.input_offset(index) == Some(old)).then_some(index)
+        };
+
+        let mut decoded = String::new();
+        let mut entries = Vec::new();
+        let mut tokens = Tokens::new(&self.input[restart..]);
+        let last = 'decode: loop {
+            let pos = restart + tokens.offset();
+            if let Some(index) = resume(pos) {
+                break index;
+            }
+            match tokens
+                .next()
+                .transpose()
+                .map_err(|error| error.shifted(restart))?
+            {
+                Some(Token::Literal(text)) => {
+                    for (offset, c) in text.char_indices() {
+                        if offset > 0 {
+                            if let Some(index) = resume(pos + offset) {
+                                break 'decode index;
+                            }
+                        }
+                        decoded.push(c);
+                        entries.push((pos + offset, c.len_utf8()));
+                    }
+                }
+                Some(Token::Escape(escape)) => {
+                    decoded.push(escape.value);
+                    entries.push((pos, restart + escape.end - pos));
+                }
+                // The end of the input is always a previous character boundary
+                None => break map.len(),
+            }
+        };
+
+        let bytes = byte_offset(&self.decoded, first)..byte_offset(&self.decoded, last);
+        self.decoded.replace_range(bytes, &decoded);
+        self.map
+            .splice(first..last, &entries, shift, self.input.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+//! Internal consistency checks of the decoder.
+//!
+//! The `invariant!` macro asserts a condition only when the `debug-invariants` feature is enabled,
+//! and compiles to nothing otherwise. Downstream integration tests can enable the feature to catch
+//! decoder bugs, such as a token stream that stops advancing or a source map running backwards,
+//! where they happen instead of as wrong output much later.
+
+/// Asserts that an internal invariant holds if the `debug-invariants` feature is enabled.
+macro_rules! invariant {
+    ($condition:expr, $($message:tt)+) => {
+        if cfg!(feature = "debug-invariants") {
+            assert!($condition, $($message)+);
+        }
+    };
+}
+
+pub(crate) use invariant;
@@ -0,0 +1,218 @@
+//! Decodes escaped text while it is read, and escapes data while it is written.
+//!
+//! This module contains the `DecodingReader` adapter, which wraps a reader of escaped text and
+//! yields the UTF-8 encoding of the decoded text, and the `EscapingWriter` adapter, which escapes
+//! the data written to it before passing it on to another writer. Both plug into pipelines built
+//! on `std::io`, e.g. `io::copy` from a file into a socket.
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::str;
+
+use crate::encode::push_encoded;
+use crate::{DecodeOptions, Decoder, EncodeOptions};
+
+/// The number of bytes `DecodingReader` reads from its inner reader at once.
+const READ_SIZE: usize = 8192;
+
+/// Returns an error of kind `io::ErrorKind::InvalidData` wrapping `error`.
+fn invalid_data(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// A reader decoding the escaped text read from another reader.
+///
+/// The input is decoded like `Decoder` does, so escape sequences and UTF-8 characters split
+/// across the reads of the inner reader are decoded as if they had been read at once. Reading
+/// from the adapter yields the UTF-8 encoding of the decoded text.
+///
+/// Invalid escape sequences, and input that is not valid UTF-8, fail the read with an error of
+/// kind `io::ErrorKind::InvalidData`, wrapping the `DecodeError` for the former. The reader ends
+/// after such an error.
+#[derive(Debug)]
+pub struct DecodingReader<R> {
+    inner: R,
+    /// The decoder of the input, or `None` once the input has ended or failed to decode.
+    decoder: Option<Decoder>,
+    /// Bytes read from the inner reader that do not form a complete UTF-8 character yet.
+    partial: Vec<u8>,
+    /// Decoded bytes that have not been returned by `read` yet, from `pos` on.
+    output: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: Read> DecodingReader<R> {
+    /// Creates a reader decoding like `decode`.
+    pub fn new(inner: R) -> DecodingReader<R> {
+        DecodingReader::with_options(inner, DecodeOptions::new())
+    }
+
+    /// Creates a reader decoding like `decode_with` with the given options.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner`: The reader of the escaped input.
+    /// * `options`: The options controlling how escape sequences are interpreted.
+    pub fn with_options(inner: R, options: DecodeOptions) -> DecodingReader<R> {
+        DecodingReader {
+            inner,
+            decoder: Some(Decoder::with_options(options)),
+            partial: Vec::new(),
+            output: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Returns the inner reader, dropping any input read from it but not decoded yet.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads the next chunk of the input into `output`, decoded, which may come out empty.
+    fn fill(&mut self) -> io::Result<()> {
+        let mut chunk = [0; READ_SIZE];
+        // Errors of the inner reader, such as `io::ErrorKind::Interrupted`, keep the state
+        let len = self.inner.read(&mut chunk)?;
+        self.partial.extend_from_slice(&chunk[..len]);
+        self.output.clear();
+        self.pos = 0;
+        match self.decode(len == 0) {
+            Ok(output) => {
+                self.output = output;
+                Ok(())
+            }
+            Err(error) => {
+                self.decoder = None;
+                Err(error)
+            }
+        }
+    }
+
+    /// Decodes the complete UTF-8 characters read so far, or all of the input if it has ended.
+    fn decode(&mut self, last: bool) -> io::Result<Vec<u8>> {
+        // A UTF-8 character split across reads is completed by the next one
+        let complete = match str::from_utf8(&self.partial) {
+            Ok(text) => text.len(),
+            Err(error) if error.error_len().is_none() && !last => error.valid_up_to(),
+            Err(error) => return Err(invalid_data(error)),
+        };
+        let text = str::from_utf8(&self.partial[..complete]).map_err(invalid_data)?;
+        let decoded = match self.decoder.take() {
+            Some(decoder) if last => decoder.finish(),
+            Some(mut decoder) => {
+                let decoded = decoder.feed(text);
+                self.decoder = Some(decoder);
+                decoded
+            }
+            None => Ok(String::new()),
+        };
+        self.partial.drain(..complete);
+        Ok(decoded.map_err(invalid_data)?.into_bytes())
+    }
+}
+
+impl<R: Read> Read for DecodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.output.len() && self.decoder.is_some() {
+            self.fill()?;
+        }
+        let len = buf.len().min(self.output.len() - self.pos);
+        buf[..len].copy_from_slice(&self.output[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+/// A writer escaping the data written to it before writing it into another writer.
+///
+/// Valid UTF-8 is escaped character by character like `encode_with` does, except that emoji
+/// sequences are not treated as a whole, and every byte that is not part of a valid UTF-8
+/// character is written as a hexadecimal escape sequence such as `\xFF`, so that `decode_bytes`
+/// of the output yields the bytes written. A UTF-8 character split across writes is held back
+/// until the next write completes it, and written as hexadecimal escape sequences of its bytes
+/// if the writer is flushed or finished before.
+#[derive(Debug)]
+pub struct EscapingWriter<W: Write> {
+    inner: W,
+    options: EncodeOptions,
+    /// The bytes of a UTF-8 character whose remaining bytes have not been written yet.
+    partial: Vec<u8>,
+    /// Whether nothing has been written into the inner writer yet.
+    at_start: bool,
+}
+
+impl<W: Write> EscapingWriter<W> {
+    /// Creates a writer escaping like `encode`.
+    pub fn new(inner: W) -> EscapingWriter<W> {
+        EscapingWriter::with_options(inner, EncodeOptions::new())
+    }
+
+    /// Creates a writer escaping like `encode_with` with the given options.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner`: The writer receiving the escaped text.
+    /// * `options`: The options controlling which characters are escaped and how.
+    pub fn with_options(inner: W, options: EncodeOptions) -> EscapingWriter<W> {
+        EscapingWriter {
+            inner,
+            options,
+            partial: Vec::new(),
+            at_start: true,
+        }
+    }
+
+    /// Writes the bytes held back, flushes the inner writer and returns it.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.inner)
+    }
+
+    /// Escapes `buf` after the held back bytes and writes the result into the inner writer.
+    fn write_escaped(&mut self, buf: &[u8], last: bool) -> io::Result<()> {
+        let mut bytes = std::mem::take(&mut self.partial);
+        bytes.extend_from_slice(buf);
+        let mut out = String::with_capacity(bytes.len());
+        if self.at_start && self.options.byte_order_mark {
+            out.push('\u{FEFF}');
+        }
+        let held = if last { 0 } else { incomplete_len(&bytes) };
+        for chunk in bytes[..bytes.len() - held].utf8_chunks() {
+            for c in chunk.valid().chars() {
+                push_encoded(&mut out, c, &self.options);
+            }
+            for byte in chunk.invalid() {
+                let _ = write!(out, r"\x{byte:02X}");
+            }
+        }
+        self.inner.write_all(out.as_bytes())?;
+        self.at_start &= out.is_empty();
+        bytes.drain(..bytes.len() - held);
+        self.partial = bytes;
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes at the end of `bytes` that start a UTF-8 character without
+/// completing it.
+fn incomplete_len(bytes: &[u8]) -> usize {
+    (1..=bytes.len().min(3))
+        .find(|&len| {
+            str::from_utf8(&bytes[bytes.len() - len..])
+                .is_err_and(|error| error.valid_up_to() == 0 && error.error_len().is_none())
+        })
+        .unwrap_or(0)
+}
+
+impl<W: Write> Write for EscapingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_escaped(buf, false)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.partial.is_empty() {
+            self.write_escaped(&[], true)?;
+        }
+        self.inner.flush()
+    }
+}
@@ -1,21 +1,135 @@
 //! # Escape Sequence Decoder
 //!
-//! This crate provides a module for decoding strings with escape sequences. It handles simple escape sequences (e.g., '\t', '\n'), 8-bit escape sequences (e.g., '\x02'), and Unicode escape sequences (e.g., '\u{1A2B}').
+//! This crate provides a module for decoding strings with escape sequences. It handles simple escape sequences (e.g., '\t', '\n'), 8-bit escape sequences (e.g., '\x02'), and Unicode escape sequences (e.g., '\u{1A2B}'). With the `names` feature it also handles named Unicode escape sequences (e.g., '\N{BULLET}').
 //!
-//! The module exports a single function, `decode`, which takes a string as input and returns a `Result` containing the decoded string or an error of type `DecodeError`. The function handles invalid escape sequences gracefully, returning an error if an invalid sequence is encountered.
+//! The main entry point is `decode`, which takes a string as input and returns a `Result` containing the decoded string or an error of type `DecodeError`, which tells the `ErrorKind` of the failure and the byte range of the escape sequence causing it. The function handles invalid escape sequences gracefully, returning an error if an invalid sequence is encountered. `decode_lossy` never fails, replacing invalid escape sequences with U+FFFD instead, `decode_all` reports every invalid escape sequence instead of only the first one, and `decode_spanned` reports the byte range and character index of the invalid escape sequence with a `SpannedError`, whose message quotes the input around it.
 //!
-//! The module also provides a set of unit tests to ensure the correctness of the decoding functionality.
-use std::iter::Peekable;
-use std::u32;
-
+//! Other entry points decode into other shapes: `decode_cow` decodes like `decode` without allocating for inputs that contain no escape sequences, `decode_in_place` replaces a `String` with its decoded form, reusing its allocation, `decode_to` writes the decoded text into any `fmt::Write` sink, `decode_into` writes it into a fixed byte buffer without allocating, failing with `ErrorKind::BufferTooSmall` if it does not fit, `ConstDecoded` and the `decode!` macro decode string constants at compile time, like the `decode!` and `escape!` procedural macros of the `unicode_escape_macros` crate, which also report where an invalid escape sequence is, `decode_bytes` decodes byte protocols into raw bytes, turning `\xFF` into the byte 0xFF, and `decode_bytes_to` streams them into an `io::Write`, `decode_chars` yields the decoded characters lazily without building a string, `Decoder` decodes input arriving in chunks, buffering escape sequences split across them, and `DecodingReader` decodes the escaped text of an `io::Read` while it is read.
+//!
+//! `encode` is the counterpart of `decode`, escaping the characters of a string that cannot be written as plain text. `encode_with` customises the escaping through `EncodeOptions`, e.g. to emit named escape sequences with the `names` feature to keep emoji sequences intact with the `unicode-segmentation` feature, to escape whole general categories with the `unicode-categories` feature, or to transliterate non-ASCII characters with the `transliteration` feature. `Escaped` displays a string in the escaped form of `encode` without allocating. `encode_canonical` produces a versioned, byte-for-byte stable escaped form for hashing and signing. `EscapingWriter` escapes the data written to an `io::Write`, including bytes that are not valid UTF-8, on the fly.
+//!
+//! `decode_with` customises decoding through `DecodeOptions`, e.g. to combine surrogate pairs written as two Unicode escape sequences or to fold the case of the decoded string like `fold_case`. `DecodeOptions::rustc_compat` decodes string literal bodies exactly like `rustc`, which the tests check against a corpus generated by `scripts/rustc_corpus.py`. `DecodeOptions::dialect` decodes the escape sequences of other string literal syntaxes, such as JSON strings with `Dialect::Json`, rejecting every escape sequence the `Dialect` does not have. `decode_sql_literal` decodes the doubled quotes of SQL string literals, optionally together with MySQL escape sequences. `decode_with_handler` passes the escape sequences the options do not accept to a function, which can decode escape sequences of an application such as `\d`. `EscapeRegistry` combines several `EscapeHandler`s, e.g. dialects shipped by other crates, with the escape sequences of the options.
+//!
+//! The `find_escapes` function locates escape sequences without decoding them, for tools that only need their positions, and `needs_decoding` cheaply checks whether a string contains any escape sequences at all. `decode_stats` summarises what decoding a string would produce without building the decoded string, and `escape_density` measures how densely a string is populated with escape sequences.
+//!
+//! `decode_with_map` decodes a string and additionally returns a `SourceMap` relating every decoded character to its position in the input, `decode_diff` pairs every escape sequence with the character it decodes to, and `decode_styled` keeps the spelling of every escape sequence, so that a modified string can be written out again with the unmodified escape sequences as they were. `IncrementalDecoder` keeps such a decoded string and source map up to date while the input is edited, decoding only the part an edit affects.
+//!
+//! `decode_frames` splits the stream of an STX/ETX-delimited protocol into frames, decodes the escaped payload of each one and optionally verifies their checksums.
+//!
+//! `parse_codepoint_notation` and `format_codepoint_notation` convert between characters and the `U+XXXX` notation of the Unicode Standard, which `encode_with` can also produce.
+//!
+//! The `StrExt` extension trait offers the main entry points as methods of string slices, e.g. `raw.decode_escapes()?` and `text.escape_sequences()`.
+//!
+//! `validate`, `validate_spanned` and `validate_all` check a string for invalid escape sequences without building any output.
+//!
+//! `strip_ansi` removes ANSI terminal escape sequences such as `\x1b[1;32m` from decoded text, and `ansi_tokens` splits decoded text into plain runs and such sequences.
+//!
+//! `render_ansi` and `render_html` reproduce a string with its escape sequences highlighted and annotated for inspection in a terminal or a browser, and `render_report` lists invalid and non-canonical escape sequences as compiler-style diagnostics.
+//!
+//! `lint` reports redundant and non-canonical escape sequences together with suggested replacements, `normalize` applies them and `is_canonical` checks whether there is anything to apply.
+//!
+//! With the `names` feature, the `names` module looks up Unicode character names and reports the code points referenced by Unicode escape sequences. With the `unicode-segmentation` feature, the `segmentation` module reports the grapheme clusters of decoded strings. With the `unicode-normalization` feature, the `normalization` module normalizes decoded strings, which `decode_with` can do as part of decoding. With the `unicode-age` feature, the `age` module tells in which Unicode version a character was assigned, and `decode_with` can reject escape sequences producing unassigned code points.
+//!
+//! With the `generators` feature, the `generators` module generates random valid and invalid escaped strings for property tests. With the `trace` feature, the `trace` module reports the escape sequences, errors and fallbacks of `decode_with` to a hook. The `debug-invariants` feature turns on internal consistency checks of the decoder, which panic when an invariant is violated.
+//!
+//! The crate also provides a set of integration tests to ensure the correctness of the decoding functionality.
+#[cfg(feature = "unicode-age")]
+pub mod age;
+pub mod ansi;
+pub mod bytes;
+pub mod case_folding;
+#[cfg(feature = "unicode-categories")]
+pub mod category;
+pub mod chars;
+pub mod const_decode;
+pub mod diff;
+pub mod encode;
 pub mod error;
-pub use error::DecodeError;
+pub mod ext;
+pub mod frames;
+#[cfg(feature = "generators")]
+pub mod generators;
+pub mod handler;
+pub mod incremental;
+mod invariants;
+pub mod io;
+pub mod lint;
+mod message_format;
+#[cfg(feature = "names")]
+pub mod names;
+#[cfg(feature = "unicode-normalization")]
+pub mod normalization;
+pub mod notation;
+pub mod options;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+mod parser;
+#[cfg(kani)]
+mod proofs;
+pub mod render;
+pub mod scan;
+#[cfg(feature = "unicode-segmentation")]
+pub mod segmentation;
+pub mod source_map;
+pub mod stats;
+pub mod stream;
+pub mod styled;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "transliteration")]
+pub mod transliteration;
+pub mod validate;
+pub use ansi::{
+    ansi_tokens, contains_ansi, strip_ansi, AnsiKind, AnsiSequence, AnsiToken, AnsiTokens,
+};
+pub use bytes::{decode_bytes, decode_bytes_to};
+pub use case_folding::{fold_case, CaseFolding};
+pub use chars::{decode_chars, DecodeChars};
+pub use const_decode::ConstDecoded;
+pub use diff::{decode_diff, Diff, DiffSegment};
+#[cfg(feature = "unicode-segmentation")]
+pub use encode::EmojiSequences;
+pub use encode::{
+    encode, encode_canonical, encode_with, EncodeOptions, Escaped, CANONICAL_ENCODING_VERSION,
+};
+pub use error::{DecodeError, ErrorKind, SpannedError};
+pub use ext::StrExt;
+pub use frames::{
+    decode_frames, Checksum, Frame, FrameError, FrameErrorKind, FrameOptions, Frames,
+};
+pub use handler::{EscapeHandler, EscapeRegistry};
+pub use incremental::IncrementalDecoder;
+pub use io::{DecodingReader, EscapingWriter};
+pub use lint::{is_canonical, lint, normalize, LintKind, LintWarning};
+pub use notation::{format_codepoint_notation, parse_codepoint_notation};
+pub use options::{
+    BomPolicy, DecodeOptions, Dialect, LineContinuation, NoncharacterPolicy, OctalEscapes,
+    SimpleEscapes, UnknownEscapePolicy,
+};
+#[cfg(feature = "parallel")]
+pub use parallel::decode_parallel;
+pub use render::{render_ansi, render_html, render_report};
+pub use scan::{find_escapes, needs_decoding, EscapeKind, EscapeSpan, FindEscapes};
+pub use source_map::{decode_with_map, SourceMap};
+pub use stats::{decode_stats, escape_density, DecodeStats, EscapeDensity};
+pub use stream::Decoder;
+pub use styled::{decode_styled, StyledSegment, StyledString};
+pub use validate::{validate, validate_all, validate_spanned};
+
+use std::borrow::Cow;
+use std::fmt;
+
+use invariants::invariant;
+use parser::Token;
+#[cfg(feature = "trace")]
+use trace::{Fallback, TraceEvent};
 
 /// Decodes a string with escape sequences.
 ///
 /// This function interprets and converts escape sequences in the input string into their corresponding characters.
 /// It handles simple escape sequences (e.g., '\t', '\n'), 8-bit escape sequences (e.g., '\x02'),
-/// and Unicode escape sequences (e.g., '\u{1A2B}').
+/// and Unicode escape sequences (e.g., '\u{1A2B}'). With the `names` feature, named Unicode escape
+/// sequences (e.g., '\N{BULLET}') are decoded as well.
 ///
 /// # Parameters
 ///
@@ -25,114 +139,465 @@ pub use error::DecodeError;
 ///
 /// A `Result` containing a literal string or an error if the escape sequence is invalid.
 pub fn decode(input: &str) -> Result<String, DecodeError> {
-    let mut result = String::new();
-    let mut chars = input.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        if c == '\\' {
-            match chars.next() {
-                // Simple excape sequences ex: \n = newline
-                Some('t') => result.push('\t'),
-                Some('n') => result.push('\n'),
-                Some('r') => result.push('\r'),
-                Some('0') => result.push('\0'),
-                Some('\\') => result.push('\\'),
-                Some('"') => result.push('"'),
-                Some('\'') => result.push('\''),
-                // 8 bit excape sequences ex: \x02 = <STX>
-                Some('x') => result.push(escape_hex(&mut chars)?),
-                // unicode escape /u{1A2B} = ↵
-                Some('u') => result.push(decode_unicode(&mut chars)?),
-                _ => return Err(DecodeError::InvalidEscape),
-            }
-        } else {
-            result.push(c);
+    decode_with(input, &DecodeOptions::DEFAULT)
+}
+
+/// Decodes a string with escape sequences like `decode_with`, reporting where decoding failed.
+///
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `options`: The options controlling how escape sequences are interpreted.
+///
+/// # Returns
+///
+/// A `Result` containing a literal string, or an error locating the first invalid escape
+/// sequence.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode_with`, together with their position.
+pub fn decode_spanned(input: &str, options: &DecodeOptions) -> Result<String, SpannedError> {
+    decode_with(input, options).map_err(|error| {
+        // Every error of `decode_with` is located, a byte order mark with an empty span at the
+        // start, which is checked before any escape sequence following it
+        let span = error.span().unwrap_or_default();
+        SpannedError::new(error, input, span)
+    })
+}
+
+/// Decodes a string with escape sequences like `decode`, borrowing the input if it contains no
+/// escape sequences.
+///
+/// Inputs without any backslash decode to themselves, so they are returned without allocating.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Result` containing the input itself or the decoded string, or an error if an escape
+/// sequence is invalid.
+pub fn decode_cow(input: &str) -> Result<Cow<'_, str>, DecodeError> {
+    if input.contains('\\') {
+        decode(input).map(Cow::Owned)
+    } else {
+        Ok(Cow::Borrowed(input))
+    }
+}
+
+/// Decodes a string with escape sequences like `decode`, writing the result into a sink.
+///
+/// The decoded text is written piece by piece as the input is read, so it can go straight into
+/// an existing buffer or a formatter without building an intermediate `String`. If an escape
+/// sequence is invalid, the text before it has already been written.
+///
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `out`: The sink receiving the decoded text.
+///
+/// # Returns
+///
+/// `Ok(())` if the whole input was decoded, or an error if an escape sequence is invalid or the
+/// sink fails with `ErrorKind::WriteFailed`.
+pub fn decode_to(input: &str, out: &mut impl fmt::Write) -> Result<(), DecodeError> {
+    for token in parser::Tokens::new(input) {
+        match token? {
+            Token::Literal(text) => out.write_str(text),
+            Token::Escape(escape) => out.write_char(escape.value),
         }
+        .map_err(|_| ErrorKind::WriteFailed)?;
     }
-    Ok(result)
+    Ok(())
 }
 
-/// Decodes a hexadecimal escape sequence.
+/// Decodes a string with escape sequences like `decode`, writing the UTF-8 encoding of the result
+/// into a fixed buffer.
 ///
-/// This function takes an iterator of characters representing a hexadecimal escape sequence
-/// (e.g., `\x02`) and returns the corresponding character.
+/// Decoding does not allocate, so that it can run where there is no heap, e.g. for the payloads
+/// of AT commands on a microcontroller. If an escape sequence is invalid or the decoded text does
+/// not fit, the text before it has already been written.
 ///
 /// # Parameters
 ///
-/// * `chars`: An iterator of characters representing the hexadecimal escape sequence.
+/// * `input`: A string slice or raw string slice
+/// * `buf`: The buffer receiving the decoded text.
 ///
 /// # Returns
 ///
-/// A `Result` containing the decoded character or an error if the escape sequence is invalid.
+/// A `Result` containing the number of bytes written to the start of `buf`, which are valid
+/// UTF-8, or an error if an escape sequence is invalid or the decoded text does not fit, with
+/// `ErrorKind::BufferTooSmall` and the span of the input that did not fit.
+pub fn decode_into(input: &str, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let mut len = 0;
+    let mut tokens = parser::Tokens::new(input);
+    loop {
+        let start = tokens.offset();
+        let Some(token) = tokens.next() else {
+            break;
+        };
+        let mut utf8 = [0; 4];
+        let bytes = match token? {
+            Token::Literal(text) => text.as_bytes(),
+            Token::Escape(escape) => escape.value.encode_utf8(&mut utf8).as_bytes(),
+        };
+        let Some(out) = buf.get_mut(len..len + bytes.len()) else {
+            return Err(DecodeError::new(ErrorKind::BufferTooSmall).at(start..tokens.offset()));
+        };
+        out.copy_from_slice(bytes);
+        len += bytes.len();
+    }
+    Ok(len)
+}
+
+/// Decodes a string with escape sequences like `decode`, replacing the string with the result.
 ///
-/// # Errors
+/// The decoded string is never longer than the input, so it is written over the bytes of the
+/// string as they are read, reusing its allocation without an intermediate buffer. Inputs without
+/// any backslash are left as they are. The input is validated before anything is written, so the
+/// string is left unchanged if decoding fails.
 ///
-/// This function will return an error of type `DecodeError::InvalidHexChar` if the escape sequence
-/// is not a valid hexadecimal representation of a character.
-fn escape_hex(chars: &mut impl Iterator<Item = char>) -> Result<char, DecodeError> {
-    let mut hex_chars = String::new();
-    for _ in 0..2 {
-        if let Some(c) = chars.next() {
-            hex_chars.push(c);
-        } else {
-            return Err(DecodeError::InvalidHexChar);
-        }
+/// # Parameters
+///
+/// * `string`: The escaped string, which is replaced with the decoded string.
+///
+/// # Returns
+///
+/// `Ok(())` if the string was decoded, or an error if an escape sequence is invalid.
+pub fn decode_in_place(string: &mut String) -> Result<(), DecodeError> {
+    let Some(first) = string.find('\\') else {
+        return Ok(());
+    };
+    validate(string)?;
+    let mut bytes = std::mem::take(string).into_bytes();
+    let (mut read, mut write) = (first, first);
+    while read < bytes.len() {
+        // `read` is at a backslash. Escape sequences do not contain another backslash after their
+        // first two bytes, so the text up to the next one holds the whole escape sequence and
+        // ends on a character boundary
+        let end = bytes[read + 2..]
+            .iter()
+            .position(|&byte| byte == b'\\')
+            .map_or(bytes.len(), |offset| read + 2 + offset);
+        let Ok(window) = std::str::from_utf8(&bytes[read..end]) else {
+            unreachable!("the text between two backslashes is a character boundary")
+        };
+        let (value, len) = match parser::Tokens::new(window).next() {
+            Some(Ok(Token::Escape(escape))) => (escape.value, escape.end),
+            _ => unreachable!("the input was validated"),
+        };
+        invariant!(
+            value.len_utf8() <= len,
+            "`{}` decodes to more bytes than it has",
+            &window[..len]
+        );
+        write += value.encode_utf8(&mut bytes[write..read + len]).len();
+        bytes.copy_within(read + len..end, write);
+        write += end - read - len;
+        read = end;
     }
-    match u8::from_str_radix(&hex_chars, 16) {
-        Ok(value) => Ok(char::from(value)),
-        Err(_) => Err(DecodeError::InvalidHexChar),
+    bytes.truncate(write);
+    let Ok(decoded) = String::from_utf8(bytes) else {
+        unreachable!("only whole characters are written")
+    };
+    *string = decoded;
+    Ok(())
+}
+
+/// Decodes a string with escape sequences like `decode`, using the given options.
+///
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `options`: The options controlling how escape sequences are interpreted.
+///
+/// # Returns
+///
+/// A `Result` containing a literal string or an error if the escape sequence is invalid.
+pub fn decode_with(input: &str, options: &DecodeOptions) -> Result<String, DecodeError> {
+    decode_replacing(input, options, None, None, None)
+}
+
+/// Decodes the body of an SQL string literal or quoted identifier, where the quote character is
+/// written twice, e.g. `it''s` for `it's` in `'it''s'`, or `""` for `"` in a quoted identifier.
+///
+/// Backslashes are literal text, as in standard SQL, unless `backslash_escapes` is set, which
+/// reads the escape sequences of MySQL as well, like `Dialect::MySql` does.
+///
+/// # Parameters
+///
+/// * `input`: The text between the quotes of the literal.
+/// * `quote`: The quote character of the literal, e.g. `'`.
+/// * `backslash_escapes`: Whether to read MySQL escape sequences such as `\n`.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded string, or an error if a quote character is on its own or
+/// an escape sequence is invalid.
+///
+/// # Panics
+///
+/// Panics if `quote` is a backslash.
+pub fn decode_sql_literal(
+    input: &str,
+    quote: char,
+    backslash_escapes: bool,
+) -> Result<String, DecodeError> {
+    let options = if backslash_escapes {
+        DecodeOptions::dialect(Dialect::MySql)
+    } else {
+        DecodeOptions::new().backslash_escapes(false)
+    };
+    decode_with(input, &options.doubled_quote(Some(quote)))
+}
+
+/// Decodes a string with escape sequences like `decode`, replacing invalid escape sequences with
+/// U+FFFD REPLACEMENT CHARACTER instead of failing.
+///
+/// Each invalid escape sequence becomes a single U+FFFD, so that `\u{110000}` decodes to `"�"`.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The decoded string.
+pub fn decode_lossy(input: &str) -> String {
+    decode_lossy_with(input, &DecodeOptions::DEFAULT, "\u{FFFD}")
+}
+
+/// Decodes a string with escape sequences like `decode_with`, replacing everything that would
+/// make it fail with the given replacement.
+///
+/// Every invalid escape sequence is replaced as a whole, e.g. all of `\u{110000}`, resuming behind
+/// it like `validate_all` does, and so is a byte order mark the options reject.
+///
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `options`: The options controlling how escape sequences are interpreted.
+/// * `replacement`: The text replacing invalid escape sequences, e.g. `"\u{FFFD}"`, or an empty
+///   string to drop them.
+///
+/// # Returns
+///
+/// The decoded string.
+pub fn decode_lossy_with(input: &str, options: &DecodeOptions, replacement: &str) -> String {
+    match decode_replacing(input, options, Some(replacement), None, None) {
+        Ok(result) => result,
+        Err(_) => unreachable!("lossy decoding replaces every error"),
     }
 }
 
-/// Decodes a Unicode escape sequence.
+/// Decodes a string with escape sequences like `decode`, reporting every invalid escape sequence
+/// instead of only the first one.
 ///
-/// This function takes an iterator of characters representing a Unicode escape sequence
-/// (e.g., `\u{1F600}`) and returns the corresponding character.
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Result` containing the decoded string, or the errors of all invalid escape sequences.
+pub fn decode_all(input: &str) -> Result<String, Vec<DecodeError>> {
+    decode_all_with(input, &DecodeOptions::DEFAULT)
+}
+
+/// Decodes a string with escape sequences like `decode_with`, reporting every error instead of
+/// only the first one.
+///
+/// Decoding resumes behind every invalid escape sequence like `decode_lossy_with` does, so that
+/// e.g. a linter can show all problems of the input at once.
 ///
 /// # Parameters
 ///
-/// * `chars`: An iterator of characters representing the Unicode escape sequence.
+/// * `input`: A string slice or raw string slice
+/// * `options`: The options controlling how escape sequences are interpreted.
 ///
 /// # Returns
 ///
-/// A `Result` containing the decoded character or an error if the escape sequence is invalid.
+/// A `Result` containing the decoded string, or every error decoding fails with, in input
+/// order. The vector is never empty.
+pub fn decode_all_with(input: &str, options: &DecodeOptions) -> Result<String, Vec<DecodeError>> {
+    let mut errors = Vec::new();
+    let result = match decode_replacing(input, options, Some(""), None, Some(&mut errors)) {
+        Ok(result) => result,
+        Err(_) => unreachable!("lossy decoding replaces every error"),
+    };
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Decodes a string with escape sequences like `decode_with`, passing the escape sequences the
+/// options do not accept to a handler.
+///
+/// Where `decode_with` would reject an unknown escape sequence such as `\d` with
+/// `ErrorKind::UnknownEscape`, the handler is called with the character following the
+/// backslash and the decoded string so far instead. It appends what the escape sequence stands
+/// for, if anything, or returns the error to fail decoding with. This adds escape sequences of an
+/// application to the ones the options accept, so the handler is only called with
+/// `UnknownEscapePolicy::Reject`.
 ///
-/// # Errors
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `options`: The options controlling how escape sequences are interpreted.
+/// * `handler`: The function decoding unknown escape sequences.
+///
+/// # Returns
 ///
-/// This function will return an error of type `DecodeError::InvalidUnicode` if the escape sequence
-/// is not a valid Unicode representation of a character or if the Unicode code point is out of range.
-fn decode_unicode(chars: &mut Peekable<impl Iterator<Item = char>>) -> Result<char, DecodeError> {
-    // Remove the leading '{'
-    match chars.next() {
-        Some('{') => {}
-        _ => return Err(DecodeError::InvalidUnicode),
+/// A `Result` containing the decoded string, or the first error decoding or the handler fails
+/// with.
+pub fn decode_with_handler<F>(
+    input: &str,
+    options: &DecodeOptions,
+    mut handler: F,
+) -> Result<String, DecodeError>
+where
+    F: FnMut(char, &mut String) -> Result<(), DecodeError>,
+{
+    let mut handler = |rest: &str, out: &mut String| {
+        let c = rest.chars().next()?;
+        Some(handler(c, out).map(|()| c.len_utf8()))
     };
+    decode_replacing(input, options, None, Some(&mut handler), None)
+}
 
-    // Gather all hex digits in a string
-    let mut hex_chars = String::new();
-    while let Some(&c) = chars.peek() {
-        if c.is_ascii_hexdigit() {
-            hex_chars.push(c);
-            chars.next();
-        } else {
-            break;
+/// A function decoding the unknown escape sequence whose backslash precedes the given text, which
+/// returns the number of bytes of the text it consumed, or `None` if it does not know it either.
+pub(crate) type UnknownEscapeHandler<'a> =
+    dyn FnMut(&str, &mut String) -> Option<Result<usize, DecodeError>> + 'a;
+
+/// Applies the `BomPolicy` of the options to a byte order mark at the start of `result`, the
+/// beginning of a decoded string, replacing it like an invalid escape sequence if `replacement` is
+/// given and recording its error in `errors`.
+fn check_bom(
+    result: &mut String,
+    options: &DecodeOptions,
+    replacement: Option<&str>,
+    errors: Option<&mut Vec<DecodeError>>,
+) -> Result<(), DecodeError> {
+    if result.starts_with('\u{FEFF}') {
+        match options.bom {
+            BomPolicy::Preserve => {}
+            BomPolicy::Strip => {
+                #[cfg(feature = "trace")]
+                trace::emit(&TraceEvent::Fallback(Fallback::ByteOrderMarkStripped));
+                result.remove(0);
+            }
+            BomPolicy::Reject => {
+                let error = DecodeError::new(ErrorKind::ByteOrderMark).at(0..0);
+                #[cfg(feature = "trace")]
+                trace::emit(&TraceEvent::Error {
+                    range: 0..0,
+                    error: &error,
+                });
+                let Some(replacement) = replacement else {
+                    return Err(error);
+                };
+                #[cfg(feature = "trace")]
+                trace::emit(&TraceEvent::Fallback(Fallback::ErrorReplaced));
+                result.replace_range(..'\u{FEFF}'.len_utf8(), replacement);
+                if let Some(errors) = errors {
+                    errors.push(error);
+                }
+            }
         }
     }
+    Ok(())
+}
 
-    // Remove the trailing '}'
-    match chars.next() {
-        Some('}') => {}
-        _ => return Err(DecodeError::InvalidUnicode),
-    };
+/// Decodes a string like `decode_with`, passing unknown escape sequences to `handler` and
+/// replacing the remaining errors with `replacement` if there are ones, collecting the replaced
+/// errors into `errors`.
+pub(crate) fn decode_replacing(
+    input: &str,
+    options: &DecodeOptions,
+    replacement: Option<&str>,
+    mut handler: Option<&mut UnknownEscapeHandler>,
+    mut errors: Option<&mut Vec<DecodeError>>,
+) -> Result<String, DecodeError> {
+    // The decoded string is at most as long as the input, unless handlers or replacements grow it
+    let mut result = String::with_capacity(input.len());
 
-    // Convert the stirng to a char
-    if let Ok(value) = u32::from_str_radix(&hex_chars, 16) {
-        if let Some(c) = char::from_u32(value) {
-            Ok(c)
-        } else {
-            Err(DecodeError::InvalidUnicode)
+    #[cfg(feature = "trace")]
+    trace::emit(&TraceEvent::Started { input });
+    let mut quoted_end = 0;
+    let mut bom_checked = false;
+    let mut tokens = parser::Tokens::with_options(input, options);
+    loop {
+        // The byte order mark is checked once the decoded string starts, before the rest of the
+        // input, so that its error comes before those of later escape sequences
+        if !bom_checked && !result.is_empty() {
+            bom_checked = true;
+            check_bom(&mut result, options, replacement, errors.as_deref_mut())?;
+        }
+        let start = tokens.offset();
+        let Some(token) = tokens.next() else {
+            break;
+        };
+        let token = match (token, replacement) {
+            (Ok(token), _) => token,
+            (Err(error), _)
+                if handler.is_some() && matches!(error.kind(), ErrorKind::UnknownEscape(_)) =>
+            {
+                let decoded = match (parser::unknown_escape(input, start, options), &mut handler) {
+                    (Some(_), Some(handler)) => handler(&input[start + 1..], &mut result),
+                    _ => None,
+                };
+                let span = start..tokens.offset();
+                let len = match decoded {
+                    Some(decoded) => decoded.map_err(|error| error.at(span))?,
+                    None => return Err(error),
+                };
+                tokens.seek(start + 1 + len);
+                continue;
+            }
+            (Err(error), Some(replacement)) => {
+                #[cfg(feature = "trace")]
+                trace::emit(&TraceEvent::Fallback(Fallback::ErrorReplaced));
+                result.push_str(replacement);
+                if let Some(errors) = &mut errors {
+                    errors.push(error);
+                }
+                continue;
+            }
+            (Err(error), None) => return Err(error),
+        };
+        match token {
+            Token::Literal(text) => result.push_str(text),
+            Token::Escape(escape) if options.message_format => {
+                message_format::push_literal(&mut result, escape.value, &mut quoted_end)
+            }
+            Token::Escape(escape) => result.push(escape.value),
         }
-    } else {
-        Err(DecodeError::InvalidUnicode)
     }
+    // Every escape sequence is at least as long as the UTF-8 encoding of what it decodes to
+    invariant!(
+        replacement.is_some() || handler.is_some() || result.len() <= input.len(),
+        "decoding {} bytes produced {} bytes",
+        input.len(),
+        result.len()
+    );
+    if !bom_checked {
+        check_bom(&mut result, options, replacement, errors)?;
+    }
+    if let Some(folding) = options.case_folding {
+        result = fold_case(&result, folding);
+    }
+    #[cfg(feature = "unicode-normalization")]
+    if let Some(form) = options.normalization {
+        result = normalization::normalize(&result, form);
+    }
+    #[cfg(feature = "trace")]
+    trace::emit(&TraceEvent::Finished {
+        output_len: result.len(),
+    });
+    Ok(result)
 }
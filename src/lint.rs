@@ -0,0 +1,238 @@
+//! Detects redundant and non-canonical escape sequences.
+//!
+//! This module contains the `lint` function, which reports escape sequences that could be written
+//! in a simpler or more consistent way, together with a machine-applicable replacement. The
+//! `normalize` function applies all of these replacements at once, and `is_canonical` cheaply
+//! checks whether there is anything to replace.
+//!
+//! The preferred spelling of an escaped character is:
+//!
+//! * the simple escape sequence, if one exists (e.g. `\n` rather than `\x0A`),
+//! * the character itself, if it is printable ASCII (e.g. `A` rather than `\u{41}`),
+//! * a hexadecimal escape sequence for the remaining ASCII characters (e.g. `\x1B`),
+//! * a single Unicode escape sequence without leading zeros for everything else (e.g. `\u{E9}`),
+//!
+//! always using uppercase hexadecimal digits.
+use std::ops::Range;
+
+use crate::encode::push_canonical;
+use crate::parser::{Token, Tokens};
+use crate::{DecodeOptions, EscapeKind};
+
+/// The options escape sequences are read with. Surrogate pairs are combined, although `decode`
+/// rejects them by default, so that they are reported as `LintKind::SurrogatePair`.
+const LINT_OPTIONS: DecodeOptions = {
+    let mut options = DecodeOptions::new();
+    options.combine_surrogates = true;
+    options
+};
+
+/// The reason an escape sequence was flagged by `lint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LintKind {
+    /// The escape sequence encodes a printable ASCII character that needs no escaping.
+    RedundantEscape,
+    /// The character has a simple escape sequence, e.g. `\n` instead of `\x0A`.
+    PreferSimpleEscape,
+    /// An ASCII character is written as a Unicode escape sequence instead of a hexadecimal one.
+    PreferHexEscape,
+    /// A non-ASCII character is written as a hexadecimal escape sequence instead of a Unicode one.
+    PreferUnicodeEscape,
+    /// A character is written as a surrogate pair, e.g. `\u{D83D}\u{DE00}`.
+    SurrogatePair,
+    /// A Unicode escape sequence has leading zeros, e.g. `\u{00E9}`.
+    LeadingZeros,
+    /// The escape sequence uses lowercase hexadecimal digits.
+    LowercaseHex,
+}
+
+/// A single redundant or non-canonical escape sequence found by `lint`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LintWarning {
+    /// The reason the escape sequence was flagged.
+    pub kind: LintKind,
+    /// The byte range of the escape sequence in the input.
+    pub range: Range<usize>,
+    /// A human readable description of the problem.
+    pub message: String,
+    /// The text that should replace the escape sequence.
+    pub replacement: String,
+}
+
+/// Returns the preferred spelling of a character in escaped text.
+fn canonical_escape(c: char) -> String {
+    let mut spelling = String::new();
+    push_canonical(&mut spelling, c);
+    spelling
+}
+
+/// Checks whether `source`, an escape sequence decoding to `value`, is its preferred spelling.
+///
+/// This is equivalent to comparing `source` against `canonical_escape(value)`, without allocating.
+fn is_canonical_escape(source: &str, kind: EscapeKind, value: char) -> bool {
+    let uppercase = |digits: &str| !digits.bytes().any(|b| b.is_ascii_lowercase());
+    match kind {
+        EscapeKind::Simple => true,
+        EscapeKind::Hex => {
+            value.is_ascii_control()
+                && !matches!(value, '\t' | '\n' | '\r' | '\0')
+                && uppercase(&source[2..])
+        }
+        EscapeKind::Unicode => {
+            let digits = &source[3..source.len() - 1];
+            !value.is_ascii()
+                && !digits.starts_with('0')
+                && digits.bytes().all(|b| b.is_ascii_hexdigit())
+                && uppercase(digits)
+        }
+        // Names are spelled out on purpose, for readability
+        EscapeKind::Named => true,
+        EscapeKind::Octal | EscapeKind::Decimal | EscapeKind::Invalid => false,
+    }
+}
+
+/// Determines why `source`, an escape sequence decoding to `value`, is not canonical.
+fn classify(source: &str, kind: EscapeKind, value: char, canonical: &str) -> LintKind {
+    if !canonical.starts_with('\\') {
+        LintKind::RedundantEscape
+    } else if kind != EscapeKind::Simple && canonical.len() == 2 {
+        LintKind::PreferSimpleEscape
+    } else if kind == EscapeKind::Unicode && value.is_ascii() {
+        LintKind::PreferHexEscape
+    } else if kind == EscapeKind::Hex && !value.is_ascii() {
+        LintKind::PreferUnicodeEscape
+    } else if source[1..].contains('\\') {
+        LintKind::SurrogatePair
+    } else if source.starts_with(r"\u{0") && value != '\0' {
+        LintKind::LeadingZeros
+    } else {
+        LintKind::LowercaseHex
+    }
+}
+
+/// Returns the message describing a warning of the given kind.
+fn message(kind: LintKind, source: &str, replacement: &str) -> String {
+    match kind {
+        LintKind::RedundantEscape => {
+            format!(
+                "`{source}` is a printable character and needs no escaping, use `{replacement}`"
+            )
+        }
+        LintKind::PreferSimpleEscape => {
+            format!("use the simple escape `{replacement}` instead of `{source}`")
+        }
+        LintKind::PreferHexEscape => {
+            format!("ASCII characters should use a hexadecimal escape, use `{replacement}`")
+        }
+        LintKind::PreferUnicodeEscape => {
+            format!("non-ASCII characters should use a Unicode escape, use `{replacement}`")
+        }
+        LintKind::SurrogatePair => {
+            format!("`{source}` is a surrogate pair, use the single escape `{replacement}`")
+        }
+        LintKind::LeadingZeros => format!("`{source}` has leading zeros, use `{replacement}`"),
+        LintKind::LowercaseHex => {
+            format!("`{source}` uses lowercase hexadecimal digits, use `{replacement}`")
+        }
+    }
+}
+
+/// Finds redundant and non-canonical escape sequences in a string.
+///
+/// Every valid escape sequence is compared against the preferred spelling described in the module
+/// documentation. Literal text and invalid escape sequences are not reported; use `validate_all`
+/// to find the latter.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `LintWarning` for every flagged escape sequence, in input order. Replacing each warning's
+/// range with its replacement yields a string that decodes to the same value.
+pub fn lint(input: &str) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+    let mut tokens = Tokens::with_options(input, &LINT_OPTIONS);
+    loop {
+        let start = tokens.offset();
+        let escape = match tokens.next() {
+            None => break,
+            Some(Ok(Token::Escape(escape))) => escape,
+            Some(_) => continue,
+        };
+        let source = &input[start..escape.end];
+        if is_canonical_escape(source, escape.kind, escape.value) {
+            continue;
+        }
+        let canonical = canonical_escape(escape.value);
+        let kind = classify(source, escape.kind, escape.value, &canonical);
+        warnings.push(LintWarning {
+            kind,
+            range: start..escape.end,
+            message: message(kind, source, &canonical),
+            replacement: canonical,
+        });
+    }
+    warnings
+}
+
+/// Rewrites every escape sequence of a string into its preferred spelling.
+///
+/// This applies the replacements suggested by `lint`. Literal text and invalid escape sequences
+/// are copied unchanged, so the result decodes to the same value as the input whenever the input
+/// can be decoded at all.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The normalized string.
+pub fn normalize(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut tokens = Tokens::with_options(input, &LINT_OPTIONS);
+    loop {
+        let start = tokens.offset();
+        match tokens.next() {
+            None => break,
+            Some(Ok(Token::Escape(escape)))
+                if !is_canonical_escape(&input[start..escape.end], escape.kind, escape.value) =>
+            {
+                result.push_str(&canonical_escape(escape.value))
+            }
+            Some(_) => result.push_str(&input[start..tokens.offset()]),
+        }
+    }
+    result
+}
+
+/// Checks whether a string is already in its normalized form.
+///
+/// This is equivalent to `normalize(input) == input`, but does not allocate, so formatters can
+/// cheaply skip inputs that are already clean.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// `true` if `lint` reports no warnings for the input, `false` otherwise.
+pub fn is_canonical(input: &str) -> bool {
+    let mut tokens = Tokens::with_options(input, &LINT_OPTIONS);
+    loop {
+        let start = tokens.offset();
+        match tokens.next() {
+            None => return true,
+            Some(Ok(Token::Escape(escape))) => {
+                if !is_canonical_escape(&input[start..escape.end], escape.kind, escape.value) {
+                    return false;
+                }
+            }
+            Some(_) => {}
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Keeps escaped strings compatible with ICU MessageFormat patterns.
+//!
+//! In a MessageFormat pattern such as `Hello {name}`, braces delimit placeholders and apostrophes
+//! quote literal text, e.g. `'{'` for a literal brace. With `DecodeOptions::message_format`,
+//! escape sequences producing one of these characters are decoded to their quoted form, so that
+//! `\x7B` cannot turn into the start of a placeholder. With `EncodeOptions::message_format`, no
+//! escape sequence contains a brace, so that tools extracting placeholders from the escaped
+//! pattern only find the real ones.
+
+/// Appends a character produced by an escape sequence to `out`, quoting it if it is
+/// MessageFormat syntax.
+///
+/// `quoted_end` is the length of `out` after the last quoted run. A brace directly following
+/// it joins the same run, because `'{''}'` would be read as `{'}`, and so does an apostrophe, as
+/// otherwise a brace following the apostrophe could not join the run.
+pub(crate) fn push_literal(out: &mut String, c: char, quoted_end: &mut usize) {
+    match c {
+        '{' | '}' => {
+            if out.len() == *quoted_end && out.ends_with('\'') {
+                out.pop();
+            } else {
+                out.push('\'');
+            }
+            out.push(c);
+            out.push('\'');
+            *quoted_end = out.len();
+        }
+        // Inside a quoted run, the doubled apostrophe must stay inside it
+        '\'' if out.len() == *quoted_end && out.ends_with('\'') => {
+            out.push_str("''");
+            *quoted_end = out.len();
+        }
+        '\'' => out.push_str("''"),
+        _ => out.push(c),
+    }
+}
@@ -0,0 +1,223 @@
+//! Looks up Unicode character names.
+//!
+//! This module is only available with the `names` feature, which embeds the character names and
+//! name aliases of the Unicode Character Database. The feature also enables named escape
+//! sequences such as `\N{BULLET}` in `decode`. The tables in `src/tables/` are generated by
+//! `scripts/generate_tables.py`.
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use crate::parser::{Token, Tokens};
+use crate::EscapeKind;
+
+const NAMES: &str = include_str!("tables/names.txt");
+const NAME_ALIASES: &str = include_str!("tables/name_aliases.txt");
+
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+const HANGUL_BASE: u32 = 0xAC00;
+const HANGUL_PREFIX: &str = "HANGUL SYLLABLE ";
+
+/// The parsed name tables.
+struct Names {
+    /// Names sorted by code point. A name containing `*` covers a range of code points whose
+    /// names are derived from the code point.
+    by_code: Vec<(Range<u32>, &'static str)>,
+    /// Code points by uppercase name or alias, excluding derived names.
+    by_name: HashMap<&'static str, u32>,
+    /// The first control alias of code points that have no name, such as control characters.
+    control_aliases: HashMap<u32, &'static str>,
+}
+
+/// Returns the lines of a generated table, without its header.
+fn table_lines(table: &'static str) -> impl Iterator<Item = &'static str> {
+    table.lines().filter(|line| !line.starts_with('#'))
+}
+
+fn names() -> &'static Names {
+    static NAMES_TABLE: OnceLock<Names> = OnceLock::new();
+    NAMES_TABLE.get_or_init(|| {
+        let mut names = Names {
+            by_code: Vec::new(),
+            by_name: HashMap::new(),
+            control_aliases: HashMap::new(),
+        };
+        for line in table_lines(NAMES) {
+            let (code, name) = line.split_once(' ').expect("malformed names table");
+            let (first, last) = code.split_once("..").unwrap_or((code, code));
+            let first = u32::from_str_radix(first, 16).expect("malformed names table");
+            let last = u32::from_str_radix(last, 16).expect("malformed names table");
+            names.by_code.push((first..last + 1, name));
+            if !name.contains('*') {
+                names.by_name.insert(name, first);
+            }
+        }
+        for line in table_lines(NAME_ALIASES) {
+            let mut fields = line.splitn(3, ' ');
+            let (Some(code), Some(kind), Some(alias)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                panic!("malformed name aliases table");
+            };
+            let code = u32::from_str_radix(code, 16).expect("malformed name aliases table");
+            names.by_name.entry(alias).or_insert(code);
+            if kind == "control" {
+                names.control_aliases.entry(code).or_insert(alias);
+            }
+        }
+        names
+    })
+}
+
+/// Returns the derived name of `code` for a range with the given name pattern.
+fn derived_name(pattern: &str, code: u32) -> String {
+    if pattern.starts_with(HANGUL_PREFIX) {
+        let index = code - HANGUL_BASE;
+        let (l, v, t) = (index / (21 * 28), index % (21 * 28) / 28, index % 28);
+        format!(
+            "{HANGUL_PREFIX}{}{}{}",
+            JAMO_L[l as usize], JAMO_V[v as usize], JAMO_T[t as usize]
+        )
+    } else {
+        pattern.replace('*', &format!("{code:04X}"))
+    }
+}
+
+/// Returns the code point of a derived name, e.g. `CJK UNIFIED IDEOGRAPH-4E00`.
+fn lookup_derived(names: &Names, name: &str) -> Option<u32> {
+    if let Some(syllable) = name.strip_prefix(HANGUL_PREFIX) {
+        for (l, lead) in JAMO_L.iter().enumerate() {
+            let Some(rest) = syllable.strip_prefix(lead) else {
+                continue;
+            };
+            for (v, vowel) in JAMO_V.iter().enumerate() {
+                let Some(rest) = rest.strip_prefix(vowel) else {
+                    continue;
+                };
+                if let Some(t) = JAMO_T.iter().position(|trail| *trail == rest) {
+                    return Some(HANGUL_BASE + ((l * 21 + v) * 28 + t) as u32);
+                }
+            }
+        }
+        return None;
+    }
+
+    let (prefix, digits) = name.rsplit_once('-')?;
+    let code = u32::from_str_radix(digits, 16).ok()?;
+    names
+        .by_code
+        .iter()
+        .filter(|(range, _)| range.contains(&code))
+        .find(|(_, pattern)| pattern.strip_suffix("-*") == Some(prefix))
+        .map(|_| code)
+}
+
+/// Returns the Unicode name of a character.
+///
+/// Characters without a name, such as control characters, are named by their first control alias
+/// instead, e.g. `LINE FEED` for U+000A.
+///
+/// # Parameters
+///
+/// * `c`: The character to look up.
+///
+/// # Returns
+///
+/// The name of the character, or `None` if it has neither a name nor a control alias.
+pub fn char_name(c: char) -> Option<String> {
+    let names = names();
+    let code = u32::from(c);
+    let index = names
+        .by_code
+        .partition_point(|(range, _)| range.end <= code);
+    match names.by_code.get(index) {
+        Some((range, name)) if range.contains(&code) => Some(if name.contains('*') {
+            derived_name(name, code)
+        } else {
+            name.to_string()
+        }),
+        _ => names
+            .control_aliases
+            .get(&code)
+            .map(|alias| alias.to_string()),
+    }
+}
+
+/// Looks up a character by its Unicode name or name alias.
+///
+/// The lookup ignores case, so `bullet` and `BULLET` both resolve to U+2022.
+///
+/// # Parameters
+///
+/// * `name`: The name or name alias of a character, e.g. `GREEK SMALL LETTER ALPHA`.
+///
+/// # Returns
+///
+/// The named character, or `None` if no character has this name.
+pub fn lookup(name: &str) -> Option<char> {
+    let names = names();
+    let name = name.to_ascii_uppercase();
+    names
+        .by_name
+        .get(name.as_str())
+        .copied()
+        .or_else(|| lookup_derived(names, &name))
+        .and_then(char::from_u32)
+}
+
+/// A code point produced by a Unicode or named escape sequence, as reported by
+/// `code_point_report`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CodePointReference {
+    /// The byte range of the escape sequence in the input.
+    pub range: Range<usize>,
+    /// The character the escape sequence decodes to.
+    pub code_point: char,
+    /// The name of the character, as returned by `char_name`.
+    pub name: Option<String>,
+}
+
+/// Lists the code points referenced by the Unicode and named escape sequences of a string.
+///
+/// This is meant for reviewing escaped text such as localization files, where `\u{2011}` means
+/// little to a reader while `NON-BREAKING HYPHEN` does. Invalid escape sequences are skipped.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `CodePointReference` for every Unicode or named escape sequence, in input order.
+pub fn code_point_report(input: &str) -> Vec<CodePointReference> {
+    let mut report = Vec::new();
+    let mut tokens = Tokens::new(input);
+    loop {
+        let start = tokens.offset();
+        match tokens.next() {
+            None => break,
+            Some(Ok(Token::Escape(escape)))
+                if matches!(escape.kind, EscapeKind::Unicode | EscapeKind::Named) =>
+            {
+                report.push(CodePointReference {
+                    range: start..escape.end,
+                    code_point: escape.value,
+                    name: char_name(escape.value),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    report
+}
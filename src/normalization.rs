@@ -0,0 +1,163 @@
+//! Normalizes decoded text into one of the Unicode normalization forms.
+//!
+//! This module is only available with the `unicode-normalization` feature. It implements the
+//! normalization algorithm of Unicode Standard Annex #15, which `decode_with` applies to the
+//! decoded string when `DecodeOptions::normalization` is set. Escaped text often spells accented
+//! letters as combining sequences, e.g. `e\u{301}`, which then compare unequal to the precomposed
+//! character stored elsewhere.
+
+include!("tables/normalization.rs");
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_V_COUNT * HANGUL_T_COUNT;
+
+/// A Unicode normalization form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+fn combining_class(c: char) -> u8 {
+    let code = u32::from(c);
+    let index = COMBINING_CLASS.partition_point(|&(_, last, _)| last < code);
+    match COMBINING_CLASS.get(index) {
+        Some(&(first, _, value)) if first <= code => value,
+        _ => 0,
+    }
+}
+
+fn decomposition(table: &'static [(u32, &'static [u32])], code: u32) -> Option<&'static [u32]> {
+    table
+        .binary_search_by_key(&code, |&(c, _)| c)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Appends the full decomposition of a character to `out`.
+fn decompose(c: char, compatibility: bool, out: &mut Vec<char>) {
+    let code = u32::from(c);
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&code) {
+        let index = code - HANGUL_S_BASE;
+        let jamo = [
+            HANGUL_L_BASE + index / (HANGUL_V_COUNT * HANGUL_T_COUNT),
+            HANGUL_V_BASE + index % (HANGUL_V_COUNT * HANGUL_T_COUNT) / HANGUL_T_COUNT,
+            HANGUL_T_BASE + index % HANGUL_T_COUNT,
+        ];
+        let len = if jamo[2] == HANGUL_T_BASE { 2 } else { 3 };
+        out.extend(jamo[..len].iter().filter_map(|&code| char::from_u32(code)));
+        return;
+    }
+
+    let parts = decomposition(CANONICAL_DECOMPOSITION, code).or_else(|| {
+        compatibility
+            .then(|| decomposition(COMPATIBILITY_DECOMPOSITION, code))
+            .flatten()
+    });
+    match parts {
+        Some(parts) => {
+            for part in parts.iter().filter_map(|&code| char::from_u32(code)) {
+                decompose(part, compatibility, out);
+            }
+        }
+        None => out.push(c),
+    }
+}
+
+/// Sorts every run of non-starters by their canonical combining class, keeping the order of
+/// characters with equal classes.
+fn canonical_order(chars: &mut [char]) {
+    for i in 1..chars.len() {
+        let class = combining_class(chars[i]);
+        if class == 0 {
+            continue;
+        }
+        let mut j = i;
+        while j > 0 && combining_class(chars[j - 1]) > class {
+            chars.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Returns the primary composite of two characters, if any.
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first, second) = (u32::from(first), u32::from(second));
+    if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&first)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&second)
+    {
+        let index = (first - HANGUL_L_BASE) * HANGUL_V_COUNT + (second - HANGUL_V_BASE);
+        return char::from_u32(HANGUL_S_BASE + index * HANGUL_T_COUNT);
+    }
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&first)
+        && (first - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT)
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&second)
+    {
+        return char::from_u32(first + second - HANGUL_T_BASE);
+    }
+    COMPOSITION
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()
+        .and_then(|index| char::from_u32(COMPOSITION[index].2))
+}
+
+/// Canonically composes a canonically ordered sequence of characters.
+fn compose(chars: Vec<char>) -> Vec<char> {
+    let mut result: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    for c in chars {
+        let class = combining_class(c);
+        if let Some(index) = starter {
+            // A character is blocked from the starter by a character in between with a class of
+            // zero or at least its own
+            let last = combining_class(result[result.len() - 1]);
+            let adjacent = index == result.len() - 1;
+            if adjacent || (last != 0 && last < class) {
+                if let Some(composite) = compose_pair(result[index], c) {
+                    result[index] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(result.len());
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Normalizes a string into the given normalization form.
+///
+/// # Parameters
+///
+/// * `text`: The text to normalize, typically a decoded string.
+/// * `form`: The normalization form to produce.
+///
+/// # Returns
+///
+/// The normalized string.
+pub fn normalize(text: &str, form: NormalizationForm) -> String {
+    let compatibility = matches!(form, NormalizationForm::Nfkc | NormalizationForm::Nfkd);
+    let mut chars = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose(c, compatibility, &mut chars);
+    }
+    canonical_order(&mut chars);
+    if matches!(form, NormalizationForm::Nfc | NormalizationForm::Nfkc) {
+        chars = compose(chars);
+    }
+    chars.into_iter().collect()
+}
@@ -0,0 +1,58 @@
+//! Parses and formats the `U+XXXX` code point notation.
+//!
+//! This module contains `parse_codepoint_notation` and `format_codepoint_notation`, which convert
+//! between characters and the plain text notation used by the Unicode Standard, specifications
+//! and bug reports, e.g. `U+1F600`.
+use crate::parser::invalid_code_point;
+use crate::{DecodeError, ErrorKind};
+
+/// Parses a code point written in `U+XXXX` notation.
+///
+/// The notation consists of `U+` followed by four to six hexadecimal digits. The `U` and the
+/// digits may be written in either case.
+///
+/// # Parameters
+///
+/// * `notation`: The code point notation, e.g. `U+1F600`.
+///
+/// # Returns
+///
+/// A `Result` containing the character or an error if the notation is invalid.
+///
+/// # Errors
+///
+/// This function will return an error of type `ErrorKind::MissingNotationPrefix` if the notation
+/// does not start with `U+`, `ErrorKind::InvalidHexDigit` with the first character other than a
+/// hexadecimal digit following it, `ErrorKind::UnexpectedEof` if there are
+/// fewer than four digits, `ErrorKind::OverlongUnicode` if there are more than six, and
+/// `ErrorKind::SurrogateCodePoint` or `ErrorKind::CodePointOutOfRange` if the code point is not
+/// a valid character.
+pub fn parse_codepoint_notation(notation: &str) -> Result<char, DecodeError> {
+    let digits = notation
+        .strip_prefix("U+")
+        .or_else(|| notation.strip_prefix("u+"))
+        .ok_or(ErrorKind::MissingNotationPrefix)?;
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(ErrorKind::InvalidHexDigit(c).into());
+    }
+    match digits.len() {
+        0..=3 => return Err(ErrorKind::UnexpectedEof.into()),
+        7.. => return Err(ErrorKind::OverlongUnicode.into()),
+        _ => {}
+    }
+    let code = u32::from_str_radix(digits, 16).map_err(|_| ErrorKind::OverlongUnicode)?;
+    char::from_u32(code).ok_or_else(|| invalid_code_point(code).into())
+}
+
+/// Formats a character in `U+XXXX` notation, with uppercase digits and at least four of them.
+///
+/// # Parameters
+///
+/// * `c`: The character to format.
+///
+/// # Returns
+///
+/// The code point notation of the character, e.g. `U+00E9` for `é`.
+pub fn format_codepoint_notation(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
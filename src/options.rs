@@ -0,0 +1,972 @@
+//! Configures how escape sequences are decoded.
+//!
+//! This module contains the `DecodeOptions` accepted by `decode_with`, and the `Dialect`s whose
+//! escape syntax they can be preset to.
+#[cfg(feature = "unicode-age")]
+use crate::age::UnicodeVersion;
+use crate::case_folding::CaseFolding;
+#[cfg(feature = "unicode-normalization")]
+use crate::normalization::NormalizationForm;
+
+/// What `decode_with` does with escape sequences that produce a noncharacter.
+///
+/// Noncharacters are the 66 code points U+FDD0 to U+FDEF and the last two code points of every
+/// plane, such as U+FFFE and U+10FFFF, which are reserved for internal use and should not appear
+/// in interchanged text. The policy applies to escape sequences only, not to literal text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NoncharacterPolicy {
+    /// Decodes noncharacters like any other character.
+    #[default]
+    Allow,
+    /// Rejects noncharacters with `ErrorKind::Noncharacter`.
+    Reject,
+    /// Replaces noncharacters with U+FFFD REPLACEMENT CHARACTER.
+    Replace,
+}
+
+/// What `decode_with` does with a byte order mark, U+FEFF, at the start of the decoded string.
+///
+/// The policy applies whether the byte order mark is written literally or as an escape sequence
+/// such as `\u{FEFF}`. Byte order marks in the middle of the string are always preserved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BomPolicy {
+    /// Keeps the byte order mark.
+    #[default]
+    Preserve,
+    /// Removes the byte order mark.
+    Strip,
+    /// Rejects the string with `ErrorKind::ByteOrderMark`.
+    Reject,
+}
+
+/// How `decode_with` reads octal escape sequences, a backslash followed by octal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OctalEscapes {
+    /// One to three octal digits, e.g. `\7`, `\101` and `\777`, as in Python and C.
+    UpToThree,
+    /// One to three octal digits, as many as keep the value at most `\377`, e.g. `\101`, while
+    /// `\400` is `\40` followed by `0`, as in the legacy octal escape sequences of JavaScript.
+    UpToByte,
+    /// Exactly three octal digits with a value of at most `\377`, e.g. `\101`, as in Go. Other
+    /// octal escape sequences are rejected, such as `\0` with `ErrorKind::InvalidHexDigit` or
+    /// `ErrorKind::UnexpectedEof` and `\400` with `ErrorKind::CodePointOutOfRange`.
+    ExactlyThree,
+}
+
+/// What `decode_with` does with a backslash followed by a character that does not start an
+/// escape sequence accepted by the options, such as `\q`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UnknownEscapePolicy {
+    /// Rejects the escape sequence with `ErrorKind::UnknownEscape`.
+    #[default]
+    Reject,
+    /// Keeps the backslash and the character following it, as in Python.
+    Preserve,
+    /// Removes the backslash and keeps the character following it, as in JavaScript.
+    StripBackslash,
+    /// Replaces the backslash and the character following it with the given character, e.g.
+    /// with U+FFFD REPLACEMENT CHARACTER.
+    Replace(char),
+}
+
+/// How `decode_with` reads a line continuation, a backslash directly followed by a line break.
+///
+/// A line break is a line feed, a carriage return, or a carriage return followed by a line feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LineContinuation {
+    /// Removes the backslash and the line break, as in Python and C.
+    Remove,
+    /// Like `Remove`, but U+2028 LINE SEPARATOR and U+2029 PARAGRAPH SEPARATOR are line breaks as
+    /// well, as in JavaScript.
+    RemoveUnicode,
+    /// Removes the backslash, the line break and all spaces, tabs and line breaks following it,
+    /// as in Rust.
+    TrimWhitespace,
+    /// Like `TrimWhitespace`, but also accepts spaces and tabs between the backslash and the line
+    /// break, as in TOML multi-line basic strings.
+    TrimAllWhitespace,
+    /// Replaces the backslash and the line break with a line feed, as in Lua, where a line feed
+    /// followed by a carriage return is a single line break as well.
+    Newline,
+}
+
+/// A set of simple escape sequences, each a backslash followed by a single ASCII character.
+///
+/// The characters `t`, `n`, `r`, `0`, `a`, `b`, `e`, `E`, `f` and `v` stand for the control
+/// characters of their C escape sequences (`e` and `E` for ESC, U+001B), `s` for a space, as in
+/// Java, and `Z` for U+001A SUBSTITUTE, as in MySQL. Every other character stands for itself, e.g.
+/// `\/` for `/`. The characters `x`, `u`, `U` and `N` always start their own kind of escape
+/// sequence and cannot be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SimpleEscapes {
+    /// One bit for every ASCII character.
+    set: u128,
+}
+
+impl SimpleEscapes {
+    /// No simple escape sequences.
+    pub const NONE: SimpleEscapes = SimpleEscapes { set: 0 };
+
+    /// The simple escape sequences of `decode`: `\t`, `\n`, `\r`, `\0`, `\\`, `\"` and `\'`.
+    pub const DEFAULT: SimpleEscapes = SimpleEscapes::NONE
+        .with('t')
+        .with('n')
+        .with('r')
+        .with('0')
+        .with('\\')
+        .with('"')
+        .with('\'');
+
+    /// The simple escape sequences of `DEFAULT` together with those of the remaining control
+    /// characters of C and bash: `\a` (bell), `\b` (backspace), `\f` (form feed), `\v` (vertical
+    /// tab) and `\e` (escape, U+001B).
+    pub const CONTROLS: SimpleEscapes = SimpleEscapes::DEFAULT
+        .with('a')
+        .with('b')
+        .with('f')
+        .with('v')
+        .with('e');
+
+    /// Returns the set with the escape sequence of a character added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` is not ASCII or is one of `x`, `u`, `U` and `N`.
+    pub const fn with(self, c: char) -> SimpleEscapes {
+        assert!(
+            c.is_ascii() && !matches!(c, 'x' | 'u' | 'U' | 'N'),
+            "not a simple escape character"
+        );
+        SimpleEscapes {
+            set: self.set | 1 << c as u32,
+        }
+    }
+
+    /// Returns the set with the escape sequence of a character removed.
+    pub const fn without(self, c: char) -> SimpleEscapes {
+        if !c.is_ascii() {
+            return self;
+        }
+        SimpleEscapes {
+            set: self.set & !(1 << c as u32),
+        }
+    }
+
+    /// Checks whether the set contains the escape sequence of a character.
+    pub const fn contains(self, c: char) -> bool {
+        c.is_ascii() && self.set & 1 << c as u32 != 0
+    }
+
+    /// Returns the character the escape sequence of `c` stands for.
+    pub(crate) const fn value(c: char) -> char {
+        match c {
+            't' => '\t',
+            'n' => '\n',
+            'r' => '\r',
+            '0' => '\0',
+            'a' => '\x07',
+            'b' => '\x08',
+            'e' | 'E' => '\x1B',
+            'f' => '\x0C',
+            'v' => '\x0B',
+            's' => ' ',
+            'Z' => '\x1A',
+            _ => c,
+        }
+    }
+}
+
+impl Default for SimpleEscapes {
+    fn default() -> SimpleEscapes {
+        SimpleEscapes::DEFAULT
+    }
+}
+
+/// A string literal syntax whose escape sequences `DecodeOptions::dialect` can decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// JSON strings as defined by RFC 8259: the simple escape sequences `\"`, `\\`, `\/`,
+    /// `\b`, `\f`, `\n`, `\r` and `\t`, and `\uXXXX` with exactly four hexadecimal digits,
+    /// where a surrogate pair such as `\uD83D\uDE00` is combined into one character.
+    Json,
+    /// Python string literals: the simple escape sequences `\\`, `\'`, `\"`, `\a`, `\b`, `\f`,
+    /// `\n`, `\r`, `\t` and `\v`, octal escape sequences of up to three digits, `\xHH`, `\uXXXX`,
+    /// `\UXXXXXXXX`, `\N{name}` with the `names` feature, and line continuations. The backslash
+    /// of any other escape sequence is kept, e.g. `\q` decodes to itself.
+    ///
+    /// Python strings may contain lone surrogates, which Rust strings cannot, so `\uD83D` is
+    /// rejected with `ErrorKind::SurrogateCodePoint`, and so is a surrogate pair such as
+    /// `\uD83D\uDE00`, which Python does not combine either.
+    Python,
+    /// C and C++ string literals: the simple escape sequences `\\`, `\'`, `\"`, `\?`, `\a`, `\b`,
+    /// `\f`, `\n`, `\r`, `\t` and `\v`, octal escape sequences of up to three digits, hexadecimal
+    /// escape sequences taking the longest run of digits, e.g. `\x41BC` for U+41BC, `\uXXXX`,
+    /// `\UXXXXXXXX` and line continuations.
+    ///
+    /// Octal and hexadecimal escape sequences decode to the code point of their value, as in
+    /// `char32_t` and wide string literals, rather than to a byte of the execution character set.
+    C,
+    /// Rust string literals, decoded like `DecodeOptions::rustc_compat` does, with line
+    /// continuations removing the whitespace at the start of the next line.
+    Rust,
+    /// TOML basic strings: the simple escape sequences `\b`, `\t`, `\n`, `\f`, `\r`, `\"` and `\\`,
+    /// `\uXXXX` and `\UXXXXXXXX`, and the line ending backslashes of multi-line basic strings,
+    /// which remove all whitespace up to the next other character.
+    Toml,
+    /// The ANSI-C quoted strings of bash and zsh, `$'...'`: the simple escape sequences `\\`,
+    /// `\'`, `\"`, `\?`, `\a`, `\b`, `\e`, `\E`, `\f`, `\n`, `\r`, `\t` and `\v`, control escape
+    /// sequences such as `\cA`, octal escape sequences of up to three digits, `\xHH` with one or
+    /// two digits, `\uHHHH` with one to four and `\UHHHHHHHH` with one to eight. The backslash of
+    /// any other escape sequence is kept, e.g. `\q` decodes to itself.
+    ///
+    /// Octal and hexadecimal escape sequences decode to the code point of their value, rather than
+    /// to a byte that may not be valid UTF-8 as in the shell.
+    Shell,
+    /// JavaScript string literals: the simple escape sequences `\\`, `\'`, `\"`, `\b`, `\f`,
+    /// `\n`, `\r`, `\t` and `\v`, legacy octal escape sequences such as `\0` and `\101`, `\xHH`,
+    /// `\uXXXX` and `\u{...}`, where surrogate pairs are combined, and line continuations before
+    /// line feeds, carriage returns and U+2028 and U+2029. The backslash of any other escape
+    /// sequence is removed, e.g. `\q` decodes to `q`.
+    ///
+    /// JavaScript strings may contain lone surrogates, which Rust strings cannot, so they are
+    /// replaced with U+FFFD, as when a JavaScript engine encodes such a string as UTF-8.
+    JavaScript,
+    /// Go interpreted string literals: the simple escape sequences `\\`, `\"`, `\a`, `\b`, `\f`,
+    /// `\n`, `\r`, `\t` and `\v`, octal escape sequences of exactly three digits, `\xHH`,
+    /// `\uXXXX` and `\UXXXXXXXX`. Surrogates are rejected, as in Go.
+    ///
+    /// Octal and hexadecimal escape sequences decode to the code point of their value, rather than
+    /// to a byte that may not be valid UTF-8 as in Go.
+    Go,
+    /// Java string literals: the simple escape sequences `\\`, `\"`, `\'`, `\b`, `\f`, `\n`, `\r`,
+    /// `\s` and `\t`, octal escape sequences up to `\377` and `\uXXXX`, which is translated before
+    /// the other escape sequences and may repeat the `u`, as in Java. Surrogate pairs are combined,
+    /// and lone surrogates are rejected, since a Rust string cannot hold them.
+    Java,
+    /// C# regular string literals: the simple escape sequences `\\`, `\'`, `\"`, `\0`, `\a`, `\b`,
+    /// `\f`, `\n`, `\r`, `\t` and `\v`, `\x` with one to four hexadecimal digits, `\uXXXX` and
+    /// `\UXXXXXXXX`, as in the Roslyn lexer. Surrogate pairs written as `\uXXXX` are combined, and
+    /// lone surrogates are rejected, since a Rust string cannot hold them.
+    CSharp,
+    /// Lua short literal strings: the simple escape sequences `\\`, `\"`, `\'`, `\a`, `\b`, `\f`,
+    /// `\n`, `\r`, `\t` and `\v`, decimal escape sequences of up to three digits, `\xHH`, `\u{...}`
+    /// and `\z`, and a backslash before a line break standing for a line feed, as in Lua 5.4.
+    /// Surrogates are rejected.
+    ///
+    /// Decimal and hexadecimal escape sequences decode to the code point of their value, rather
+    /// than to a byte that may not be valid UTF-8 as in Lua.
+    Lua,
+    /// PostgreSQL escape string constants, `E'...'`: the simple escape sequences `\b`, `\f`, `\n`,
+    /// `\r` and `\t`, octal escape sequences of up to three digits, `\x` with one or two
+    /// hexadecimal digits, `\uXXXX` and `\UXXXXXXXX`, and `''` for a single quote. Any other
+    /// character following a backslash stands for itself, e.g. `\'` for a single quote, except for
+    /// `x` without hexadecimal digits, which is rejected. Surrogate pairs written as `\uXXXX` are
+    /// combined, and lone surrogates are rejected, as in PostgreSQL.
+    ///
+    /// Octal and hexadecimal escape sequences decode to the code point of their value, rather
+    /// than to a byte of the UTF-8 encoding as in PostgreSQL, where `\xC3\xA9` stands for `é`.
+    PostgreSql,
+    /// Quoted CSV fields as described in RFC 4180, which write a double quote as `""` and have no
+    /// escape sequences, so backslashes are literal text. Enable `backslash_escapes` to read the
+    /// escape sequences of `DecodeOptions::new` as well, e.g. for control characters embedded in
+    /// exported fields.
+    Csv,
+    /// MySQL string literals: the simple escape sequences `\0`, `\'`, `\"`, `\b`, `\n`, `\r`, `\t`,
+    /// `\Z` and `\\`, and `''` for a single quote. Any other character following a backslash
+    /// stands for itself, except `%` and `_`, whose backslash MySQL keeps for `LIKE` patterns, so
+    /// that `\%` stays `\%`.
+    MySql,
+}
+
+/// Options controlling how `decode_with` interprets escape sequences.
+///
+/// The default options produce the same output as `decode`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeOptions {
+    pub(crate) backslash_escapes: bool,
+    pub(crate) simple_escapes: bool,
+    pub(crate) simple_escape_set: SimpleEscapes,
+    pub(crate) hex_escapes: bool,
+    pub(crate) unicode_escapes: bool,
+    pub(crate) ascii_hex: bool,
+    pub(crate) min_hex_digits: usize,
+    pub(crate) max_hex_digits: Option<usize>,
+    pub(crate) braced_unicode: bool,
+    pub(crate) fixed_unicode: bool,
+    pub(crate) long_unicode: bool,
+    pub(crate) short_unicode: bool,
+    pub(crate) unicode_prepass: bool,
+    pub(crate) max_unicode_digits: Option<usize>,
+    pub(crate) combine_surrogates: bool,
+    pub(crate) replace_lone_surrogates: bool,
+    pub(crate) digit_separators: bool,
+    pub(crate) code_point_groups: bool,
+    pub(crate) octal_escapes: Option<OctalEscapes>,
+    pub(crate) octal_zero: bool,
+    pub(crate) control_escapes: bool,
+    pub(crate) decimal_escapes: bool,
+    pub(crate) skip_whitespace: bool,
+    pub(crate) line_continuation: Option<LineContinuation>,
+    pub(crate) unknown_escapes: UnknownEscapePolicy,
+    pub(crate) preserved_escapes: SimpleEscapes,
+    pub(crate) doubled_quote: Option<char>,
+    #[cfg(feature = "names")]
+    pub(crate) named_escapes: bool,
+    pub(crate) message_format: bool,
+    pub(crate) noncharacters: NoncharacterPolicy,
+    pub(crate) bom: BomPolicy,
+    #[cfg(feature = "unicode-age")]
+    pub(crate) assigned_only: Option<UnicodeVersion>,
+    pub(crate) case_folding: Option<CaseFolding>,
+    #[cfg(feature = "unicode-normalization")]
+    pub(crate) normalization: Option<NormalizationForm>,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> DecodeOptions {
+        DecodeOptions::new()
+    }
+}
+
+impl DecodeOptions {
+    /// The default options, usable in constant contexts.
+    pub(crate) const DEFAULT: DecodeOptions = DecodeOptions::new();
+
+    /// Creates the default options.
+    pub const fn new() -> DecodeOptions {
+        DecodeOptions {
+            backslash_escapes: true,
+            simple_escapes: true,
+            simple_escape_set: SimpleEscapes::DEFAULT,
+            hex_escapes: true,
+            unicode_escapes: true,
+            ascii_hex: false,
+            min_hex_digits: 2,
+            max_hex_digits: Some(2),
+            braced_unicode: true,
+            fixed_unicode: false,
+            long_unicode: false,
+            short_unicode: false,
+            unicode_prepass: false,
+            max_unicode_digits: None,
+            combine_surrogates: false,
+            replace_lone_surrogates: false,
+            digit_separators: false,
+            code_point_groups: false,
+            octal_escapes: None,
+            octal_zero: true,
+            control_escapes: false,
+            decimal_escapes: false,
+            skip_whitespace: false,
+            line_continuation: None,
+            unknown_escapes: UnknownEscapePolicy::Reject,
+            preserved_escapes: SimpleEscapes::NONE,
+            doubled_quote: None,
+            #[cfg(feature = "names")]
+            named_escapes: true,
+            message_format: false,
+            noncharacters: NoncharacterPolicy::Allow,
+            bom: BomPolicy::Preserve,
+            #[cfg(feature = "unicode-age")]
+            assigned_only: None,
+            case_folding: None,
+            #[cfg(feature = "unicode-normalization")]
+            normalization: None,
+        }
+    }
+
+    /// Creates options that decode the escape sequences of a dialect, and reject every escape
+    /// sequence the dialect does not have.
+    ///
+    /// Only escape sequences are affected: literal text is copied unchanged, even where the
+    /// dialect would not allow it, such as a control character in a JSON string.
+    pub const fn dialect(dialect: Dialect) -> DecodeOptions {
+        let mut options = DecodeOptions::new();
+        match dialect {
+            Dialect::Json => {
+                options.combine_surrogates = true;
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('"')
+                    .with('\\')
+                    .with('/')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t');
+                options.hex_escapes = false;
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Python => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('\'')
+                    .with('"')
+                    .with('a')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.octal_escapes = Some(OctalEscapes::UpToThree);
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                options.line_continuation = Some(LineContinuation::Remove);
+                options.unknown_escapes = UnknownEscapePolicy::Preserve;
+            }
+            Dialect::C => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('\'')
+                    .with('"')
+                    .with('?')
+                    .with('a')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.octal_escapes = Some(OctalEscapes::UpToThree);
+                options.min_hex_digits = 1;
+                options.max_hex_digits = None;
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                options.line_continuation = Some(LineContinuation::Remove);
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Rust => {
+                options = DecodeOptions::rustc_compat();
+                options.line_continuation = Some(LineContinuation::TrimWhitespace);
+            }
+            Dialect::Toml => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('b')
+                    .with('t')
+                    .with('n')
+                    .with('f')
+                    .with('r')
+                    .with('"')
+                    .with('\\');
+                options.hex_escapes = false;
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                options.line_continuation = Some(LineContinuation::TrimAllWhitespace);
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Shell => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('\'')
+                    .with('"')
+                    .with('?')
+                    .with('a')
+                    .with('b')
+                    .with('e')
+                    .with('E')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.control_escapes = true;
+                options.octal_escapes = Some(OctalEscapes::UpToThree);
+                options.min_hex_digits = 1;
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                options.short_unicode = true;
+                options.unknown_escapes = UnknownEscapePolicy::Preserve;
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::JavaScript => {
+                options.combine_surrogates = true;
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('\'')
+                    .with('"')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.octal_escapes = Some(OctalEscapes::UpToByte);
+                options.fixed_unicode = true;
+                options.replace_lone_surrogates = true;
+                options.line_continuation = Some(LineContinuation::RemoveUnicode);
+                options.unknown_escapes = UnknownEscapePolicy::StripBackslash;
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Go => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('"')
+                    .with('a')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.octal_escapes = Some(OctalEscapes::ExactlyThree);
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Java => {
+                options.combine_surrogates = true;
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('"')
+                    .with('\'')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('s')
+                    .with('t');
+                options.octal_escapes = Some(OctalEscapes::UpToByte);
+                options.hex_escapes = false;
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.unicode_prepass = true;
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::CSharp => {
+                options.combine_surrogates = true;
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('\'')
+                    .with('"')
+                    .with('0')
+                    .with('a')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.min_hex_digits = 1;
+                options.max_hex_digits = Some(4);
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Lua => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('\\')
+                    .with('"')
+                    .with('\'')
+                    .with('a')
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('v');
+                options.decimal_escapes = true;
+                options.skip_whitespace = true;
+                options.line_continuation = Some(LineContinuation::Newline);
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::PostgreSql => {
+                options.combine_surrogates = true;
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('b')
+                    .with('f')
+                    .with('n')
+                    .with('r')
+                    .with('t');
+                options.octal_escapes = Some(OctalEscapes::UpToThree);
+                options.min_hex_digits = 1;
+                options.braced_unicode = false;
+                options.fixed_unicode = true;
+                options.long_unicode = true;
+                options.unknown_escapes = UnknownEscapePolicy::StripBackslash;
+                options.doubled_quote = Some('\'');
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+            Dialect::Csv => {
+                options.backslash_escapes = false;
+                options.doubled_quote = Some('"');
+            }
+            Dialect::MySql => {
+                options.simple_escape_set = SimpleEscapes::NONE
+                    .with('0')
+                    .with('\'')
+                    .with('"')
+                    .with('b')
+                    .with('n')
+                    .with('r')
+                    .with('t')
+                    .with('Z')
+                    .with('\\');
+                options.hex_escapes = false;
+                options.unicode_escapes = false;
+                options.unknown_escapes = UnknownEscapePolicy::StripBackslash;
+                options.preserved_escapes = SimpleEscapes::NONE.with('%').with('_');
+                options.doubled_quote = Some('\'');
+                #[cfg(feature = "names")]
+                {
+                    options.named_escapes = false;
+                }
+            }
+        }
+        options
+    }
+
+    /// Creates options that decode string literal bodies exactly like `rustc` does: hexadecimal
+    /// escape sequences are limited to `\x00` to `\x7F`, Unicode escape sequences take up to six
+    /// digits with underscores after the first one, and surrogates are always rejected.
+    ///
+    /// Line continuations, i.e. a backslash before a newline, are only supported by
+    /// `Dialect::Rust`, and neither supports the translation of literal carriage returns.
+    pub const fn rustc_compat() -> DecodeOptions {
+        let mut options = DecodeOptions::new();
+        options.ascii_hex = true;
+        options.max_unicode_digits = Some(6);
+        options.digit_separators = true;
+        #[cfg(feature = "names")]
+        {
+            options.named_escapes = false;
+        }
+        options
+    }
+
+    /// Reads a backslash as the start of an escape sequence. When disabled, backslashes are
+    /// literal text, for formats that only double their quote characters, such as CSV fields,
+    /// along with `doubled_quote`. Enabled by default.
+    pub fn backslash_escapes(mut self, backslash_escapes: bool) -> DecodeOptions {
+        self.backslash_escapes = backslash_escapes;
+        self
+    }
+
+    /// Accepts the simple escape sequences selected by `simple_escape_set`, by default `\t`, `\n`,
+    /// `\r`, `\0`, `\\`, `\"` and `\'`. When disabled, they are rejected with
+    /// `ErrorKind::UnknownEscape`. Enabled by default.
+    pub fn simple_escapes(mut self, simple_escapes: bool) -> DecodeOptions {
+        self.simple_escapes = simple_escapes;
+        self
+    }
+
+    /// Sets which simple escape sequences are accepted, e.g. `SimpleEscapes::CONTROLS` to accept
+    /// `\a`, `\b`, `\f`, `\v` and `\e` as well. Defaults to `SimpleEscapes::DEFAULT`.
+    pub fn simple_escape_set(mut self, simple_escape_set: SimpleEscapes) -> DecodeOptions {
+        self.simple_escape_set = simple_escape_set;
+        self
+    }
+
+    /// Accepts hexadecimal escape sequences, e.g. `\x02`. When disabled, they are rejected with
+    /// `ErrorKind::UnknownEscape`. Enabled by default.
+    pub fn hex_escapes(mut self, hex_escapes: bool) -> DecodeOptions {
+        self.hex_escapes = hex_escapes;
+        self
+    }
+
+    /// Accepts Unicode escape sequences, in the syntaxes selected by `braced_unicode`,
+    /// `fixed_unicode` and `long_unicode`. When disabled, every escape sequence starting with
+    /// `\u` or `\U` is rejected with `ErrorKind::UnknownEscape`. Named escape sequences are
+    /// controlled by `named_escapes`. Enabled by default.
+    pub fn unicode_escapes(mut self, unicode_escapes: bool) -> DecodeOptions {
+        self.unicode_escapes = unicode_escapes;
+        self
+    }
+
+    /// Rejects hexadecimal escape sequences above `\x7F` with `ErrorKind::CodePointOutOfRange`,
+    /// as in Rust string literals. Disabled by default, where `\x80` to `\xFF` decode to U+0080 to
+    /// U+00FF.
+    pub fn ascii_hex(mut self, ascii_hex: bool) -> DecodeOptions {
+        self.ascii_hex = ascii_hex;
+        self
+    }
+
+    /// Sets how many hexadecimal digits a hexadecimal escape sequence takes, e.g. one to four like
+    /// C# does, or any number of at least `min` if `max` is `None`, reading the longest run of
+    /// digits like C does. Values above U+10FFFF are rejected with
+    /// `ErrorKind::CodePointOutOfRange` and surrogates with `ErrorKind::SurrogateCodePoint`.
+    /// Defaults to exactly two digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is zero or greater than `max`.
+    pub fn hex_digits(mut self, min: usize, max: Option<usize>) -> DecodeOptions {
+        assert!(
+            min > 0 && max.is_none_or(|max| min <= max),
+            "invalid number of hexadecimal digits"
+        );
+        self.min_hex_digits = min;
+        self.max_hex_digits = max;
+        self
+    }
+
+    /// Accepts Unicode escape sequences with braces, e.g. `\u{1F600}`. Enabled by default.
+    pub fn braced_unicode(mut self, braced_unicode: bool) -> DecodeOptions {
+        self.braced_unicode = braced_unicode;
+        self
+    }
+
+    /// Accepts Unicode escape sequences of exactly four hexadecimal digits without braces, e.g.
+    /// `\u00E9`, as in JSON, Java and JavaScript. If braced Unicode escape sequences are accepted
+    /// as well, `\u{` always starts a braced one. Disabled by default.
+    pub fn fixed_unicode(mut self, fixed_unicode: bool) -> DecodeOptions {
+        self.fixed_unicode = fixed_unicode;
+        self
+    }
+
+    /// Accepts Unicode escape sequences of exactly eight hexadecimal digits introduced by an
+    /// uppercase `\U`, e.g. `\U0001F600`, as in Python, C and Go. Disabled by default.
+    pub fn long_unicode(mut self, long_unicode: bool) -> DecodeOptions {
+        self.long_unicode = long_unicode;
+        self
+    }
+
+    /// Accepts Unicode escape sequences without braces that have fewer digits than `fixed_unicode`
+    /// and `long_unicode` require, reading the longest run of one to four digits after `\u` and of
+    /// one to eight digits after `\U`, as in bash. Disabled by default.
+    pub fn short_unicode(mut self, short_unicode: bool) -> DecodeOptions {
+        self.short_unicode = short_unicode;
+        self
+    }
+
+    /// Translates `\uXXXX` escape sequences before all other escape sequences, like the Java
+    /// compiler does with the Unicode escapes of the source text. The `u` may be repeated, e.g.
+    /// `\uuu0041` decodes to `A`, and an escape sequence of a backslash starts an escape sequence
+    /// with the character following it, e.g. `\u005cn` decodes to a line feed. A backslash
+    /// escaped by a backslash does not start a Unicode escape sequence, e.g. `\\\u0041` decodes to
+    /// `\u0041`. Disabled by default.
+    pub fn unicode_prepass(mut self, unicode_prepass: bool) -> DecodeOptions {
+        self.unicode_prepass = unicode_prepass;
+        self
+    }
+
+    /// Limits the number of hexadecimal digits in the braces of a Unicode escape sequence, e.g.
+    /// to six like Rust does, or accepts any number of digits if `None`. Digit separators do not
+    /// count towards the limit. Defaults to `None`.
+    pub fn max_unicode_digits(mut self, max_unicode_digits: Option<usize>) -> DecodeOptions {
+        self.max_unicode_digits = max_unicode_digits;
+        self
+    }
+
+    /// Combines a Unicode escape sequence of a high surrogate that is directly followed by one of
+    /// a low surrogate into the single character they encode, as in JSON and Java, e.g.
+    /// `\u{D83D}\u{DE00}` and `\uD83D\uDE00` decode to U+1F600. When disabled, such pairs are
+    /// rejected like any other surrogate code point. Disabled by default, as in Rust, and enabled
+    /// by the dialects of languages with UTF-16 strings, such as `Dialect::Json`.
+    pub fn combine_surrogates(mut self, combine_surrogates: bool) -> DecodeOptions {
+        self.combine_surrogates = combine_surrogates;
+        self
+    }
+
+    /// Replaces a Unicode escape sequence of a surrogate that is not part of a surrogate pair with
+    /// U+FFFD REPLACEMENT CHARACTER, like JavaScript does when encoding a string with such
+    /// a lone surrogate as UTF-8. Disabled by default, where lone surrogates are rejected with
+    /// `ErrorKind::SurrogateCodePoint`.
+    pub fn replace_lone_surrogates(mut self, replace_lone_surrogates: bool) -> DecodeOptions {
+        self.replace_lone_surrogates = replace_lone_surrogates;
+        self
+    }
+
+    /// Accepts underscores between the hexadecimal digits of a Unicode escape sequence, like in
+    /// Rust numeric literals, e.g. `\u{1_F600}` decodes to U+1F600. The first digit cannot be an
+    /// underscore. Disabled by default.
+    pub fn digit_separators(mut self, digit_separators: bool) -> DecodeOptions {
+        self.digit_separators = digit_separators;
+        self
+    }
+
+    /// Accepts several space-separated code points in the braces of a single Unicode escape
+    /// sequence, e.g. `\u{1F468 200D 1F4BB}` decodes to the three characters of an emoji ZWJ
+    /// sequence. Disabled by default.
+    pub fn code_point_groups(mut self, code_point_groups: bool) -> DecodeOptions {
+        self.code_point_groups = code_point_groups;
+        self
+    }
+
+    /// Accepts octal escape sequences, e.g. `\101` for `A`, read as selected. If enabled, `\0`
+    /// starts an octal escape sequence even if it is in `simple_escape_set`, unless `octal_zero`
+    /// is disabled. Disabled by default.
+    pub fn octal_escapes(mut self, octal_escapes: Option<OctalEscapes>) -> DecodeOptions {
+        self.octal_escapes = octal_escapes;
+        self
+    }
+
+    /// Sets whether `\0` starts an octal escape sequence if they are accepted. When disabled,
+    /// octal escape sequences start with a digit from 1 to 7 and `\0` is read from
+    /// `simple_escape_set`, where it stands for NUL on its own, e.g. `\012` decodes to NUL followed
+    /// by `12` rather than to a line feed. Enabled by default.
+    pub fn octal_zero(mut self, octal_zero: bool) -> DecodeOptions {
+        self.octal_zero = octal_zero;
+        self
+    }
+
+    /// Accepts control escape sequences, `\c` followed by a printable ASCII character, which stand
+    /// for the control character of that character, e.g. `\cA` and `\ca` for U+0001, and `\c?` for
+    /// U+007F, as in bash. A backslash is written twice, i.e. `\c\\` stands for U+001C. Disabled
+    /// by default.
+    pub fn control_escapes(mut self, control_escapes: bool) -> DecodeOptions {
+        self.control_escapes = control_escapes;
+        self
+    }
+
+    /// Accepts decimal escape sequences of one to three digits with a value of at most 255, e.g.
+    /// `\65` for `A`, as in Lua. If enabled, they take precedence over octal escape sequences and
+    /// over `\0` in `simple_escape_set`. Disabled by default.
+    pub fn decimal_escapes(mut self, decimal_escapes: bool) -> DecodeOptions {
+        self.decimal_escapes = decimal_escapes;
+        self
+    }
+
+    /// Accepts `\z`, which stands for nothing and skips the whitespace following it, including line
+    /// breaks, as in Lua. Disabled by default.
+    pub fn skip_whitespace(mut self, skip_whitespace: bool) -> DecodeOptions {
+        self.skip_whitespace = skip_whitespace;
+        self
+    }
+
+    /// Accepts line continuations, read as selected. Disabled by default, where a backslash
+    /// before a line break is an invalid escape sequence.
+    pub fn line_continuation(
+        mut self,
+        line_continuation: Option<LineContinuation>,
+    ) -> DecodeOptions {
+        self.line_continuation = line_continuation;
+        self
+    }
+
+    /// Sets what happens to a backslash that does not start an accepted escape sequence.
+    /// Defaults to `UnknownEscapePolicy::Reject`. Malformed escape sequences of an accepted kind,
+    /// such as `\x4`, are always rejected.
+    pub fn unknown_escapes(mut self, unknown_escapes: UnknownEscapePolicy) -> DecodeOptions {
+        self.unknown_escapes = unknown_escapes;
+        self
+    }
+
+    /// Keeps the backslash of the unknown escape sequences of the characters in the set together
+    /// with the character, whatever the `unknown_escapes` policy, e.g. `\%` and `\_` in MySQL.
+    /// Characters starting an accepted escape sequence are not affected. Defaults to
+    /// `SimpleEscapes::NONE`.
+    pub fn preserved_escapes(mut self, preserved_escapes: SimpleEscapes) -> DecodeOptions {
+        self.preserved_escapes = preserved_escapes;
+        self
+    }
+
+    /// Reads the given quote character written twice as a single one, e.g. `''` for `'` as in SQL
+    /// string literals, and rejects it on its own with `ErrorKind::UnpairedQuote`, since it would
+    /// end the literal. Defaults to `None`, where quote characters are literal text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quote character is a backslash.
+    pub fn doubled_quote(mut self, doubled_quote: Option<char>) -> DecodeOptions {
+        assert!(
+            doubled_quote != Some('\\'),
+            "a backslash cannot be a quote character"
+        );
+        self.doubled_quote = doubled_quote;
+        self
+    }
+
+    /// Accepts named Unicode escape sequences, e.g. `\N{BULLET}`. Requires the `names` feature.
+    /// Enabled by default.
+    #[cfg(feature = "names")]
+    pub fn named_escapes(mut self, named_escapes: bool) -> DecodeOptions {
+        self.named_escapes = named_escapes;
+        self
+    }
+
+    /// Decodes escape sequences producing braces and apostrophes to their quoted form in ICU
+    /// MessageFormat patterns, e.g. `\x7B` to `'{'` and `\'` to `''`, so that they remain
+    /// literal text instead of starting a placeholder. Placeholders and apostrophes written
+    /// literally are copied unchanged. To decode the output of `EncodeOptions::message_format`,
+    /// also enable `fixed_unicode` and `long_unicode`. Disabled by default.
+    pub fn message_format(mut self, message_format: bool) -> DecodeOptions {
+        self.message_format = message_format;
+        self
+    }
+
+    /// Sets what happens to escape sequences that produce a noncharacter, e.g. `\u{FFFE}`.
+    /// Defaults to `NoncharacterPolicy::Allow`.
+    pub fn noncharacters(mut self, noncharacters: NoncharacterPolicy) -> DecodeOptions {
+        self.noncharacters = noncharacters;
+        self
+    }
+
+    /// Sets what happens to a byte order mark at the start of the decoded string. Defaults to
+    /// `BomPolicy::Preserve`.
+    pub fn bom(mut self, bom: BomPolicy) -> DecodeOptions {
+        self.bom = bom;
+        self
+    }
+
+    /// Rejects escape sequences producing a code point that is unassigned in the given Unicode
+    /// version with `ErrorKind::Unassigned`, or accepts any code point if `None`. Literal text
+    /// is not checked. Requires the `unicode-age` feature. Disabled by default.
+    #[cfg(feature = "unicode-age")]
+    pub fn assigned_only(mut self, assigned_only: Option<UnicodeVersion>) -> DecodeOptions {
+        self.assigned_only = assigned_only;
+        self
+    }
+
+    /// Folds the case of the decoded string, or leaves it as decoded if `None`. Case folding is
+    /// applied before normalization. Disabled by default.
+    pub fn case_folding(mut self, case_folding: Option<CaseFolding>) -> DecodeOptions {
+        self.case_folding = case_folding;
+        self
+    }
+
+    /// Normalizes the decoded string into the given normalization form, or leaves it as decoded
+    /// if `None`. Requires the `unicode-normalization` feature. Disabled by default.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalization(mut self, normalization: Option<NormalizationForm>) -> DecodeOptions {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Checks whether a backslash followed by `c` starts an octal escape sequence.
+    pub(crate) fn octal_start(&self, c: char) -> bool {
+        self.octal_escapes.is_some() && (matches!(c, '1'..='7') || (c == '0' && self.octal_zero))
+    }
+}
@@ -0,0 +1,105 @@
+//! Decodes very large strings on several threads.
+//!
+//! This module contains the `decode_parallel` function, which splits its input at line breaks
+//! that cannot be part of an escape sequence, decodes the parts on scoped threads and joins the
+//! results, so that decoding multi-gigabyte dumps is not bound to a single core.
+use std::ops::Range;
+use std::thread;
+
+use crate::{decode_with, BomPolicy, DecodeError, DecodeOptions};
+
+/// The smallest part of the input worth decoding on a thread of its own.
+const MIN_CHUNK_LEN: usize = 1 << 20;
+
+/// Decodes a string with escape sequences like `decode_with`, on up to `threads` threads.
+///
+/// The input is split into parts of at least 1 MiB after line breaks that do not follow a
+/// backslash, so it is decoded on a single thread if it is smaller than that or has no such line
+/// breaks. With options under which an escape sequence may span a line break, such as
+/// `line_continuation`, `skip_whitespace`, `code_point_groups` or `message_format`, the input is
+/// not split either. The result is the same as that of `decode_with`.
+///
+/// # Parameters
+///
+/// * `input`: A string slice or raw string slice
+/// * `options`: The options controlling how escape sequences are interpreted.
+/// * `threads`: The largest number of threads to decode on, e.g. the one of
+///   `std::thread::available_parallelism`.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded string, or the first error decoding fails with, whose span
+/// refers to the whole input.
+pub fn decode_parallel(
+    input: &str,
+    options: &DecodeOptions,
+    threads: usize,
+) -> Result<String, DecodeError> {
+    let spans_lines = options.line_continuation.is_some()
+        || options.skip_whitespace
+        || options.code_point_groups
+        || options.message_format;
+    let chunks = split(input, threads);
+    if spans_lines || chunks.len() <= 1 {
+        return decode_with(input, options);
+    }
+    // Only the decoded string as a whole can start with a byte order mark
+    let rest_options = options.clone().bom(BomPolicy::Preserve);
+    let results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let options = if index == 0 { options } else { &rest_options };
+                let chunk = &input[chunk.clone()];
+                scope.spawn(move || decode_with(chunk, options))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+    let mut decoded = String::with_capacity(input.len());
+    for (result, chunk) in results.into_iter().zip(&chunks) {
+        decoded.push_str(&result.map_err(|error| error.shifted(chunk.start))?);
+    }
+    Ok(decoded)
+}
+
+/// Splits `input` into at most `count` ranges of at least `MIN_CHUNK_LEN` bytes, each ending after
+/// a line break that is not part of an escape sequence.
+fn split(input: &str, count: usize) -> Vec<Range<usize>> {
+    let len = (input.len() / count.max(1)).max(MIN_CHUNK_LEN);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while chunks.len() + 1 < count && input.len() - start > len {
+        match line_end(input, start + len) {
+            Some(end) if end < input.len() => {
+                chunks.push(start..end);
+                start = end;
+            }
+            _ => break,
+        }
+    }
+    chunks.push(start..input.len());
+    chunks
+}
+
+/// Returns the position after the first line break at or after byte `pos` that does not follow a
+/// backslash. Neither a line break nor the character after it can then be part of an escape
+/// sequence, since no escape sequence contains a line break other than right after a backslash.
+fn line_end(input: &str, mut pos: usize) -> Option<usize> {
+    let bytes = input.as_bytes();
+    loop {
+        let newline = pos + bytes[pos..].iter().position(|&byte| byte == b'\n')?;
+        if newline == 0 || bytes[newline - 1] != b'\\' {
+            return Some(newline + 1);
+        }
+        pos = newline + 1;
+    }
+}
@@ -0,0 +1,1011 @@
+//! Parses individual escape sequences.
+//!
+//! This module contains the shared escape sequence parser used by `decode` and by the scanning
+//! functions, so that every entry point agrees on what counts as a valid escape.
+use crate::invariants::invariant;
+use crate::scan::EscapeKind;
+#[cfg(feature = "trace")]
+use crate::trace::{self, Fallback, TraceEvent};
+use crate::{
+    DecodeError, DecodeOptions, ErrorKind, LineContinuation, NoncharacterPolicy, OctalEscapes,
+    SimpleEscapes, UnknownEscapePolicy,
+};
+
+/// A single parsed escape sequence.
+pub(crate) struct Escape {
+    /// The family the escape sequence belongs to.
+    pub kind: EscapeKind,
+    /// The byte offset just past the end of the escape sequence.
+    pub end: usize,
+    /// The character the escape sequence decodes to.
+    pub value: char,
+    /// Whether the escape sequence is followed by more code points of the same brace group.
+    pub open_group: bool,
+}
+
+/// A piece of the input, either a run of literal text or an escape sequence.
+pub(crate) enum Token<'a> {
+    /// A run of text without any escape sequences, a backslash kept as is together with the
+    /// character following it, the text a line continuation stands for, or a quote character
+    /// written twice.
+    Literal(&'a str),
+    /// A parsed escape sequence.
+    Escape(Escape),
+}
+
+/// An iterator splitting the input into literal runs and escape sequences.
+///
+/// After yielding an error the iterator resumes behind the invalid escape sequence, as determined
+/// by `invalid_end`. Every code point of a brace group such as `\u{1F468 200D 1F4BB}` is yielded
+/// as an escape sequence of its own.
+#[derive(Debug, Clone)]
+pub(crate) struct Tokens<'a> {
+    input: &'a str,
+    pos: usize,
+    options: &'a DecodeOptions,
+    /// Whether the next token continues a brace group.
+    in_group: bool,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(input: &'a str) -> Tokens<'a> {
+        Tokens::with_options(input, &DecodeOptions::DEFAULT)
+    }
+
+    pub fn with_options(input: &'a str, options: &'a DecodeOptions) -> Tokens<'a> {
+        Tokens {
+            input,
+            pos: 0,
+            options,
+            in_group: false,
+        }
+    }
+
+    /// Returns the byte offset of the next token.
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    /// Continues with the token at byte offset `pos`, e.g. behind an escape sequence decoded
+    /// elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds or not on a character boundary of the input.
+    pub fn seek(&mut self, pos: usize) {
+        assert!(
+            self.input.is_char_boundary(pos),
+            "byte {pos} is not a character boundary of the input"
+        );
+        self.pos = pos;
+        self.in_group = false;
+    }
+
+    /// Returns the next token, without checking the invariants of the iterator or reporting the
+    /// token to the trace hook. Errors are located at the invalid escape sequence.
+    pub fn next_token(&mut self) -> Option<Result<Token<'a>, DecodeError>> {
+        let start = self.pos;
+        let token = self.token()?;
+        Some(token.map_err(|kind| DecodeError::new(kind).at(start..self.pos)))
+    }
+
+    /// Returns the next token, or the kind of error its escape sequence is invalid with.
+    fn token(&mut self) -> Option<Result<Token<'a>, ErrorKind>> {
+        let rest = &self.input[self.pos..];
+        if self.in_group {
+            self.in_group = false;
+            let decoded = decode_braced(rest, self.options).and_then(|(value, len, open_group)| {
+                Ok((check_value(value, self.options)?, len, open_group))
+            });
+            return Some(match decoded {
+                Ok((value, len, open_group)) => {
+                    self.pos += len;
+                    self.in_group = open_group;
+                    Ok(Token::Escape(Escape {
+                        kind: EscapeKind::Unicode,
+                        end: self.pos,
+                        value,
+                        open_group,
+                    }))
+                }
+                Err(error) => {
+                    let first = rest.chars().next().map_or(0, char::len_utf8);
+                    self.pos += braced_len(rest, false, self.options).max(first);
+                    Err(error)
+                }
+            });
+        }
+        match find_special(rest, self.options) {
+            None if rest.is_empty() => None,
+            None => {
+                self.pos = self.input.len();
+                Some(Ok(Token::Literal(rest)))
+            }
+            // A doubled quote character stands for a single one
+            Some(0)
+                if self
+                    .options
+                    .doubled_quote
+                    .is_some_and(|quote| rest.starts_with(quote)) =>
+            {
+                let len = rest.chars().next().map_or(0, char::len_utf8);
+                if rest[len..].starts_with(&rest[..len]) {
+                    self.pos += 2 * len;
+                    Some(Ok(Token::Literal(&rest[..len])))
+                } else {
+                    self.pos += len;
+                    Some(Err(ErrorKind::UnpairedQuote))
+                }
+            }
+            Some(0) if unescaped(rest, self.options).is_some() => {
+                let (text, len) = unescaped(rest, self.options)?;
+                self.pos += len;
+                Some(Ok(Token::Literal(text)))
+            }
+            Some(0) => match parse_escape(self.input, self.pos, self.options) {
+                Ok(escape) => {
+                    self.pos = escape.end;
+                    self.in_group = escape.open_group;
+                    Some(Ok(Token::Escape(escape)))
+                }
+                Err(error) => {
+                    self.pos = invalid_end(self.input, self.pos, self.options);
+                    Some(Err(error))
+                }
+            },
+            Some(offset) => {
+                self.pos += offset;
+                Some(Ok(Token::Literal(&rest[..offset])))
+            }
+        }
+    }
+}
+
+/// Returns the offset of the first backslash or quote character of `rest` that may start
+/// something other than literal text.
+///
+/// Literal runs make up most of typical input, so the search goes byte by byte rather than
+/// character by character where it can: `str::find` with a single character uses `memchr`, and an
+/// ASCII byte never occurs inside the UTF-8 encoding of another character.
+fn find_special(rest: &str, options: &DecodeOptions) -> Option<usize> {
+    match (options.backslash_escapes, options.doubled_quote) {
+        (true, None) => rest.find('\\'),
+        (false, None) => None,
+        (false, Some(quote)) => rest.find(quote),
+        (true, Some(quote)) if quote.is_ascii() => {
+            let quote = quote as u8;
+            rest.bytes().position(|byte| byte == b'\\' || byte == quote)
+        }
+        (true, Some(quote)) => rest.find(['\\', quote]),
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<Token<'a>, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos;
+        let token = self.next_token();
+        invariant!(
+            token.is_none() || self.pos > start,
+            "the token at byte {start} is empty"
+        );
+        invariant!(
+            self.input.is_char_boundary(self.pos),
+            "the token at byte {start} ends inside a character"
+        );
+        match &token {
+            Some(Ok(Token::Escape(escape))) => {
+                invariant!(
+                    escape.end == self.pos,
+                    "the escape sequence at byte {start} ends at {} instead of {}",
+                    escape.end,
+                    self.pos
+                );
+                invariant!(
+                    escape.open_group == self.in_group,
+                    "the brace group state after byte {start} is inconsistent"
+                );
+                #[cfg(feature = "trace")]
+                trace::emit(&TraceEvent::Escape {
+                    kind: escape.kind,
+                    range: start..escape.end,
+                    value: escape.value,
+                });
+            }
+            #[cfg(feature = "trace")]
+            Some(Err(error)) => trace::emit(&TraceEvent::Error {
+                range: start..self.pos,
+                error,
+            }),
+            _ => {}
+        }
+        token
+    }
+}
+
+/// Parses the escape sequence starting at the backslash at byte offset `start`.
+///
+/// # Parameters
+///
+/// * `input`: The complete input string.
+/// * `start`: The byte offset of the backslash introducing the escape sequence.
+/// * `options`: The options controlling which escape sequences are accepted.
+///
+/// # Returns
+///
+/// A `Result` containing the parsed escape sequence or an error if the escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function will return `ErrorKind::UnexpectedEof` if the input ends after the backslash,
+/// `ErrorKind::UnknownEscape` if the backslash is not followed by a known escape character or
+/// starts an escape sequence of a family the options disable, the errors of `escape_hex` and
+/// `decode_unicode` if the escape sequence is malformed, and those of `check_value` if the options
+/// reject the decoded character.
+pub(crate) fn parse_escape(
+    input: &str,
+    start: usize,
+    options: &DecodeOptions,
+) -> Result<Escape, ErrorKind> {
+    if options.unicode_prepass {
+        let (kind, value, len) = parse_prepass(input, start, options)?;
+        return Ok(Escape {
+            kind,
+            end: start + len,
+            value: check_value(value, options)?,
+            open_group: false,
+        });
+    }
+    let rest = &input[start + 1..];
+    match rest.chars().next() {
+        Some(c) if is_escape_char(c, options) => {}
+        Some(c) => {
+            return Ok(Escape {
+                kind: EscapeKind::Simple,
+                end: start + 1 + c.len_utf8(),
+                value: check_value(replace_unknown(c, options)?, options)?,
+                open_group: false,
+            })
+        }
+        None => return Err(ErrorKind::UnexpectedEof),
+    }
+    let mut open_group = false;
+    let (kind, value, len) = match rest.chars().next() {
+        // 8 bit excape sequences ex: \x02 = <STX>
+        Some('x') => {
+            let (value, len) = escape_hex(&rest[1..], options)?;
+            (EscapeKind::Hex, value, len)
+        }
+        // unicode escape /u{1A2B} = ↵
+        Some('u') if options.braced_unicode && rest[1..].starts_with('{') => {
+            let (value, len, open) = decode_unicode(&rest[1..], options)?;
+            open_group = open;
+            (EscapeKind::Unicode, value, len)
+        }
+        // fixed width unicode escapes \u21B5 = ↵ and \U0001F600 = 😀
+        Some('u') if options.fixed_unicode => {
+            let (value, len) = decode_fixed_pair(&rest[1..], options)?;
+            (EscapeKind::Unicode, value, len)
+        }
+        Some('u') if rest.len() == 1 => return Err(ErrorKind::UnexpectedEof),
+        Some('u') => return Err(ErrorKind::UnterminatedUnicode),
+        Some('U') if options.long_unicode => {
+            let (value, len) = decode_fixed_unicode(&rest[1..], 8, options)?;
+            (EscapeKind::Unicode, value, len)
+        }
+        // named unicode escape \N{BULLET} = •
+        #[cfg(feature = "names")]
+        Some('N') if options.named_escapes => {
+            let (value, len) = decode_name(&rest[1..])?;
+            (EscapeKind::Named, value, len)
+        }
+        // control escape sequences ex: \cA = <SOH>
+        Some('c') if options.control_escapes => {
+            let (value, len) = match rest[1..].chars().next() {
+                Some('?') => ('\x7F', 1),
+                // A backslash is escaped itself, as in bash
+                Some('\\') if rest[2..].starts_with('\\') => ('\x1C', 2),
+                Some(c) if c.is_ascii_graphic() => {
+                    (char::from(c.to_ascii_uppercase() as u8 & 0x1F), 1)
+                }
+                None => return Err(ErrorKind::UnexpectedEof),
+                Some(_) => return Err(ErrorKind::UnknownEscape('c')),
+            };
+            (EscapeKind::Simple, value, len)
+        }
+        // decimal escape sequences ex: \65 = A
+        Some('0'..='9') if options.decimal_escapes => {
+            let (value, len) = decode_decimal(rest)?;
+            (EscapeKind::Decimal, value, len - 1)
+        }
+        // octal escape sequences ex: \101 = A
+        Some(c) if options.octal_start(c) => {
+            let octal = options.octal_escapes.unwrap_or(OctalEscapes::UpToThree);
+            let (value, len) = decode_octal(rest, octal)?;
+            (EscapeKind::Octal, value, len - 1)
+        }
+        // Simple excape sequences ex: \n = newline
+        Some(c) if options.simple_escape_set.contains(c) => {
+            (EscapeKind::Simple, SimpleEscapes::value(c), 0)
+        }
+        Some(c) => return Err(ErrorKind::UnknownEscape(c)),
+        None => return Err(ErrorKind::UnexpectedEof),
+    };
+    Ok(Escape {
+        kind,
+        end: start + 2 + len,
+        value: check_value(value, options)?,
+        open_group,
+    })
+}
+
+/// Parses the escape sequence starting at the backslash at byte offset `start` after translating
+/// the `\uXXXX` escape sequences in it, as in Java.
+///
+/// # Returns
+///
+/// A `Result` containing the family of the escape sequence, the character it decodes to and the
+/// number of bytes it covers, or an error if the escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function will return the errors of `prepass_unicode` if a Unicode escape sequence is
+/// malformed, `ErrorKind::SurrogateCodePoint` if it is a lone surrogate the options do not
+/// replace, and `ErrorKind::UnexpectedEof` or `ErrorKind::UnknownEscape` if the backslash at
+/// `start`, or the one it translates to, is not followed by a known escape character.
+fn parse_prepass(
+    input: &str,
+    start: usize,
+    options: &DecodeOptions,
+) -> Result<(EscapeKind, char, usize), ErrorKind> {
+    let rest = &input[start..];
+    // A backslash escaped by a backslash does not start a Unicode escape sequence
+    let (mut next, mut pos) = (rest[1..].chars().next().map(|c| (u32::from(c), 1)), 1);
+    if let Some(unicode) = prepass_unicode(rest) {
+        let (code, len) = unicode?;
+        if code != u32::from('\\') {
+            if let Some(c) = char::from_u32(code) {
+                return Ok((EscapeKind::Unicode, c, len));
+            }
+            // Combine a high surrogate with the low surrogate following it
+            if let Some(Ok((low @ 0xDC00..=0xDFFF, low_len))) = prepass_unicode(&rest[len..]) {
+                if options.combine_surrogates && (0xD800..0xDC00).contains(&code) {
+                    let combined = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                    let c = char::from_u32(combined).ok_or(ErrorKind::SurrogateCodePoint(code))?;
+                    return Ok((EscapeKind::Unicode, c, len + low_len));
+                }
+            }
+            return Ok((EscapeKind::Unicode, lone_surrogate(code, options)?, len));
+        }
+        (next, pos) = (prepass_char(&rest[len..])?, len);
+    }
+
+    // The backslash starts an escape sequence with the character following it
+    let (c, len) = next.ok_or(ErrorKind::UnexpectedEof)?;
+    let c = char::from_u32(c).ok_or_else(|| invalid_code_point(c))?;
+    match c {
+        c if options.octal_start(c) => {
+            let octal = options.octal_escapes.unwrap_or(OctalEscapes::UpToThree);
+            let (mut digits, mut ends) = (String::new(), Vec::new());
+            let mut end = pos;
+            while ends.len() < 3 {
+                let Ok(Some((digit @ 0x30..=0x37, len))) = prepass_char(&rest[end..]) else {
+                    break;
+                };
+                digits.extend(char::from_u32(digit));
+                end += len;
+                ends.push(end);
+            }
+            let (value, len) = decode_octal(&digits, octal)?;
+            Ok((EscapeKind::Octal, value, ends[len - 1]))
+        }
+        c if options.simple_escapes && options.simple_escape_set.contains(c) => {
+            Ok((EscapeKind::Simple, SimpleEscapes::value(c), pos + len))
+        }
+        _ => Ok((EscapeKind::Simple, replace_unknown(c, options)?, pos + len)),
+    }
+}
+
+/// Returns the character the unknown escape sequence of a backslash followed by `c` stands for if
+/// the options replace unknown escape sequences.
+///
+/// # Errors
+///
+/// This function will return `ErrorKind::UnknownEscape` if the options do not replace unknown
+/// escape sequences.
+fn replace_unknown(c: char, options: &DecodeOptions) -> Result<char, ErrorKind> {
+    match options.unknown_escapes {
+        UnknownEscapePolicy::Replace(replacement) => Ok(replacement),
+        _ => Err(ErrorKind::UnknownEscape(c)),
+    }
+}
+
+/// Returns the error for an escape sequence whose hexadecimal digits, at the start of `input`,
+/// end before the escape sequence is complete.
+fn missing_digit(input: &str) -> ErrorKind {
+    match input
+        .trim_start_matches(|c: char| c.is_ascii_hexdigit())
+        .chars()
+        .next()
+    {
+        Some(c) => ErrorKind::InvalidHexDigit(c),
+        None => ErrorKind::UnexpectedEof,
+    }
+}
+
+/// Returns the error for a code point that is not a valid character.
+pub(crate) fn invalid_code_point(code: u32) -> ErrorKind {
+    if (0xD800..0xE000).contains(&code) {
+        ErrorKind::SurrogateCodePoint(code)
+    } else {
+        ErrorKind::CodePointOutOfRange(code)
+    }
+}
+
+/// Returns the code point of the `\uXXXX` escape sequence at the start of `input`, which may
+/// repeat the `u`, and the number of bytes it covers, or `None` if `input` does not start with a
+/// Unicode escape sequence.
+///
+/// # Errors
+///
+/// This function will return the error of `missing_digit` if the `u` is not followed by four
+/// hexadecimal digits.
+pub(crate) fn prepass_unicode(input: &str) -> Option<Result<(u32, usize), ErrorKind>> {
+    let digits = input.strip_prefix(r"\u")?.trim_start_matches('u');
+    let len = input.len() - digits.len() + 4;
+    Some(
+        hex4(digits)
+            .map(|code| (code, len))
+            .ok_or_else(|| missing_digit(digits)),
+    )
+}
+
+/// Returns the code point of the first character of `input` after translating a `\uXXXX` escape
+/// sequence, and the number of bytes it covers, or `None` if `input` is empty.
+fn prepass_char(input: &str) -> Result<Option<(u32, usize)>, ErrorKind> {
+    match prepass_unicode(input) {
+        Some(unicode) => unicode.map(Some),
+        None => Ok(input.chars().next().map(|c| (u32::from(c), c.len_utf8()))),
+    }
+}
+
+/// Returns the character following the backslash at byte offset `start` if it does not start an
+/// escape sequence of a kind the options accept.
+pub(crate) fn unknown_escape(input: &str, start: usize, options: &DecodeOptions) -> Option<char> {
+    input[start + 1..]
+        .chars()
+        .next()
+        .filter(|&c| !is_escape_char(c, options))
+}
+
+/// Checks whether a backslash followed by `c` starts an escape sequence of a kind the options
+/// accept.
+fn is_escape_char(c: char, options: &DecodeOptions) -> bool {
+    match c {
+        'x' => options.hex_escapes,
+        'u' => options.unicode_escapes,
+        'U' => options.unicode_escapes && options.long_unicode,
+        'c' if options.control_escapes => true,
+        #[cfg(feature = "names")]
+        'N' => options.named_escapes,
+        '0'..='9' if options.decimal_escapes => true,
+        c if options.octal_start(c) => true,
+        _ => options.simple_escapes && options.simple_escape_set.contains(c),
+    }
+}
+
+/// Returns the literal text that the backslash at the start of `rest` stands for together with
+/// the character following it, and the number of bytes they cover, if the options make them a
+/// line continuation or keep them as an unknown escape sequence.
+fn unescaped<'a>(rest: &'a str, options: &DecodeOptions) -> Option<(&'a str, usize)> {
+    let next = rest[1..].chars().next()?;
+    if next == 'z' && options.skip_whitespace {
+        let skipped = rest[2..].trim_start_matches([' ', '\t', '\n', '\x0B', '\x0C', '\r']);
+        return Some((&rest[..0], rest.len() - skipped.len()));
+    }
+    if let Some(continuation) = options.line_continuation {
+        let line_break = match continuation {
+            LineContinuation::TrimAllWhitespace => rest[1..].trim_start_matches([' ', '\t']),
+            _ => &rest[1..],
+        };
+        let unicode = continuation == LineContinuation::RemoveUnicode;
+        let newline = continuation == LineContinuation::Newline;
+        if line_break.starts_with(['\n', '\r'])
+            || (unicode && line_break.starts_with(['\u{2028}', '\u{2029}']))
+        {
+            let rest_len = match continuation {
+                LineContinuation::Remove
+                | LineContinuation::RemoveUnicode
+                | LineContinuation::Newline => {
+                    let len = match line_break.chars().next() {
+                        Some('\r') if line_break.starts_with("\r\n") => 2,
+                        Some('\n') if newline && line_break.starts_with("\n\r") => 2,
+                        c => c.map_or(0, char::len_utf8),
+                    };
+                    line_break.len() - len
+                }
+                LineContinuation::TrimWhitespace | LineContinuation::TrimAllWhitespace => {
+                    line_break.trim_start_matches([' ', '\t', '\n', '\r']).len()
+                }
+            };
+            let text = if newline { "\n" } else { &rest[..0] };
+            return Some((text, rest.len() - rest_len));
+        }
+    }
+    if is_escape_char(next, options) {
+        return None;
+    }
+    let len = 1 + next.len_utf8();
+    if options.preserved_escapes.contains(next) {
+        return Some((&rest[..len], len));
+    }
+    match options.unknown_escapes {
+        UnknownEscapePolicy::Reject | UnknownEscapePolicy::Replace(_) => None,
+        UnknownEscapePolicy::Preserve => Some((&rest[..len], len)),
+        UnknownEscapePolicy::StripBackslash => Some((&rest[1..len], len)),
+    }
+}
+
+/// Applies the policies of the options to the character an escape sequence decodes to.
+///
+/// # Errors
+///
+/// This function will return `ErrorKind::Unassigned` if the character is unassigned in the
+/// Unicode version required by the options, and `ErrorKind::Noncharacter` if the character is a
+/// noncharacter and the options reject them.
+fn check_value(value: char, options: &DecodeOptions) -> Result<char, ErrorKind> {
+    #[cfg(feature = "unicode-age")]
+    if let Some(version) = options.assigned_only {
+        if !crate::age::is_assigned(value, version) {
+            return Err(ErrorKind::Unassigned);
+        }
+    }
+
+    let code = u32::from(value);
+    let noncharacter = (0xFDD0..=0xFDEF).contains(&code) || code & 0xFFFE == 0xFFFE;
+    match options.noncharacters {
+        NoncharacterPolicy::Reject if noncharacter => Err(ErrorKind::Noncharacter),
+        NoncharacterPolicy::Replace if noncharacter => {
+            #[cfg(feature = "trace")]
+            trace::emit(&TraceEvent::Fallback(Fallback::NoncharacterReplaced(value)));
+            Ok(char::REPLACEMENT_CHARACTER)
+        }
+        _ => Ok(value),
+    }
+}
+
+/// Returns the end of the invalid escape sequence starting at byte offset `start`.
+///
+/// An invalid escape sequence covers the backslash, the character following it, if any, and as
+/// much of the rest as belongs to the escape sequence: the digits of a hexadecimal, octal,
+/// decimal or fixed width Unicode escape sequence, or the body of a braced one up to and
+/// including its closing brace, so that scanning resumes behind all of it.
+pub(crate) fn invalid_end(input: &str, start: usize, options: &DecodeOptions) -> usize {
+    let rest = &input[start + 1..];
+    let Some(c) = rest.chars().next() else {
+        return input.len();
+    };
+    let body = &rest[c.len_utf8()..];
+    let hex_len = |body: &str, max: usize| {
+        body.bytes()
+            .take(max)
+            .take_while(u8::is_ascii_hexdigit)
+            .count()
+    };
+    let len = match c {
+        // Named escape sequences are braced in every syntax that has them
+        'N' if body.starts_with('{') => 1 + braced_len(&body[1..], true, options),
+        _ if !is_escape_char(c, options) => 0,
+        'u' if body.starts_with('{') && options.braced_unicode => {
+            1 + braced_len(&body[1..], false, options)
+        }
+        'u' if options.fixed_unicode => {
+            let repeated = if options.unicode_prepass {
+                body.len() - body.trim_start_matches('u').len()
+            } else {
+                0
+            };
+            repeated + hex_len(&body[repeated..], 4)
+        }
+        'U' if options.long_unicode => hex_len(body, 8),
+        'x' => hex_len(body, options.max_hex_digits.unwrap_or(usize::MAX)),
+        '0'..='9' if options.decimal_escapes => {
+            body.bytes().take(2).take_while(u8::is_ascii_digit).count()
+        }
+        c if options.octal_start(c) => body
+            .bytes()
+            .take(2)
+            .take_while(|b| matches!(b, b'0'..=b'7'))
+            .count(),
+        _ => 0,
+    };
+    start + 1 + c.len_utf8() + len
+}
+
+/// Returns the number of bytes of `body`, the text following the opening brace of a braced
+/// escape sequence, that belong to it: the characters that may occur between the braces,
+/// followed by the closing brace if there is one.
+fn braced_len(body: &str, name: bool, options: &DecodeOptions) -> usize {
+    let len = body
+        .bytes()
+        .take_while(|&b| {
+            b.is_ascii_alphanumeric()
+                || b == b'_'
+                || (b == b' ' && (name || options.code_point_groups))
+                || (b == b'-' && name)
+        })
+        .count();
+    len + usize::from(body[len..].starts_with('}'))
+}
+
+/// Decodes a hexadecimal escape sequence.
+///
+/// This function takes the text following a `\x` introducer (e.g., `02` for `\x02`) and returns
+/// the corresponding character. The options determine how many digits are read.
+///
+/// # Parameters
+///
+/// * `input`: The text following the `\x` introducer.
+/// * `options`: The options controlling which hexadecimal escape sequences are accepted.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded character and the number of bytes consumed, or an error if
+/// the escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function will return the error of `missing_digit` if there are fewer digits than the
+/// options require, `ErrorKind::SurrogateCodePoint` or `ErrorKind::CodePointOutOfRange` if the
+/// digits do not represent a character, and `ErrorKind::CodePointOutOfRange` as well if the
+/// value is above `\x7F` and the options only accept ASCII.
+fn escape_hex(input: &str, options: &DecodeOptions) -> Result<(char, usize), ErrorKind> {
+    let len = input
+        .bytes()
+        .take(options.max_hex_digits.unwrap_or(usize::MAX))
+        .take_while(u8::is_ascii_hexdigit)
+        .count();
+    if len < options.min_hex_digits {
+        return Err(missing_digit(input));
+    }
+    // Values too large for a `u32` are reported as `u32::MAX`
+    let code = input[..len]
+        .bytes()
+        .try_fold(0u32, |value, digit| {
+            value
+                .checked_mul(16)?
+                .checked_add(char::from(digit).to_digit(16)?)
+        })
+        .unwrap_or(u32::MAX);
+    let value = char::from_u32(code).ok_or_else(|| invalid_code_point(code))?;
+    if options.ascii_hex && !value.is_ascii() {
+        return Err(ErrorKind::CodePointOutOfRange(code));
+    }
+    Ok((value, len))
+}
+
+/// Decodes a Unicode escape sequence.
+///
+/// This function takes the text following a `\u` introducer (e.g., `{1F600}` for `\u{1F600}`)
+/// and returns the corresponding character. If the options allow brace groups of several code
+/// points, only the first one is decoded, and the returned flag tells whether more follow.
+///
+/// # Parameters
+///
+/// * `input`: The text following the `\u` introducer.
+/// * `options`: The options controlling which Unicode escape sequences are accepted.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded character, the number of bytes consumed and whether the
+/// brace group continues, or an error if the escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function will return the errors of `decode_code_point` if the escape sequence is malformed,
+/// and `ErrorKind::SurrogateCodePoint` or `ErrorKind::CodePointOutOfRange` if the code point is
+/// not a valid character.
+fn decode_unicode(input: &str, options: &DecodeOptions) -> Result<(char, usize, bool), ErrorKind> {
+    // Remove the leading '{'
+    let body = input
+        .strip_prefix('{')
+        .ok_or(ErrorKind::UnterminatedUnicode)?;
+    let (value, len, open_group) = decode_braced(body, options)?;
+    Ok((value, len + 1, open_group))
+}
+
+/// Decodes a Unicode escape sequence without braces.
+///
+/// This function takes the text following a `\u` or `\U` introducer (e.g., `21B5` for `\u21B5`)
+/// and returns the corresponding character.
+///
+/// # Parameters
+///
+/// * `input`: The text following the introducer.
+/// * `digits`: The number of hexadecimal digits of the escape sequence, or the largest number if
+///   the options accept shortened ones.
+/// * `options`: The options controlling which Unicode escape sequences are accepted.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded character and the number of bytes consumed, or an error if
+/// the escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function will return the error of `missing_digit` if there are fewer digits than
+/// required, and `ErrorKind::SurrogateCodePoint` or `ErrorKind::CodePointOutOfRange` if the
+/// code point is not a valid character.
+fn decode_fixed_unicode(
+    input: &str,
+    digits: usize,
+    options: &DecodeOptions,
+) -> Result<(char, usize), ErrorKind> {
+    let hex = fixed_digits(input, digits, options).ok_or_else(|| missing_digit(input))?;
+    let code = u32::from_str_radix(hex, 16).map_err(|_| missing_digit(input))?;
+    let c = char::from_u32(code).ok_or_else(|| invalid_code_point(code))?;
+    Ok((c, hex.len()))
+}
+
+/// Returns the hexadecimal digits at the start of `input` of a Unicode escape sequence without
+/// braces taking `digits` digits, or at least one of them if the options accept shortened ones.
+fn fixed_digits<'a>(input: &'a str, digits: usize, options: &DecodeOptions) -> Option<&'a str> {
+    let len = input
+        .bytes()
+        .take(digits)
+        .take_while(u8::is_ascii_hexdigit)
+        .count();
+    (len == digits || (options.short_unicode && len > 0)).then(|| &input[..len])
+}
+
+/// Decodes the digits of an octal escape sequence at the start of `input`.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded character and the number of digits, of which at most three
+/// are read, or fewer if the value would exceed the largest one of `octal` otherwise.
+///
+/// # Errors
+///
+/// If the options require exactly three digits, this function will return
+/// `ErrorKind::CodePointOutOfRange` if they have a larger value, and
+/// `ErrorKind::InvalidHexDigit` with the character following the digits, or
+/// `ErrorKind::UnexpectedEof` if there is none, if there are fewer.
+fn decode_octal(input: &str, octal: OctalEscapes) -> Result<(char, usize), ErrorKind> {
+    let max = match octal {
+        OctalEscapes::UpToThree => 0o777,
+        OctalEscapes::UpToByte | OctalEscapes::ExactlyThree => 0o377,
+    };
+    let (mut value, mut len) = (0, 0);
+    for digit in input
+        .bytes()
+        .take(3)
+        .take_while(|b| matches!(b, b'0'..=b'7'))
+    {
+        let next = value * 8 + u32::from(digit - b'0');
+        if next > max {
+            break;
+        }
+        (value, len) = (next, len + 1);
+    }
+    if octal == OctalEscapes::ExactlyThree && len < 3 {
+        return Err(match input[len..].chars().next() {
+            Some(c @ '0'..='7') => ErrorKind::CodePointOutOfRange(value * 8 + c as u32 - 0x30),
+            Some(c) => ErrorKind::InvalidHexDigit(c),
+            None => ErrorKind::UnexpectedEof,
+        });
+    }
+    let value = char::from_u32(value).ok_or(ErrorKind::CodePointOutOfRange(value))?;
+    Ok((value, len))
+}
+
+/// Decodes the digits of a decimal escape sequence at the start of `input`.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded character and the number of digits, of which at most three
+/// are read.
+///
+/// # Errors
+///
+/// This function will return `ErrorKind::CodePointOutOfRange` if the value exceeds 255, e.g.
+/// `\256`.
+fn decode_decimal(input: &str) -> Result<(char, usize), ErrorKind> {
+    let len = input.bytes().take(3).take_while(u8::is_ascii_digit).count();
+    let value = input[..len]
+        .bytes()
+        .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+    let value = u8::try_from(value).map_err(|_| ErrorKind::CodePointOutOfRange(value))?;
+    Ok((char::from(value), len))
+}
+
+/// Decodes the four hexadecimal digits of a fixed width Unicode escape sequence.
+///
+/// If enabled in the options, a high surrogate directly followed by a fixed width escape sequence
+/// of a low surrogate is combined into the character the pair encodes (e.g., `D83D\uDE00`).
+fn decode_fixed_pair(input: &str, options: &DecodeOptions) -> Result<(char, usize), ErrorKind> {
+    let hex = fixed_digits(input, 4, options).ok_or_else(|| missing_digit(input))?;
+    let code = u32::from_str_radix(hex, 16).map_err(|_| missing_digit(input))?;
+    if let Some(c) = char::from_u32(code) {
+        return Ok((c, hex.len()));
+    }
+
+    // Combine a high surrogate with the low surrogate following it
+    if options.combine_surrogates && (0xD800..0xDC00).contains(&code) {
+        let low = match input[hex.len()..].strip_prefix(r"\u") {
+            Some(next) if options.braced_unicode && next.starts_with('{') => {
+                match decode_code_point(&next[1..], options) {
+                    Ok((low, len, false)) => Some((low, 3 + len)),
+                    _ => None,
+                }
+            }
+            Some(next) => hex4(next).map(|low| (low, 6)),
+            None => None,
+        };
+        if let Some((low @ 0xDC00..=0xDFFF, low_len)) = low {
+            let combined = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+            let c = char::from_u32(combined).ok_or(ErrorKind::SurrogateCodePoint(code))?;
+            return Ok((c, hex.len() + low_len));
+        }
+    }
+    lone_surrogate(code, options).map(|c| (c, hex.len()))
+}
+
+/// Parses exactly four hexadecimal digits at the start of `input`.
+fn hex4(input: &str) -> Option<u32> {
+    input
+        .get(..4)
+        .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+}
+
+/// Returns the character a code point that is not a valid character decodes to on its own.
+///
+/// # Errors
+///
+/// This function will return `ErrorKind::SurrogateCodePoint` or
+/// `ErrorKind::CodePointOutOfRange` unless the code point is a surrogate and the options replace
+/// lone surrogates with U+FFFD.
+fn lone_surrogate(code: u32, options: &DecodeOptions) -> Result<char, ErrorKind> {
+    if !options.replace_lone_surrogates || !(0xD800..0xE000).contains(&code) {
+        return Err(invalid_code_point(code));
+    }
+    #[cfg(feature = "trace")]
+    trace::emit(&TraceEvent::Fallback(Fallback::SurrogateReplaced(code)));
+    Ok(char::REPLACEMENT_CHARACTER)
+}
+
+/// Decodes the next code point of a brace group into a character.
+///
+/// If enabled in the options, a high surrogate directly followed by a low surrogate is combined
+/// into the character the pair encodes. The low surrogate is either the next code point of the
+/// group, or a Unicode escape sequence of its own (e.g., `D83D}\u{DE00}`, or `D83D}\uDE00` if
+/// Unicode escape sequences without braces are accepted).
+fn decode_braced(body: &str, options: &DecodeOptions) -> Result<(char, usize, bool), ErrorKind> {
+    let (code, len, open_group) = decode_code_point(body, options)?;
+    if let Some(c) = char::from_u32(code) {
+        return Ok((c, len, open_group));
+    }
+
+    // Combine a high surrogate with the low surrogate following it
+    if options.combine_surrogates && (0xD800..0xDC00).contains(&code) {
+        let rest = &body[len..];
+        let braced_low = |next: &str, offset: usize| {
+            let (low, low_len, open_group) = decode_code_point(next, options).ok()?;
+            Some((low, offset + low_len, open_group))
+        };
+        let low = if open_group {
+            braced_low(rest, len)
+        } else {
+            match rest.strip_prefix(r"\u") {
+                Some(next) if next.starts_with('{') => braced_low(&next[1..], len + 3),
+                Some(next) if options.fixed_unicode => hex4(next).map(|low| (low, len + 6, false)),
+                _ => None,
+            }
+        };
+        if let Some((low @ 0xDC00..=0xDFFF, end, open_group)) = low {
+            let combined = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+            let c = char::from_u32(combined).ok_or(ErrorKind::SurrogateCodePoint(code))?;
+            return Ok((c, end, open_group));
+        }
+    }
+    lone_surrogate(code, options).map(|c| (c, len, open_group))
+}
+
+/// Decodes the next code point of a brace group, e.g. `1F600}`.
+///
+/// If enabled in the options, underscores may separate the hexadecimal digits after the first
+/// one, e.g. `1_F600}`, and spaces may separate several code points, e.g. `1F468 200D 1F4BB}`.
+/// Returns the code point, which is not necessarily a valid character, the number of bytes
+/// consumed including the closing brace or the following spaces, and whether the group continues.
+///
+/// # Errors
+///
+/// This function will return `ErrorKind::EmptyUnicode` if the group has no digits,
+/// `ErrorKind::OverlongUnicode` if it has more than the options accept,
+/// `ErrorKind::InvalidHexDigit` if a character other than a digit or a separator the options
+/// accept follows them, and `ErrorKind::UnterminatedUnicode` if the input ends before the
+/// closing brace. Code points too large for a `u32` are reported as
+/// `ErrorKind::CodePointOutOfRange` with `u32::MAX`.
+fn decode_code_point(body: &str, options: &DecodeOptions) -> Result<(u32, usize, bool), ErrorKind> {
+    // Gather all hex digits, and the separators between them
+    let digits = body
+        .bytes()
+        .enumerate()
+        .take_while(|&(i, b)| {
+            b.is_ascii_hexdigit() || (options.digit_separators && i > 0 && b == b'_')
+        })
+        .count();
+    if digits == 0 {
+        return Err(match body.chars().next() {
+            Some('}') => ErrorKind::EmptyUnicode,
+            Some(c) => ErrorKind::InvalidHexDigit(c),
+            None => ErrorKind::UnterminatedUnicode,
+        });
+    }
+    if let Some(max) = options.max_unicode_digits {
+        if body[..digits].bytes().filter(|&b| b != b'_').count() > max {
+            return Err(ErrorKind::OverlongUnicode);
+        }
+    }
+
+    // Convert the digits to a code point
+    let mut code: u32 = 0;
+    for b in body[..digits].bytes().filter(|&b| b != b'_') {
+        let digit = char::from(b)
+            .to_digit(16)
+            .ok_or(ErrorKind::InvalidHexDigit(char::from(b)))?;
+        code = code
+            .checked_mul(16)
+            .and_then(|code| code.checked_add(digit))
+            .ok_or(ErrorKind::CodePointOutOfRange(u32::MAX))?;
+    }
+
+    // Remove the trailing '}', or the spaces before the next code point of the group
+    let rest = &body[digits..];
+    if rest.starts_with('}') {
+        return Ok((code, digits + 1, false));
+    }
+    let spaces = rest.bytes().take_while(|&b| b == b' ').count();
+    if options.code_point_groups
+        && spaces > 0
+        && rest[spaces..].starts_with(|c: char| c.is_ascii_hexdigit())
+    {
+        return Ok((code, digits + spaces, true));
+    }
+    Err(match rest.chars().next() {
+        Some(c) => ErrorKind::InvalidHexDigit(c),
+        None => ErrorKind::UnterminatedUnicode,
+    })
+}
+
+/// Decodes a named Unicode escape sequence.
+///
+/// This function takes the text following a `\N` introducer (e.g., `{BULLET}` for `\N{BULLET}`)
+/// and returns the named character. Names are matched ignoring case, and name aliases are
+/// accepted as well.
+///
+/// # Parameters
+///
+/// * `input`: The text following the `\N` introducer.
+///
+/// # Returns
+///
+/// A `Result` containing the decoded character and the number of bytes consumed, or an error if
+/// the escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function will return an error of type `ErrorKind::UnterminatedUnicode` if the braces
+/// are missing, and `ErrorKind::UnknownCharacterName` if no character has the given name.
+#[cfg(feature = "names")]
+fn decode_name(input: &str) -> Result<(char, usize), ErrorKind> {
+    let body = input
+        .strip_prefix('{')
+        .ok_or(ErrorKind::UnterminatedUnicode)?;
+    let name = &body[..body.find('}').ok_or(ErrorKind::UnterminatedUnicode)?];
+    crate::names::lookup(name)
+        .map(|c| (c, name.len() + 2))
+        .ok_or(ErrorKind::UnknownCharacterName)
+}
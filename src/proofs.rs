@@ -0,0 +1,88 @@
+//! Model checking harnesses proving that decoding cannot panic.
+//!
+//! This module is only compiled by the Kani model checker, which sets `cfg(kani)`. Every proof
+//! runs an entry point on all strings up to a small length, so the decoder cannot panic, index a
+//! string out of bounds or split a character on any of them. Run them with
+//!
+//! ```text
+//! cargo kani
+//! cargo kani --features debug-invariants
+//! ```
+//!
+//! where the second run additionally proves the internal invariants of the decoder.
+use crate::{decode, decode_with, decode_with_map, validate_all, DecodeOptions};
+
+/// The longest input the proofs cover, in bytes.
+const MAX_LEN: usize = 8;
+
+/// Characters the escape sequence proofs build their inputs from.
+///
+/// Restricting the inputs to the characters that are significant to the parser, plus one that is
+/// not and one multibyte character, makes the proofs tractable while still reaching every branch.
+const ALPHABET: [char; 14] = [
+    '\\', 'x', 'u', 'U', 'N', '{', '}', ' ', '_', '0', 'D', 'F', 'q', '\u{E9}',
+];
+
+/// Returns an arbitrary string of up to `MAX_LEN` characters of `ALPHABET`.
+fn any_escaped() -> String {
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    let mut input = String::new();
+    for _ in 0..len {
+        let index: usize = kani::any();
+        kani::assume(index < ALPHABET.len());
+        input.push(ALPHABET[index]);
+    }
+    input
+}
+
+/// Returns the options of the proofs with every optional syntax enabled.
+fn all_syntax() -> DecodeOptions {
+    DecodeOptions::new()
+        .fixed_unicode(true)
+        .long_unicode(true)
+        .digit_separators(true)
+        .code_point_groups(true)
+        .max_unicode_digits(Some(6))
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn decode_arbitrary_utf8() {
+    let bytes: [u8; MAX_LEN] = kani::any();
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    if let Ok(input) = std::str::from_utf8(&bytes[..len]) {
+        let _ = decode(input);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn decode_escapes() {
+    let input = any_escaped();
+    let _ = decode(&input);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn decode_escapes_with_all_syntax() {
+    let input = any_escaped();
+    let _ = decode_with(&input, &all_syntax());
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn decode_with_map_matches_decode() {
+    let input = any_escaped();
+    let decoded = decode(&input).ok();
+    let mapped = decode_with_map(&input).ok().map(|(decoded, _)| decoded);
+    assert!(decoded == mapped);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn validate_all_agrees_with_decode() {
+    let input = any_escaped();
+    assert!(validate_all(&input).is_empty() == decode(&input).is_ok());
+}
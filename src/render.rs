@@ -0,0 +1,303 @@
+//! Renders escaped strings for human inspection.
+//!
+//! This module contains functions that reproduce the escaped input with every escape sequence
+//! highlighted and annotated with what it decodes to, either with ANSI colors for a terminal or as
+//! HTML for web tools. `render_report` lists the problems of the escaped input as plain text
+//! diagnostics, and `DecodeError::render` formats a single error the same way.
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::diff::{decode_diff, DiffSegment};
+use crate::lint::lint;
+use crate::notation::format_codepoint_notation;
+use crate::validate::validate_all;
+use crate::{DecodeError, ErrorKind, EscapeKind};
+
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+const INVALID: &str = "\x1b[1;31m";
+
+/// Returns the ANSI color used to highlight escape sequences of the given kind.
+fn ansi_color(kind: EscapeKind) -> &'static str {
+    match kind {
+        EscapeKind::Simple => "\x1b[33m",
+        EscapeKind::Hex | EscapeKind::Octal | EscapeKind::Decimal => "\x1b[36m",
+        EscapeKind::Unicode | EscapeKind::Named => "\x1b[35m",
+        EscapeKind::Invalid => INVALID,
+    }
+}
+
+/// Returns the ASCII abbreviation of a control character, e.g. `STX` for U+0002.
+fn control_abbreviation(c: char) -> Option<&'static str> {
+    const C0: [&str; 32] = [
+        "NUL", "SOH", "STX", "ETX", "EOT", "ENQ", "ACK", "BEL", "BS", "HT", "LF", "VT", "FF", "CR",
+        "SO", "SI", "DLE", "DC1", "DC2", "DC3", "DC4", "NAK", "SYN", "ETB", "CAN", "EM", "SUB",
+        "ESC", "FS", "GS", "RS", "US",
+    ];
+    match c {
+        '\0'..='\x1f' => Some(C0[c as usize]),
+        ' ' => Some("SP"),
+        '\x7f' => Some("DEL"),
+        '\u{a0}' => Some("NBSP"),
+        _ if c.is_control() => Some("CTRL"),
+        _ => None,
+    }
+}
+
+/// Returns a short human readable label for a decoded character.
+///
+/// Control characters and spaces are labelled with their abbreviation or code point, so that the
+/// label itself never consists of invisible characters. Any other character is its own label.
+fn label(c: char) -> String {
+    match control_abbreviation(c) {
+        Some(abbreviation) => abbreviation.to_string(),
+        None if c.is_whitespace() => format_codepoint_notation(c),
+        None => c.to_string(),
+    }
+}
+
+/// Renders a string with its escape sequences highlighted using ANSI colors.
+///
+/// Every escape sequence is colored according to its kind and followed by a dimmed annotation of
+/// the character it decodes to, e.g. `\x02⟨STX⟩`. Invalid escape sequences are rendered in bold
+/// red without an annotation. Literal text is copied unchanged.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The annotated string, ready to be written to a terminal.
+pub fn render_ansi(input: &str) -> String {
+    let mut result = String::new();
+    // Writing to a String cannot fail
+    for segment in decode_diff(input).segments() {
+        match *segment {
+            DiffSegment::Literal(text) => result.push_str(text),
+            DiffSegment::Escape {
+                source,
+                kind,
+                decoded,
+            } => {
+                let color = ansi_color(kind);
+                let label = label(decoded);
+                let _ = write!(result, "{color}{source}{RESET}{DIM}⟨{label}⟩{RESET}");
+            }
+            DiffSegment::Invalid { source, .. } => {
+                let _ = write!(result, "{INVALID}{source}{RESET}");
+            }
+        }
+    }
+    result
+}
+
+/// Returns the CSS class used for escape sequences of the given kind.
+fn html_class(kind: EscapeKind) -> &'static str {
+    match kind {
+        EscapeKind::Simple => "escape escape-simple",
+        EscapeKind::Hex => "escape escape-hex",
+        EscapeKind::Octal => "escape escape-octal",
+        EscapeKind::Decimal => "escape escape-decimal",
+        EscapeKind::Unicode => "escape escape-unicode",
+        EscapeKind::Named => "escape escape-named",
+        EscapeKind::Invalid => "escape escape-invalid",
+    }
+}
+
+/// Appends `text` to `out`, replacing the characters that are special in HTML.
+fn push_html_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Renders a string as HTML with its escape sequences wrapped in annotated `<span>`s.
+///
+/// Every escape sequence is wrapped in a `<span>` with the classes `escape` and `escape-<kind>`
+/// (`simple`, `hex`, `octal`, `decimal`, `unicode`, `named` or `invalid`), and a `title` attribute
+/// naming the code point it decodes to, e.g. `U+0002 STX`. Literal text is HTML-escaped but
+/// otherwise left unchanged, so the output can be embedded directly into a page.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The annotated HTML fragment.
+pub fn render_html(input: &str) -> String {
+    let mut result = String::new();
+    for segment in decode_diff(input).segments() {
+        let (source, class, title) = match *segment {
+            DiffSegment::Literal(text) => {
+                push_html_escaped(&mut result, text);
+                continue;
+            }
+            DiffSegment::Escape {
+                source,
+                kind,
+                decoded,
+            } => {
+                let title = match control_abbreviation(decoded) {
+                    Some(abbreviation) => {
+                        format!("{} {abbreviation}", format_codepoint_notation(decoded))
+                    }
+                    None => format_codepoint_notation(decoded),
+                };
+                (source, html_class(kind), title)
+            }
+            DiffSegment::Invalid { source, .. } => (
+                source,
+                html_class(EscapeKind::Invalid),
+                "invalid escape sequence".to_string(),
+            ),
+        };
+        let _ = write!(result, "<span class=\"{class}\" title=\"");
+        push_html_escaped(&mut result, &title);
+        result.push_str("\">");
+        push_html_escaped(&mut result, source);
+        result.push_str("</span>");
+    }
+    result
+}
+
+/// Returns the message describing an invalid escape sequence.
+fn error_message(error: &DecodeError, source: &str) -> String {
+    match error.kind() {
+        ErrorKind::UnexpectedEof => format!("incomplete escape sequence `{source}`"),
+        ErrorKind::UnknownEscape(_) => format!("unknown escape sequence `{source}`"),
+        ErrorKind::InvalidHexDigit(c) => format!("invalid digit {c:?} in `{source}`"),
+        ErrorKind::UnterminatedUnicode => format!("unterminated Unicode escape `{source}`"),
+        ErrorKind::EmptyUnicode => format!("empty Unicode escape `{source}`"),
+        ErrorKind::OverlongUnicode => format!("overlong Unicode escape `{source}`"),
+        ErrorKind::CodePointOutOfRange(code) => {
+            format!("`{source}` produces the out of range value {code:#X}")
+        }
+        ErrorKind::SurrogateCodePoint(code) => {
+            format!("`{source}` produces the surrogate U+{code:04X}")
+        }
+        ErrorKind::UnknownCharacterName => format!("unknown character name in `{source}`"),
+        ErrorKind::Noncharacter => format!("`{source}` produces a noncharacter"),
+        ErrorKind::Unassigned => format!("`{source}` produces an unassigned code point"),
+        ErrorKind::ByteOrderMark => "the string starts with a byte order mark".to_string(),
+        ErrorKind::UnpairedQuote => format!("unpaired quote `{source}`"),
+        ErrorKind::WriteFailed => "the decoded text could not be written".to_string(),
+        ErrorKind::BufferTooSmall => "the decoded text does not fit into the buffer".to_string(),
+        ErrorKind::MissingNotationPrefix => format!("code point notation `{source}` without `U+`"),
+    }
+}
+
+/// Appends a diagnostic to `out`, quoting the line of `input` containing `range` and underlining
+/// the range with carets, followed by `note` if it is not empty.
+fn push_diagnostic(
+    out: &mut String,
+    input: &str,
+    level: &str,
+    message: &str,
+    range: Range<usize>,
+    note: &str,
+) {
+    let line_start = input[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[range.start..]
+        .find('\n')
+        .map_or(input.len(), |i| range.start + i);
+    let line = &input[line_start..line_end];
+    let number = input[..line_start].matches('\n').count() + 1;
+    let column = input[line_start..range.start].chars().count() + 1;
+    let gutter = " ".repeat(number.to_string().len());
+    // Tabs are kept in the indentation of the carets, so that they line up with the quoted line
+    let indent: String = input[line_start..range.start]
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let carets = "^".repeat(
+        input[range.start..range.end.min(line_end)]
+            .chars()
+            .count()
+            .max(1),
+    );
+
+    let _ = writeln!(out, "{level}: {message}");
+    let _ = writeln!(out, "{gutter}--> line {number}, column {column}");
+    let _ = writeln!(out, "{gutter} |");
+    let _ = writeln!(out, "{number} | {line}");
+    if note.is_empty() {
+        let _ = writeln!(out, "{gutter} | {indent}{carets}");
+    } else {
+        let _ = writeln!(out, "{gutter} | {indent}{carets} {note}");
+    }
+}
+
+/// Renders `error` as a diagnostic quoting the line of `source` containing its span, or as a
+/// message alone if it has no span within `source`.
+pub(crate) fn render_error(error: &DecodeError, source: &str) -> String {
+    let mut out = String::new();
+    match error
+        .span()
+        .filter(|span| source.get(span.clone()).is_some())
+    {
+        Some(span) => {
+            let message = error_message(error, &source[span.clone()]);
+            let note = error
+                .help(source)
+                .map_or(String::new(), |help| format!("help: {help}"));
+            push_diagnostic(&mut out, source, "error", &message, span, &note);
+        }
+        None => {
+            let _ = writeln!(out, "error: {}", error.kind());
+        }
+    }
+    out
+}
+
+/// Renders a plain text report of the invalid and non-canonical escape sequences of a string.
+///
+/// Every invalid escape sequence found by `validate_all` is reported as an error, and every
+/// warning of `lint` as a warning with the suggested replacement, in input order. Each entry
+/// quotes the affected line with the escape sequence underlined by carets, like compiler
+/// diagnostics. The output contains no colors and depends only on the input, so that it can be
+/// compared against stored snapshots.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The report, with a blank line between entries, or an empty string if there is nothing to
+/// report.
+pub fn render_report(input: &str) -> String {
+    let mut entries: Vec<(Range<usize>, String)> = Vec::new();
+    for error in validate_all(input) {
+        let range = error.span().unwrap_or_default();
+        let mut entry = String::new();
+        let message = error_message(&error, &input[range.clone()]);
+        push_diagnostic(&mut entry, input, "error", &message, range.clone(), "");
+        entries.push((range, entry));
+    }
+    for warning in lint(input) {
+        let mut entry = String::new();
+        let note = format!("help: replace with `{}`", warning.replacement);
+        let range = warning.range.clone();
+        push_diagnostic(
+            &mut entry,
+            input,
+            "warning",
+            &warning.message,
+            range.clone(),
+            &note,
+        );
+        entries.push((range, entry));
+    }
+    entries.sort_by_key(|(range, _)| range.start);
+    let entries: Vec<String> = entries.into_iter().map(|(_, entry)| entry).collect();
+    entries.join("\n")
+}
@@ -0,0 +1,125 @@
+//! Locates escape sequences without decoding them.
+//!
+//! This module contains the `find_escapes` scanner, which reports the byte range and kind of every
+//! escape sequence in a string. It is intended for tools such as editors that only need to
+//! highlight escape sequences and have no use for the decoded values.
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::parser::{self, Escape};
+use crate::{DecodeError, DecodeOptions};
+
+/// The family an escape sequence belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EscapeKind {
+    /// A simple escape sequence, e.g. `\n` or `\\`.
+    Simple,
+    /// An 8 bit hexadecimal escape sequence, e.g. `\x02`.
+    Hex,
+    /// A Unicode escape sequence, e.g. `\u{1A2B}`.
+    Unicode,
+    /// An octal escape sequence, e.g. `\101`.
+    Octal,
+    /// A decimal escape sequence, e.g. `\65`.
+    Decimal,
+    /// A named Unicode escape sequence, e.g. `\N{BULLET}`. Requires the `names` feature.
+    Named,
+    /// A backslash that does not start a valid escape sequence.
+    Invalid,
+}
+
+/// The location and kind of a single escape sequence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EscapeSpan {
+    /// The family the escape sequence belongs to.
+    pub kind: EscapeKind,
+    /// The byte range of the escape sequence in the input, including the backslash.
+    pub range: Range<usize>,
+}
+
+/// An iterator over the escape sequences of a string.
+///
+/// This struct is created by the `find_escapes` function.
+#[derive(Debug, Clone)]
+pub struct FindEscapes<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Iterator for FindEscapes<'_> {
+    type Item = EscapeSpan;
+
+    fn next(&mut self) -> Option<EscapeSpan> {
+        let start = self.pos + self.input[self.pos..].find('\\')?;
+        let (range, result) = scan_escape(self.input, start);
+        self.pos = range.end;
+        let kind = result.map_or(EscapeKind::Invalid, |escape| escape.kind);
+        Some(EscapeSpan { kind, range })
+    }
+}
+
+impl FusedIterator for FindEscapes<'_> {}
+
+/// Scans the escape sequence starting at the backslash at byte offset `start`.
+///
+/// Returns the byte range of the escape sequence along with the parsed escape sequence, or along
+/// with the error if it is invalid.
+pub(crate) fn scan_escape(
+    input: &str,
+    start: usize,
+) -> (Range<usize>, Result<Escape, DecodeError>) {
+    match parser::parse_escape(input, start, &DecodeOptions::DEFAULT) {
+        Ok(escape) => (start..escape.end, Ok(escape)),
+        Err(kind) => {
+            let span = start..parser::invalid_end(input, start, &DecodeOptions::DEFAULT);
+            (span.clone(), Err(DecodeError::new(kind).at(span)))
+        }
+    }
+}
+
+/// Finds the escape sequences in a string without decoding them.
+///
+/// This function scans the input for backslashes and reports the byte range and kind of each
+/// escape sequence it finds. Backslashes that do not start a valid escape sequence are reported
+/// with the kind `EscapeKind::Invalid` and cover the backslash, the character following it and
+/// the rest of a malformed escape sequence, e.g. all of `\u{110000}`.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// An iterator yielding an `EscapeSpan` for every escape sequence, in input order.
+pub fn find_escapes(input: &str) -> FindEscapes<'_> {
+    FindEscapes { input, pos: 0 }
+}
+
+/// Checks whether a string contains any escape sequences.
+///
+/// Only valid escape sequences are counted; a backslash that does not start one is treated as
+/// literal text. Neither result says whether the input is valid: `false` means there is nothing
+/// for `decode` to replace, but `decode` still rejects an invalid escape sequence such as `\q`,
+/// and `true` is returned for an input with both valid and invalid escape sequences.
+///
+/// ```
+/// use unicode_escape::{decode, needs_decoding};
+///
+/// assert!(!needs_decoding(r"plain text"));
+/// assert!(needs_decoding(r"\t\q"));
+/// assert!(decode(r"\t\q").is_err());
+/// assert!(!needs_decoding(r"\q"));
+/// assert!(decode(r"\q").is_err());
+/// ```
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// `true` if the input contains at least one valid escape sequence, `false` otherwise.
+pub fn needs_decoding(input: &str) -> bool {
+    find_escapes(input).any(|span| span.kind != EscapeKind::Invalid)
+}
@@ -0,0 +1,211 @@
+//! Segments decoded text into grapheme clusters.
+//!
+//! This module is only available with the `unicode-segmentation` feature. It implements the
+//! extended grapheme cluster rules of Unicode Standard Annex #29, and uses them to report where
+//! the clusters of a decoded string start and which of them are assembled from several escape
+//! sequences or from escaped and literal text. Such clusters are split apart by any logic that
+//! truncates or measures the escaped text instead of the decoded one.
+use std::ops::Range;
+
+use crate::{decode_with_map, DecodeError};
+
+/// The Grapheme_Cluster_Break property of a character, plus Extended_Pictographic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum GraphemeBreak {
+    Other,
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+}
+
+include!("tables/grapheme_break.rs");
+
+fn grapheme_break(c: char) -> GraphemeBreak {
+    let code = u32::from(c);
+    let index = GRAPHEME_BREAK.partition_point(|&(_, last, _)| last < code);
+    match GRAPHEME_BREAK.get(index) {
+        Some(&(first, _, value)) if first <= code => value,
+        _ => GraphemeBreak::Other,
+    }
+}
+
+/// Tracks the state needed to decide on boundaries between two characters.
+#[derive(Default)]
+struct Segmenter {
+    previous: Option<GraphemeBreak>,
+    /// Whether the current cluster is an Extended_Pictographic followed by Extend characters.
+    in_emoji: bool,
+    /// Whether the previous character was a ZWJ ending such a sequence.
+    emoji_zwj: bool,
+    /// The number of consecutive regional indicators before the current character.
+    regional_indicators: usize,
+}
+
+impl Segmenter {
+    /// Feeds the next character and returns whether a cluster boundary precedes it.
+    fn is_boundary(&mut self, c: char) -> bool {
+        use GraphemeBreak::*;
+
+        let current = grapheme_break(c);
+        let boundary = match (self.previous, current) {
+            // GB1: break at the start of text
+            (None, _) => true,
+            // GB3, GB4, GB5: keep CR LF together, break around other controls
+            (Some(CR), LF) => false,
+            (Some(CR | LF | Control), _) | (_, CR | LF | Control) => true,
+            // GB6, GB7, GB8: keep Hangul syllables together
+            (Some(L), L | V | LV | LVT) => false,
+            (Some(LV | V), V | T) => false,
+            (Some(LVT | T), T) => false,
+            // GB9, GB9a, GB9b: keep extending and spacing marks with their base
+            (_, Extend | ZWJ | SpacingMark) => false,
+            (Some(Prepend), _) => false,
+            // GB11: keep emoji ZWJ sequences together
+            (Some(ZWJ), ExtendedPictographic) if self.emoji_zwj => false,
+            // GB12, GB13: keep pairs of regional indicators together
+            (Some(RegionalIndicator), RegionalIndicator) => {
+                self.regional_indicators.is_multiple_of(2)
+            }
+            // GB999: break everywhere else
+            _ => true,
+        };
+
+        self.emoji_zwj = self.in_emoji && current == ZWJ;
+        self.in_emoji = match current {
+            ExtendedPictographic => true,
+            Extend => self.in_emoji,
+            _ => false,
+        };
+        self.regional_indicators = match current {
+            RegionalIndicator => self.regional_indicators + 1,
+            _ => 0,
+        };
+        self.previous = Some(current);
+        boundary
+    }
+}
+
+/// Returns the byte offsets at which the grapheme clusters of a string start.
+///
+/// # Parameters
+///
+/// * `text`: The text to segment, typically a decoded string.
+///
+/// # Returns
+///
+/// The start offset of every grapheme cluster, in order. The vector is empty for an empty string.
+pub fn grapheme_boundaries(text: &str) -> Vec<usize> {
+    let mut segmenter = Segmenter::default();
+    text.char_indices()
+        .filter(|&(_, c)| segmenter.is_boundary(c))
+        .map(|(offset, _)| offset)
+        .collect()
+}
+
+/// Checks whether a grapheme cluster is an emoji sequence, i.e. contains an Extended_Pictographic
+/// or a Regional_Indicator character.
+pub(crate) fn is_emoji_sequence(cluster: &str) -> bool {
+    cluster.chars().any(|c| {
+        matches!(
+            grapheme_break(c),
+            GraphemeBreak::ExtendedPictographic | GraphemeBreak::RegionalIndicator
+        )
+    })
+}
+
+/// A grapheme cluster assembled from more than one piece of the escaped input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplitCluster {
+    /// The byte range of the cluster in the decoded string.
+    pub output: Range<usize>,
+    /// The byte range of the escaped input the cluster was decoded from.
+    pub input: Range<usize>,
+}
+
+/// The grapheme clusters of a decoded string, as reported by `grapheme_report`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphemeReport {
+    /// The decoded string.
+    pub decoded: String,
+    /// The byte offsets in the decoded string at which the grapheme clusters start.
+    pub boundaries: Vec<usize>,
+    /// The clusters that are split across escape sequences, or across an escape sequence and
+    /// literal text, in the input.
+    pub split_clusters: Vec<SplitCluster>,
+}
+
+impl GraphemeReport {
+    /// Returns the number of grapheme clusters in the decoded string.
+    pub fn cluster_count(&self) -> usize {
+        self.boundaries.len()
+    }
+}
+
+/// Decodes a string and reports the grapheme clusters of the decoded output.
+///
+/// Besides the cluster boundaries, the report lists every cluster whose characters were produced
+/// by more than one escape sequence or by escape sequences and literal text, e.g. `e\u{301}` or
+/// `\u{1F468}\u{200D}\u{1F4BB}`.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Result` containing the report or an error if an escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode`.
+pub fn grapheme_report(input: &str) -> Result<GraphemeReport, DecodeError> {
+    let (decoded, map) = decode_with_map(input)?;
+    let boundaries = grapheme_boundaries(&decoded);
+
+    // Walk the clusters in step with the decoded characters to find their input ranges
+    let mut split_clusters = Vec::new();
+    let mut chars = decoded.char_indices().enumerate().peekable();
+    for (i, &start) in boundaries.iter().enumerate() {
+        let end = boundaries.get(i + 1).copied().unwrap_or(decoded.len());
+        let mut pieces: Vec<Range<usize>> = Vec::new();
+        while let Some(&(index, (offset, _))) = chars.peek() {
+            if offset >= end {
+                break;
+            }
+            chars.next();
+            let range = map
+                .input_range(index)
+                .expect("decoded character outside of map");
+            if pieces.last() != Some(&range) {
+                pieces.push(range);
+            }
+        }
+        let escaped = pieces
+            .iter()
+            .any(|range| input[range.clone()].starts_with('\\'));
+        if escaped && pieces.len() > 1 {
+            split_clusters.push(SplitCluster {
+                output: start..end,
+                input: pieces[0].start..pieces[pieces.len() - 1].end,
+            });
+        }
+    }
+
+    Ok(GraphemeReport {
+        decoded,
+        boundaries,
+        split_clusters,
+    })
+}
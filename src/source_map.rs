@@ -0,0 +1,215 @@
+//! Maps decoded characters back to their position in the escaped input.
+//!
+//! This module contains the `decode_with_map` function and the `SourceMap` it returns. A second
+//! stage parser working on the decoded string can use the map to report its own errors against
+//! the original escaped text.
+use std::ops::Range;
+
+use crate::invariants::invariant;
+use crate::parser::{Token, Tokens};
+use crate::DecodeError;
+
+/// A run of decoded characters that each consumed the same number of input bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    /// The index of the first decoded character of the run.
+    output: usize,
+    /// The byte offset in the input of the first character of the run.
+    input: usize,
+    /// The number of input bytes each character of the run was decoded from.
+    stride: usize,
+}
+
+/// A compact mapping from decoded character indices to input byte offsets.
+///
+/// Characters are grouped into runs that advance through the input at a constant rate, so plain
+/// text and repeated escape sequences of the same length only take up a single entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    segments: Vec<Segment>,
+    output_len: usize,
+    input_len: usize,
+}
+
+impl SourceMap {
+    /// Records that the next decoded character was produced from `len` input bytes at `input`.
+    fn push(&mut self, input: usize, len: usize) {
+        let last_end = self.segments.last().map_or(0, |last| {
+            last.input + (self.output_len - last.output) * last.stride
+        });
+        invariant!(
+            last_end <= input && input + len <= self.input_len,
+            "the source map entry {input}..{} does not follow {last_end} within {}",
+            input + len,
+            self.input_len
+        );
+        let extends_last = self.segments.last().is_some_and(|last| {
+            last.stride == len && last.input + (self.output_len - last.output) * len == input
+        });
+        if !extends_last {
+            self.segments.push(Segment {
+                output: self.output_len,
+                input,
+                stride: len,
+            });
+        }
+        self.output_len += 1;
+    }
+
+    /// Records that the next `count` decoded characters were each produced from `len` input bytes,
+    /// the first of them at `input`.
+    fn push_run(&mut self, input: usize, len: usize, count: usize) {
+        if count > 0 {
+            // After the first character, the last segment is a run of `len` byte characters
+            self.push(input, len);
+            self.output_len += count - 1;
+        }
+    }
+
+    /// Replaces the entries of the decoded characters `chars` with `entries`, the input offsets
+    /// and lengths of the characters replacing them, after an edit of the input.
+    ///
+    /// The input offsets of the characters following `chars` move by `shift` bytes, and the input
+    /// is now `input_len` bytes long.
+    pub(crate) fn splice(
+        &mut self,
+        chars: Range<usize>,
+        entries: &[(usize, usize)],
+        shift: isize,
+        input_len: usize,
+    ) {
+        let mut map = SourceMap {
+            segments: Vec::with_capacity(self.segments.len()),
+            output_len: 0,
+            input_len,
+        };
+        let ends = self
+            .segments
+            .iter()
+            .skip(1)
+            .map(|segment| segment.output)
+            .chain([self.output_len]);
+        let runs: Vec<(Segment, usize)> = self.segments.iter().copied().zip(ends).collect();
+        for &(segment, end) in &runs {
+            let end = end.min(chars.start);
+            if segment.output < end {
+                map.push_run(segment.input, segment.stride, end - segment.output);
+            }
+        }
+        for &(input, len) in entries {
+            map.push(input, len);
+        }
+        for &(segment, end) in &runs {
+            let start = segment.output.max(chars.end);
+            if start < end {
+                let input = segment.input + (start - segment.output) * segment.stride;
+                map.push_run(
+                    input.wrapping_add_signed(shift),
+                    segment.stride,
+                    end - start,
+                );
+            }
+        }
+        *self = map;
+    }
+
+    /// Returns the number of decoded characters covered by the map.
+    pub fn len(&self) -> usize {
+        self.output_len
+    }
+
+    /// Returns `true` if the decoded string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.output_len == 0
+    }
+
+    /// Returns the input byte range the decoded character at `index` was produced from.
+    ///
+    /// For a character produced by an escape sequence this is the range of the whole escape
+    /// sequence, otherwise it is the range of the character itself.
+    ///
+    /// # Parameters
+    ///
+    /// * `index`: The index of a character in the decoded string.
+    ///
+    /// # Returns
+    ///
+    /// The input byte range, or `None` if `index` is out of bounds.
+    pub fn input_range(&self, index: usize) -> Option<Range<usize>> {
+        if index >= self.output_len {
+            return None;
+        }
+        let segment = self.segments[self.segments.partition_point(|s| s.output <= index) - 1];
+        let start = segment.input + (index - segment.output) * segment.stride;
+        Some(start..start + segment.stride)
+    }
+
+    /// Returns the input byte offset the decoded character at `index` was produced from.
+    ///
+    /// An `index` equal to the length of the decoded string maps to the end of the input, so that
+    /// errors at the end of the decoded string can be reported as well.
+    ///
+    /// # Parameters
+    ///
+    /// * `index`: The index of a character in the decoded string.
+    ///
+    /// # Returns
+    ///
+    /// The input byte offset, or `None` if `index` is out of bounds.
+    pub fn input_offset(&self, index: usize) -> Option<usize> {
+        if index == self.output_len {
+            return Some(self.input_len);
+        }
+        self.input_range(index).map(|range| range.start)
+    }
+}
+
+/// Decodes a string with escape sequences and records where each decoded character came from.
+///
+/// This function decodes the input exactly like `decode` does, and additionally returns a
+/// `SourceMap` relating every character of the decoded string to its position in the input.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Result` containing the decoded string and its source map, or an error if an escape
+/// sequence is invalid.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode`.
+pub fn decode_with_map(input: &str) -> Result<(String, SourceMap), DecodeError> {
+    let mut result = String::with_capacity(input.len());
+    let mut map = SourceMap {
+        input_len: input.len(),
+        ..SourceMap::default()
+    };
+    let mut pos = 0;
+
+    for token in Tokens::new(input) {
+        match token? {
+            Token::Literal(text) => {
+                result.push_str(text);
+                for (offset, c) in text.char_indices() {
+                    map.push(pos + offset, c.len_utf8());
+                }
+                pos += text.len();
+            }
+            Token::Escape(escape) => {
+                result.push(escape.value);
+                map.push(pos, escape.end - pos);
+                pos = escape.end;
+            }
+        }
+    }
+    invariant!(
+        map.len() == result.chars().count(),
+        "the source map covers {} of {} characters",
+        map.len(),
+        result.chars().count()
+    );
+    Ok((result, map))
+}
@@ -0,0 +1,133 @@
+//! Collects statistics about the escape sequences in a string.
+//!
+//! This module contains the `decode_stats` function and the `DecodeStats` report it returns,
+//! which summarise what decoding a string would produce without building the decoded string, and
+//! the cheaper `escape_density` analyzer.
+use std::collections::HashMap;
+
+use crate::parser::{Token, Tokens};
+use crate::{DecodeError, EscapeKind};
+
+/// A summary of the escape sequences in a string and of its decoded form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeStats {
+    /// The length of the decoded string in bytes.
+    pub decoded_len: usize,
+    /// The number of characters in the decoded string.
+    pub decoded_chars: usize,
+    /// The number of non-ASCII characters in the decoded string.
+    pub non_ascii_chars: usize,
+    escape_counts: HashMap<EscapeKind, usize>,
+}
+
+impl DecodeStats {
+    /// Returns the number of escape sequences of the given kind.
+    pub fn escapes(&self, kind: EscapeKind) -> usize {
+        self.escape_counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of escape sequences.
+    pub fn total_escapes(&self) -> usize {
+        self.escape_counts.values().sum()
+    }
+}
+
+/// Collects statistics about decoding a string with escape sequences.
+///
+/// This function walks the input exactly like `decode` does, but only counts what it encounters
+/// instead of building the decoded string.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// A `Result` containing the statistics or an error if an escape sequence is invalid.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode`.
+pub fn decode_stats(input: &str) -> Result<DecodeStats, DecodeError> {
+    let mut stats = DecodeStats::default();
+
+    for token in Tokens::new(input) {
+        match token? {
+            Token::Literal(text) => {
+                stats.decoded_len += text.len();
+                for c in text.chars() {
+                    stats.decoded_chars += 1;
+                    stats.non_ascii_chars += usize::from(!c.is_ascii());
+                }
+            }
+            Token::Escape(escape) => {
+                stats.decoded_len += escape.value.len_utf8();
+                stats.decoded_chars += 1;
+                stats.non_ascii_chars += usize::from(!escape.value.is_ascii());
+                *stats.escape_counts.entry(escape.kind).or_insert(0) += 1;
+            }
+        }
+    }
+    Ok(stats)
+}
+
+/// Ratios describing how densely a string is populated with escape sequences.
+///
+/// All ratios are relative to the length of the escaped input in bytes, and are `0.0` for an empty
+/// input.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EscapeDensity {
+    /// The number of escape sequences, valid or not, per input byte.
+    pub escapes_per_byte: f64,
+    /// The number of invalid escape sequences per input byte.
+    pub invalid_escapes_per_byte: f64,
+    /// The number of control characters in the decoded string per input byte.
+    pub control_chars_per_byte: f64,
+    /// The number of Unicode escape sequences, including named ones, per input byte.
+    pub unicode_escapes_per_byte: f64,
+}
+
+/// Measures the escape sequence density of a string in a single pass.
+///
+/// This function is meant as a cheap pre-filter, e.g. to flag suspicious log records before fully
+/// decoding them. It never fails; invalid escape sequences are counted rather than reported.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The `EscapeDensity` of the input.
+pub fn escape_density(input: &str) -> EscapeDensity {
+    if input.is_empty() {
+        return EscapeDensity::default();
+    }
+
+    let (mut escapes, mut invalid, mut controls, mut unicode) = (0usize, 0usize, 0usize, 0usize);
+    for token in Tokens::new(input) {
+        match token {
+            Ok(Token::Literal(text)) => controls += text.chars().filter(|c| c.is_control()).count(),
+            Ok(Token::Escape(escape)) => {
+                escapes += 1;
+                controls += usize::from(escape.value.is_control());
+                unicode += usize::from(matches!(
+                    escape.kind,
+                    EscapeKind::Unicode | EscapeKind::Named
+                ));
+            }
+            Err(_) => {
+                escapes += 1;
+                invalid += 1;
+            }
+        }
+    }
+
+    let len = input.len() as f64;
+    EscapeDensity {
+        escapes_per_byte: escapes as f64 / len,
+        invalid_escapes_per_byte: invalid as f64 / len,
+        control_chars_per_byte: controls as f64 / len,
+        unicode_escapes_per_byte: unicode as f64 / len,
+    }
+}
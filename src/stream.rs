@@ -0,0 +1,395 @@
+//! Decodes escaped text that arrives in chunks.
+//!
+//! Data received from a network connection or read from a pipe comes in chunks whose boundaries
+//! are arbitrary, so an escape sequence such as `\u{1F600}` may be split into `\u{1F` and `600}`.
+//! A `Decoder` buffers such partial escape sequences until the rest of them arrives, and returns
+//! the decoded text of each chunk as far as it is complete.
+use crate::case_folding::fold_case;
+use crate::message_format;
+use crate::parser::{prepass_unicode, Token, Tokens};
+use crate::{BomPolicy, DecodeError, DecodeOptions, ErrorKind, LineContinuation};
+
+/// A stateful decoder for escaped text split into chunks.
+///
+/// Concatenating the output of every `feed` and of `finish` yields the same string as passing
+/// the concatenated chunks to `decode_with` at once.
+///
+/// ```
+/// use unicode_escape::Decoder;
+///
+/// let mut decoder = Decoder::new();
+/// assert_eq!(decoder.feed(r"caf\u{E").unwrap(), "caf");
+/// assert_eq!(decoder.feed(r"9} \x4").unwrap(), "\u{E9} ");
+/// assert_eq!(decoder.feed(r"1").unwrap(), "A");
+/// assert_eq!(decoder.finish().unwrap(), "");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    options: DecodeOptions,
+    /// The input that has not been decoded yet, starting with a partial escape sequence.
+    input: String,
+    /// Decoded text that is held back because later input may still change it.
+    held: String,
+    /// The length of `held` after the last quoted run of the MessageFormat syntax.
+    quoted_end: usize,
+    /// Whether no decoded text has been produced yet.
+    at_start: bool,
+    /// The number of bytes of the input decoded before `input`, which locates errors in the input
+    /// as a whole.
+    offset: usize,
+}
+
+/// Checks whether `rest`, the undecoded input from a backslash or a quote character to the end of
+/// what has been received, may still become a valid escape sequence, or a longer one, once more
+/// input arrives.
+fn may_continue(rest: &str, options: &DecodeOptions) -> bool {
+    // A quote character may still be doubled
+    if options
+        .doubled_quote
+        .is_some_and(|quote| rest.strip_prefix(quote) == Some(""))
+    {
+        return true;
+    }
+    if !options.backslash_escapes {
+        return false;
+    }
+    if options.unicode_prepass {
+        return rest.starts_with('\\') && may_continue_prepass(rest, options);
+    }
+    let Some(rest) = rest.strip_prefix('\\') else {
+        return false;
+    };
+    let is_hex = |s: &str| s.bytes().all(|b| b.is_ascii_hexdigit());
+    let mut chars = rest.chars();
+    match chars.next() {
+        None => true,
+        Some(c @ (' ' | '\t' | '\n' | '\r')) => {
+            // More whitespace to trim may follow
+            let whitespace = chars
+                .as_str()
+                .bytes()
+                .all(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\r'));
+            match options.line_continuation {
+                Some(LineContinuation::TrimAllWhitespace) => whitespace,
+                Some(LineContinuation::TrimWhitespace) => matches!(c, '\n' | '\r') && whitespace,
+                Some(LineContinuation::Remove | LineContinuation::RemoveUnicode) => {
+                    c == '\r' && chars.as_str().is_empty()
+                }
+                Some(LineContinuation::Newline) => {
+                    matches!(c, '\n' | '\r') && chars.as_str().is_empty()
+                }
+                None => false,
+            }
+        }
+        Some('c') if options.control_escapes => matches!(chars.as_str(), "" | "\\"),
+        Some('z') if options.skip_whitespace => chars
+            .as_str()
+            .bytes()
+            .all(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')),
+        Some('0'..='9') if options.decimal_escapes => {
+            chars.as_str().len() < 2 && chars.as_str().bytes().all(|b| b.is_ascii_digit())
+        }
+        Some(c) if options.octal_start(c) => {
+            chars.as_str().len() < 2 && chars.as_str().bytes().all(|b| matches!(b, b'0'..=b'7'))
+        }
+        Some('x') => {
+            let max = options.max_hex_digits.unwrap_or(usize::MAX);
+            options.hex_escapes && chars.as_str().len() < max && is_hex(chars.as_str())
+        }
+        Some('u') if options.unicode_escapes => {
+            let after = chars.as_str();
+            match after.strip_prefix('{') {
+                Some(body) if options.braced_unicode => match body.find('}') {
+                    None => body
+                        .bytes()
+                        .all(|b| b.is_ascii_hexdigit() || matches!(b, b'_' | b' ')),
+                    // A high surrogate waiting for the low surrogate it combines with
+                    Some(end) => {
+                        let code = u32::from_str_radix(&body[..end].replace('_', ""), 16);
+                        let low = &body[end + 1..];
+                        options.combine_surrogates
+                            && code.is_ok_and(|code| (0xD800..0xDC00).contains(&code))
+                            && (r"\u{".starts_with(low)
+                                || low.strip_prefix(r"\u{").is_some_and(|digits| {
+                                    digits.bytes().all(|b| b.is_ascii_hexdigit() || b == b'_')
+                                })
+                                || (options.fixed_unicode
+                                    && low
+                                        .strip_prefix(r"\u")
+                                        .is_some_and(|digits| digits.len() < 4 && is_hex(digits))))
+                    }
+                },
+                _ if options.fixed_unicode && after.len() >= 4 => {
+                    // A high surrogate waiting for the low surrogate it combines with
+                    let code = after.get(..4).filter(|hex| is_hex(hex));
+                    let code = code.and_then(|hex| u32::from_str_radix(hex, 16).ok());
+                    let low = after.get(4..).unwrap_or_default();
+                    options.combine_surrogates
+                        && code.is_some_and(|code| (0xD800..0xDC00).contains(&code))
+                        && (r"\u".starts_with(low)
+                            || low
+                                .strip_prefix(r"\u")
+                                .is_some_and(|digits| digits.len() < 4 && is_hex(digits))
+                            || (options.braced_unicode
+                                && low.strip_prefix(r"\u{").is_some_and(|digits| {
+                                    digits.bytes().all(|b| b.is_ascii_hexdigit() || b == b'_')
+                                })))
+                }
+                _ => {
+                    (options.braced_unicode && after.is_empty())
+                        || (options.fixed_unicode && is_hex(after))
+                }
+            }
+        }
+        Some('U') => {
+            options.unicode_escapes
+                && options.long_unicode
+                && chars.as_str().len() < 8
+                && is_hex(chars.as_str())
+        }
+        #[cfg(feature = "names")]
+        Some('N') if options.named_escapes => {
+            let after = chars.as_str();
+            after.is_empty() || (after.starts_with('{') && !after.contains('}'))
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether `error`, which decoding `input` from the token boundary at byte `start` fails
+/// with, may still change once more input arrives, because its invalid escape sequence ends with
+/// the input and may be extended, like `\u{q` whose span reaches up to the closing brace of
+/// `\u{qAF}`.
+fn may_extend(input: &str, start: usize, error: &DecodeError, options: &DecodeOptions) -> bool {
+    if error.span().is_none_or(|span| span.end < input.len()) {
+        return false;
+    }
+    // Every escape sequence that more input extends takes a `0`, except for the opening brace of
+    // a rejected `\N{...}`
+    ["0", "{"].iter().any(|next| {
+        let probe = format!("{}{next}", &input[start..]);
+        let extended = Tokens::with_options(&probe, options).find_map(Result::err);
+        extended.is_some_and(|extended| extended.shifted(start) != *error)
+    })
+}
+
+/// Checks like `may_continue` whether `rest` may still become a valid escape sequence, or a longer
+/// one, if the options translate `\uXXXX` escape sequences before the others.
+fn may_continue_prepass(rest: &str, options: &DecodeOptions) -> bool {
+    // The code points of the first characters after the translation, up to the first one that has
+    // not been received completely, or `u32::MAX` standing for an invalid one
+    let mut chars = Vec::new();
+    let mut pos = 0;
+    while pos < rest.len() && chars.len() < 4 {
+        // A backslash escaped by the backslash before it does not start a Unicode escape sequence
+        let escaped = pos == 1 && rest.starts_with(r"\\\\");
+        let (code, len) = match prepass_unicode(&rest[pos..]) {
+            Some(Ok(unicode)) if !escaped => unicode,
+            Some(Err(_)) if !escaped => {
+                let digits = rest[pos + 1..].trim_start_matches('u');
+                if digits.len() < 4 && digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    break;
+                }
+                (u32::MAX, rest.len() - pos)
+            }
+            _ if &rest[pos..] == "\\" && !escaped => break,
+            _ => {
+                let c = rest[pos..].chars().next().unwrap_or_default();
+                (u32::from(c), c.len_utf8())
+            }
+        };
+        chars.push(code);
+        pos += len;
+    }
+    let is_octal = |code: &u32| (0x30..0x38).contains(code);
+    match chars.as_slice() {
+        [] | [0x5C] => true,
+        [0xD800..=0xDBFF] => options.combine_surrogates,
+        [0x5C, c, digits @ ..] if char::from_u32(*c).is_some_and(|c| options.octal_start(c)) => {
+            digits.len() < 2 && digits.iter().all(is_octal)
+        }
+        _ => false,
+    }
+}
+
+/// Applies the `BomPolicy` of the options to a byte order mark at the start of `out`, the first
+/// decoded text, moving `quoted_end` along with the text.
+fn check_bom(
+    out: &mut String,
+    quoted_end: &mut usize,
+    options: &DecodeOptions,
+) -> Result<(), DecodeError> {
+    if out.starts_with('\u{FEFF}') {
+        match options.bom {
+            BomPolicy::Preserve => {}
+            BomPolicy::Strip => {
+                out.remove(0);
+                *quoted_end = quoted_end.saturating_sub('\u{FEFF}'.len_utf8());
+            }
+            BomPolicy::Reject => return Err(DecodeError::new(ErrorKind::ByteOrderMark).at(0..0)),
+        }
+    }
+    Ok(())
+}
+
+impl Default for Decoder {
+    fn default() -> Decoder {
+        Decoder::new()
+    }
+}
+
+impl Decoder {
+    /// Creates a decoder decoding like `decode`.
+    pub fn new() -> Decoder {
+        Decoder::with_options(DecodeOptions::new())
+    }
+
+    /// Creates a decoder decoding like `decode_with` with the given options.
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: The options controlling how escape sequences are interpreted.
+    ///
+    /// # Returns
+    ///
+    /// A decoder that has not received any input yet.
+    pub fn with_options(options: DecodeOptions) -> Decoder {
+        Decoder {
+            options,
+            input: String::new(),
+            held: String::new(),
+            quoted_end: 0,
+            at_start: true,
+            offset: 0,
+        }
+    }
+
+    /// Returns the input that has been received but not decoded yet, e.g. `\u{1F` after a chunk
+    /// ending in the middle of an escape sequence.
+    pub fn pending(&self) -> &str {
+        &self.input
+    }
+
+    /// Decodes the next chunk of the input.
+    ///
+    /// A partial escape sequence at the end of the chunk is kept until the following chunk
+    /// completes it. Some decoded text may be held back as well, if the options quote
+    /// MessageFormat syntax or normalize the decoded text, since the following chunk may still
+    /// change it.
+    ///
+    /// # Parameters
+    ///
+    /// * `chunk`: The next piece of the escaped input.
+    ///
+    /// # Returns
+    ///
+    /// The decoded text that is complete after this chunk, which may be empty.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as `decode_with`, as soon as an escape sequence is
+    /// known to be invalid and where it ends, e.g. once the closing brace of `\u{q}` arrives. After
+    /// an error, the decoder starts over with a new input.
+    pub fn feed(&mut self, chunk: &str) -> Result<String, DecodeError> {
+        self.input.push_str(chunk);
+        self.decode(false)
+    }
+
+    /// Decodes the rest of the input, including any held back text.
+    ///
+    /// # Returns
+    ///
+    /// The rest of the decoded text.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as `decode_with`, including those of a partial
+    /// escape sequence at the end of the input, such as `\x4`.
+    pub fn finish(mut self) -> Result<String, DecodeError> {
+        self.decode(true)
+    }
+
+    /// Decodes the received input, keeping a partial escape sequence at its end unless `last`.
+    fn decode(&mut self, last: bool) -> Result<String, DecodeError> {
+        let result = self.decode_input(last);
+        if result.is_err() {
+            *self = Decoder::with_options(self.options.clone());
+        }
+        result
+    }
+
+    /// Decodes the received input like `decode`, without resetting the decoder on errors.
+    fn decode_input(&mut self, last: bool) -> Result<String, DecodeError> {
+        let options = &self.options;
+        let mut out = std::mem::take(&mut self.held);
+        let mut quoted_end = self.quoted_end;
+        // The characters of a brace group are only taken once the group is complete
+        let mut group = Vec::new();
+        let mut consumed = 0;
+        let mut tokens = Tokens::with_options(&self.input, options);
+        loop {
+            // The byte order mark is checked before the input following it, like `decode_with`
+            // does
+            if self.at_start && !out.is_empty() {
+                self.at_start = false;
+                check_bom(&mut out, &mut quoted_end, options)?;
+            }
+            let Some(token) = tokens.next_token() else {
+                break;
+            };
+            match token {
+                // An escape sequence may still grow once more input arrives, such as `\1` into
+                // `\12` or `\uD83D` followed by `\` into a surrogate pair
+                Ok(_) if !last && may_continue(&self.input[consumed..], options) => break,
+                Ok(Token::Literal(text)) => out.push_str(text),
+                Ok(Token::Escape(escape)) => {
+                    group.push(escape.value);
+                    if escape.open_group {
+                        continue;
+                    }
+                    for c in group.drain(..) {
+                        if options.message_format {
+                            message_format::push_literal(&mut out, c, &mut quoted_end);
+                        } else {
+                            out.push(c);
+                        }
+                    }
+                }
+                Err(_) if !last && may_continue(&self.input[consumed..], options) => break,
+                Err(error) if !last && may_extend(&self.input, consumed, &error, options) => break,
+                Err(error) => return Err(error.shifted(self.offset)),
+            }
+            consumed = tokens.offset();
+        }
+        self.input.drain(..consumed);
+        self.offset += consumed;
+        if self.at_start && !out.is_empty() {
+            self.at_start = false;
+            check_bom(&mut out, &mut quoted_end, options)?;
+        }
+
+        let mut split = out.len();
+        if !last {
+            // A quoted run at the end may still be extended by the next brace
+            if options.message_format && quoted_end == out.len() && out.ends_with('\'') {
+                split -= 1;
+            }
+            // Normalization never combines a character with an ASCII character following it
+            #[cfg(feature = "unicode-normalization")]
+            if options.normalization.is_some() {
+                split = out[..split].rfind(|c: char| c.is_ascii()).unwrap_or(0);
+            }
+        }
+        self.held = out.split_off(split);
+        self.quoted_end = quoted_end.checked_sub(split).unwrap_or(usize::MAX);
+
+        if let Some(folding) = options.case_folding {
+            out = fold_case(&out, folding);
+        }
+        #[cfg(feature = "unicode-normalization")]
+        if let Some(form) = options.normalization {
+            out = crate::normalization::normalize(&out, form);
+        }
+        Ok(out)
+    }
+}
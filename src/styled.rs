@@ -0,0 +1,137 @@
+//! Decodes strings while keeping the spelling of every escape sequence.
+//!
+//! This module contains the `decode_styled` function, which decodes a string into segments that
+//! remember how they were written in the input, e.g. that an `A` was written as `\x41`. The
+//! segments can be inspected and modified, and `StyledString::encode` writes them out again,
+//! reproducing the unmodified parts of the input byte for byte.
+use crate::parser::{Token, Tokens};
+use crate::{encode, DecodeError, DecodeOptions, EscapeKind};
+
+/// A single segment of a `StyledString`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyledSegment {
+    /// A run of input that decodes to itself, including escape sequences the options keep, such
+    /// as `\q` with `UnknownEscapePolicy::Preserve`.
+    Literal(String),
+    /// A character written as an escape sequence. Every code point of a brace group such as
+    /// `\u{41 42}` is a segment of its own, whose source is its part of the group.
+    Escape {
+        /// The escape sequence as it appears in the input.
+        source: String,
+        /// The family the escape sequence belongs to.
+        kind: EscapeKind,
+        /// The character the escape sequence decodes to.
+        decoded: char,
+    },
+    /// Input that decodes to other text without being an escape sequence, such as a line
+    /// continuation or a quote character written twice.
+    Substituted {
+        /// The text as it appears in the input.
+        source: String,
+        /// The text the input decodes to, which may be empty.
+        decoded: String,
+    },
+    /// Decoded text without a spelling of its own, e.g. text inserted after decoding, which
+    /// `StyledString::encode` escapes like `encode` does.
+    Text(String),
+}
+
+impl StyledSegment {
+    /// Returns the text the segment decodes to.
+    pub fn decoded(&self) -> String {
+        match self {
+            StyledSegment::Literal(text) | StyledSegment::Text(text) => text.clone(),
+            StyledSegment::Escape { decoded, .. } => decoded.to_string(),
+            StyledSegment::Substituted { decoded, .. } => decoded.clone(),
+        }
+    }
+
+    /// Returns the escaped form of the segment.
+    pub fn encoded(&self) -> String {
+        match self {
+            StyledSegment::Literal(text) => text.clone(),
+            StyledSegment::Escape { source, .. } | StyledSegment::Substituted { source, .. } => {
+                source.clone()
+            }
+            StyledSegment::Text(text) => encode(text),
+        }
+    }
+}
+
+/// A decoded string that remembers the spelling of its input, segment by segment.
+///
+/// This struct is created by the `decode_styled` function.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyledString {
+    segments: Vec<StyledSegment>,
+}
+
+impl StyledString {
+    /// Returns the segments of the string, in input order.
+    pub fn segments(&self) -> &[StyledSegment] {
+        &self.segments
+    }
+
+    /// Returns the segments of the string for modification, e.g. to replace an escape sequence
+    /// with a `StyledSegment::Text`.
+    pub fn segments_mut(&mut self) -> &mut Vec<StyledSegment> {
+        &mut self.segments
+    }
+
+    /// Returns the decoded string.
+    pub fn decoded(&self) -> String {
+        self.segments.iter().map(StyledSegment::decoded).collect()
+    }
+
+    /// Returns the escaped string, which is the input of `decode_styled` as long as no segment
+    /// was modified.
+    pub fn encode(&self) -> String {
+        self.segments.iter().map(StyledSegment::encoded).collect()
+    }
+}
+
+/// Decodes a string, remembering how every part of it was written.
+///
+/// Only the escape sequences are decoded; the processing `decode_with` applies to the decoded
+/// string as a whole, such as normalization or the byte order mark policy, is not.
+///
+/// # Parameters
+///
+/// * `input`: The escaped input.
+/// * `options`: The escape sequences to accept.
+///
+/// # Returns
+///
+/// A `StyledString` containing the segments of the input, or the first error decoding fails
+/// with, like `decode_with`.
+pub fn decode_styled(input: &str, options: &DecodeOptions) -> Result<StyledString, DecodeError> {
+    let mut segments = Vec::new();
+    let mut tokens = Tokens::with_options(input, options);
+    loop {
+        let start = tokens.offset();
+        let Some(token) = tokens.next() else {
+            break;
+        };
+        let source = &input[start..tokens.offset()];
+        let segment = match token? {
+            Token::Literal(text) if text == source => match segments.last_mut() {
+                Some(StyledSegment::Literal(run)) => {
+                    run.push_str(text);
+                    continue;
+                }
+                _ => StyledSegment::Literal(text.to_string()),
+            },
+            Token::Literal(text) => StyledSegment::Substituted {
+                source: source.to_string(),
+                decoded: text.to_string(),
+            },
+            Token::Escape(escape) => StyledSegment::Escape {
+                source: source.to_string(),
+                kind: escape.kind,
+                decoded: escape.value,
+            },
+        };
+        segments.push(segment);
+    }
+    Ok(StyledString { segments })
+}
@@ -0,0 +1,2752 @@
+// Generated by scripts/generate_tables.py from the Unicode Character Database 14.0.0. Do not edit.
+
+const TRANSLITERATION: &[(u32, &str)] = &[
+    (0x00A0, " "),
+    (0x00A1, "!"),
+    (0x00A2, "c"),
+    (0x00A3, "GBP"),
+    (0x00A5, "JPY"),
+    (0x00A9, "(C)"),
+    (0x00AA, "a"),
+    (0x00AB, "<<"),
+    (0x00AE, "(R)"),
+    (0x00B1, "+/-"),
+    (0x00B2, "2"),
+    (0x00B3, "3"),
+    (0x00B7, "."),
+    (0x00B9, "1"),
+    (0x00BA, "o"),
+    (0x00BB, ">>"),
+    (0x00BF, "?"),
+    (0x00C0, "A"),
+    (0x00C1, "A"),
+    (0x00C2, "A"),
+    (0x00C3, "A"),
+    (0x00C4, "A"),
+    (0x00C5, "A"),
+    (0x00C6, "AE"),
+    (0x00C7, "C"),
+    (0x00C8, "E"),
+    (0x00C9, "E"),
+    (0x00CA, "E"),
+    (0x00CB, "E"),
+    (0x00CC, "I"),
+    (0x00CD, "I"),
+    (0x00CE, "I"),
+    (0x00CF, "I"),
+    (0x00D0, "D"),
+    (0x00D1, "N"),
+    (0x00D2, "O"),
+    (0x00D3, "O"),
+    (0x00D4, "O"),
+    (0x00D5, "O"),
+    (0x00D6, "O"),
+    (0x00D7, "x"),
+    (0x00D8, "O"),
+    (0x00D9, "U"),
+    (0x00DA, "U"),
+    (0x00DB, "U"),
+    (0x00DC, "U"),
+    (0x00DD, "Y"),
+    (0x00DE, "Th"),
+    (0x00DF, "ss"),
+    (0x00E0, "a"),
+    (0x00E1, "a"),
+    (0x00E2, "a"),
+    (0x00E3, "a"),
+    (0x00E4, "a"),
+    (0x00E5, "a"),
+    (0x00E6, "ae"),
+    (0x00E7, "c"),
+    (0x00E8, "e"),
+    (0x00E9, "e"),
+    (0x00EA, "e"),
+    (0x00EB, "e"),
+    (0x00EC, "i"),
+    (0x00ED, "i"),
+    (0x00EE, "i"),
+    (0x00EF, "i"),
+    (0x00F0, "d"),
+    (0x00F1, "n"),
+    (0x00F2, "o"),
+    (0x00F3, "o"),
+    (0x00F4, "o"),
+    (0x00F5, "o"),
+    (0x00F6, "o"),
+    (0x00F7, "/"),
+    (0x00F8, "o"),
+    (0x00F9, "u"),
+    (0x00FA, "u"),
+    (0x00FB, "u"),
+    (0x00FC, "u"),
+    (0x00FD, "y"),
+    (0x00FE, "th"),
+    (0x00FF, "y"),
+    (0x0100, "A"),
+    (0x0101, "a"),
+    (0x0102, "A"),
+    (0x0103, "a"),
+    (0x0104, "A"),
+    (0x0105, "a"),
+    (0x0106, "C"),
+    (0x0107, "c"),
+    (0x0108, "C"),
+    (0x0109, "c"),
+    (0x010A, "C"),
+    (0x010B, "c"),
+    (0x010C, "C"),
+    (0x010D, "c"),
+    (0x010E, "D"),
+    (0x010F, "d"),
+    (0x0110, "D"),
+    (0x0111, "d"),
+    (0x0112, "E"),
+    (0x0113, "e"),
+    (0x0114, "E"),
+    (0x0115, "e"),
+    (0x0116, "E"),
+    (0x0117, "e"),
+    (0x0118, "E"),
+    (0x0119, "e"),
+    (0x011A, "E"),
+    (0x011B, "e"),
+    (0x011C, "G"),
+    (0x011D, "g"),
+    (0x011E, "G"),
+    (0x011F, "g"),
+    (0x0120, "G"),
+    (0x0121, "g"),
+    (0x0122, "G"),
+    (0x0123, "g"),
+    (0x0124, "H"),
+    (0x0125, "h"),
+    (0x0126, "H"),
+    (0x0127, "h"),
+    (0x0128, "I"),
+    (0x0129, "i"),
+    (0x012A, "I"),
+    (0x012B, "i"),
+    (0x012C, "I"),
+    (0x012D, "i"),
+    (0x012E, "I"),
+    (0x012F, "i"),
+    (0x0130, "I"),
+    (0x0131, "i"),
+    (0x0132, "IJ"),
+    (0x0133, "ij"),
+    (0x0134, "J"),
+    (0x0135, "j"),
+    (0x0136, "K"),
+    (0x0137, "k"),
+    (0x0139, "L"),
+    (0x013A, "l"),
+    (0x013B, "L"),
+    (0x013C, "l"),
+    (0x013D, "L"),
+    (0x013E, "l"),
+    (0x013F, "L"),
+    (0x0140, "l"),
+    (0x0141, "L"),
+    (0x0142, "l"),
+    (0x0143, "N"),
+    (0x0144, "n"),
+    (0x0145, "N"),
+    (0x0146, "n"),
+    (0x0147, "N"),
+    (0x0148, "n"),
+    (0x014C, "O"),
+    (0x014D, "o"),
+    (0x014E, "O"),
+    (0x014F, "o"),
+    (0x0150, "O"),
+    (0x0151, "o"),
+    (0x0152, "OE"),
+    (0x0153, "oe"),
+    (0x0154, "R"),
+    (0x0155, "r"),
+    (0x0156, "R"),
+    (0x0157, "r"),
+    (0x0158, "R"),
+    (0x0159, "r"),
+    (0x015A, "S"),
+    (0x015B, "s"),
+    (0x015C, "S"),
+    (0x015D, "s"),
+    (0x015E, "S"),
+    (0x015F, "s"),
+    (0x0160, "S"),
+    (0x0161, "s"),
+    (0x0162, "T"),
+    (0x0163, "t"),
+    (0x0164, "T"),
+    (0x0165, "t"),
+    (0x0166, "T"),
+    (0x0167, "t"),
+    (0x0168, "U"),
+    (0x0169, "u"),
+    (0x016A, "U"),
+    (0x016B, "u"),
+    (0x016C, "U"),
+    (0x016D, "u"),
+    (0x016E, "U"),
+    (0x016F, "u"),
+    (0x0170, "U"),
+    (0x0171, "u"),
+    (0x0172, "U"),
+    (0x0173, "u"),
+    (0x0174, "W"),
+    (0x0175, "w"),
+    (0x0176, "Y"),
+    (0x0177, "y"),
+    (0x0178, "Y"),
+    (0x0179, "Z"),
+    (0x017A, "z"),
+    (0x017B, "Z"),
+    (0x017C, "z"),
+    (0x017D, "Z"),
+    (0x017E, "z"),
+    (0x017F, "s"),
+    (0x0180, "b"),
+    (0x0181, "B"),
+    (0x0182, "B"),
+    (0x0183, "b"),
+    (0x0186, "O"),
+    (0x0187, "C"),
+    (0x0188, "c"),
+    (0x018A, "D"),
+    (0x018B, "D"),
+    (0x018C, "d"),
+    (0x018E, "E"),
+    (0x0190, "E"),
+    (0x0191, "F"),
+    (0x0192, "f"),
+    (0x0193, "G"),
+    (0x0195, "hv"),
+    (0x0197, "I"),
+    (0x0198, "K"),
+    (0x0199, "k"),
+    (0x019A, "l"),
+    (0x019C, "M"),
+    (0x019D, "N"),
+    (0x019E, "n"),
+    (0x019F, "O"),
+    (0x01A0, "O"),
+    (0x01A1, "o"),
+    (0x01A2, "OI"),
+    (0x01A3, "oi"),
+    (0x01A4, "P"),
+    (0x01A5, "p"),
+    (0x01AB, "t"),
+    (0x01AC, "T"),
+    (0x01AD, "t"),
+    (0x01AE, "T"),
+    (0x01AF, "U"),
+    (0x01B0, "u"),
+    (0x01B2, "V"),
+    (0x01B3, "Y"),
+    (0x01B4, "y"),
+    (0x01B5, "Z"),
+    (0x01B6, "z"),
+    (0x01C4, "DZ"),
+    (0x01C5, "Dz"),
+    (0x01C6, "dz"),
+    (0x01C7, "LJ"),
+    (0x01C8, "Lj"),
+    (0x01C9, "lj"),
+    (0x01CA, "NJ"),
+    (0x01CB, "Nj"),
+    (0x01CC, "nj"),
+    (0x01CD, "A"),
+    (0x01CE, "a"),
+    (0x01CF, "I"),
+    (0x01D0, "i"),
+    (0x01D1, "O"),
+    (0x01D2, "o"),
+    (0x01D3, "U"),
+    (0x01D4, "u"),
+    (0x01D5, "U"),
+    (0x01D6, "u"),
+    (0x01D7, "U"),
+    (0x01D8, "u"),
+    (0x01D9, "U"),
+    (0x01DA, "u"),
+    (0x01DB, "U"),
+    (0x01DC, "u"),
+    (0x01DD, "e"),
+    (0x01DE, "A"),
+    (0x01DF, "a"),
+    (0x01E0, "A"),
+    (0x01E1, "a"),
+    (0x01E2, "AE"),
+    (0x01E3, "ae"),
+    (0x01E4, "G"),
+    (0x01E5, "g"),
+    (0x01E6, "G"),
+    (0x01E7, "g"),
+    (0x01E8, "K"),
+    (0x01E9, "k"),
+    (0x01EA, "O"),
+    (0x01EB, "o"),
+    (0x01EC, "O"),
+    (0x01ED, "o"),
+    (0x01F0, "j"),
+    (0x01F1, "DZ"),
+    (0x01F2, "Dz"),
+    (0x01F3, "dz"),
+    (0x01F4, "G"),
+    (0x01F5, "g"),
+    (0x01F8, "N"),
+    (0x01F9, "n"),
+    (0x01FA, "A"),
+    (0x01FB, "a"),
+    (0x01FC, "AE"),
+    (0x01FD, "ae"),
+    (0x01FE, "O"),
+    (0x01FF, "o"),
+    (0x0200, "A"),
+    (0x0201, "a"),
+    (0x0202, "A"),
+    (0x0203, "a"),
+    (0x0204, "E"),
+    (0x0205, "e"),
+    (0x0206, "E"),
+    (0x0207, "e"),
+    (0x0208, "I"),
+    (0x0209, "i"),
+    (0x020A, "I"),
+    (0x020B, "i"),
+    (0x020C, "O"),
+    (0x020D, "o"),
+    (0x020E, "O"),
+    (0x020F, "o"),
+    (0x0210, "R"),
+    (0x0211, "r"),
+    (0x0212, "R"),
+    (0x0213, "r"),
+    (0x0214, "U"),
+    (0x0215, "u"),
+    (0x0216, "U"),
+    (0x0217, "u"),
+    (0x0218, "S"),
+    (0x0219, "s"),
+    (0x021A, "T"),
+    (0x021B, "t"),
+    (0x021E, "H"),
+    (0x021F, "h"),
+    (0x0220, "N"),
+    (0x0221, "d"),
+    (0x0222, "OU"),
+    (0x0223, "ou"),
+    (0x0224, "Z"),
+    (0x0225, "z"),
+    (0x0226, "A"),
+    (0x0227, "a"),
+    (0x0228, "E"),
+    (0x0229, "e"),
+    (0x022A, "O"),
+    (0x022B, "o"),
+    (0x022C, "O"),
+    (0x022D, "o"),
+    (0x022E, "O"),
+    (0x022F, "o"),
+    (0x0230, "O"),
+    (0x0231, "o"),
+    (0x0232, "Y"),
+    (0x0233, "y"),
+    (0x0234, "l"),
+    (0x0235, "n"),
+    (0x0236, "t"),
+    (0x0237, "j"),
+    (0x023A, "A"),
+    (0x023B, "C"),
+    (0x023C, "c"),
+    (0x023D, "L"),
+    (0x023E, "T"),
+    (0x023F, "s"),
+    (0x0240, "z"),
+    (0x0243, "B"),
+    (0x0245, "V"),
+    (0x0246, "E"),
+    (0x0247, "e"),
+    (0x0248, "J"),
+    (0x0249, "j"),
+    (0x024B, "q"),
+    (0x024C, "R"),
+    (0x024D, "r"),
+    (0x024E, "Y"),
+    (0x024F, "y"),
+    (0x0250, "a"),
+    (0x0253, "b"),
+    (0x0254, "o"),
+    (0x0255, "c"),
+    (0x0256, "d"),
+    (0x0257, "d"),
+    (0x0258, "e"),
+    (0x025B, "e"),
+    (0x025C, "e"),
+    (0x025D, "e"),
+    (0x025F, "j"),
+    (0x0260, "g"),
+    (0x0261, "g"),
+    (0x0265, "h"),
+    (0x0266, "h"),
+    (0x0268, "i"),
+    (0x026B, "l"),
+    (0x026C, "l"),
+    (0x026D, "l"),
+    (0x026F, "m"),
+    (0x0270, "m"),
+    (0x0271, "m"),
+    (0x0272, "n"),
+    (0x0273, "n"),
+    (0x0279, "r"),
+    (0x027A, "r"),
+    (0x027B, "r"),
+    (0x027C, "r"),
+    (0x027D, "r"),
+    (0x027E, "r"),
+    (0x027F, "r"),
+    (0x0282, "s"),
+    (0x0284, "j"),
+    (0x0287, "t"),
+    (0x0288, "t"),
+    (0x028B, "v"),
+    (0x028C, "v"),
+    (0x028D, "w"),
+    (0x028E, "y"),
+    (0x0290, "z"),
+    (0x0291, "z"),
+    (0x029D, "j"),
+    (0x029E, "k"),
+    (0x02A0, "q"),
+    (0x02AE, "h"),
+    (0x02AF, "h"),
+    (0x02B0, "h"),
+    (0x02B2, "j"),
+    (0x02B3, "r"),
+    (0x02B7, "w"),
+    (0x02B8, "y"),
+    (0x02E1, "l"),
+    (0x02E2, "s"),
+    (0x02E3, "x"),
+    (0x037E, ";"),
+    (0x0386, "A"),
+    (0x0388, "E"),
+    (0x0389, "E"),
+    (0x038A, "I"),
+    (0x038C, "O"),
+    (0x038E, "Y"),
+    (0x038F, "O"),
+    (0x0390, "i"),
+    (0x0391, "A"),
+    (0x0392, "B"),
+    (0x0393, "G"),
+    (0x0394, "D"),
+    (0x0395, "E"),
+    (0x0396, "Z"),
+    (0x0397, "E"),
+    (0x0398, "Th"),
+    (0x0399, "I"),
+    (0x039A, "K"),
+    (0x039B, "L"),
+    (0x039C, "M"),
+    (0x039D, "N"),
+    (0x039E, "X"),
+    (0x039F, "O"),
+    (0x03A0, "P"),
+    (0x03A1, "R"),
+    (0x03A3, "S"),
+    (0x03A4, "T"),
+    (0x03A5, "Y"),
+    (0x03A6, "Ph"),
+    (0x03A7, "Kh"),
+    (0x03A8, "Ps"),
+    (0x03A9, "O"),
+    (0x03AA, "I"),
+    (0x03AB, "Y"),
+    (0x03AC, "a"),
+    (0x03AD, "e"),
+    (0x03AE, "e"),
+    (0x03AF, "i"),
+    (0x03B0, "y"),
+    (0x03B1, "a"),
+    (0x03B2, "b"),
+    (0x03B3, "g"),
+    (0x03B4, "d"),
+    (0x03B5, "e"),
+    (0x03B6, "z"),
+    (0x03B7, "e"),
+    (0x03B8, "th"),
+    (0x03B9, "i"),
+    (0x03BA, "k"),
+    (0x03BB, "l"),
+    (0x03BC, "m"),
+    (0x03BD, "n"),
+    (0x03BE, "x"),
+    (0x03BF, "o"),
+    (0x03C0, "p"),
+    (0x03C1, "r"),
+    (0x03C2, "s"),
+    (0x03C3, "s"),
+    (0x03C4, "t"),
+    (0x03C5, "y"),
+    (0x03C6, "ph"),
+    (0x03C7, "kh"),
+    (0x03C8, "ps"),
+    (0x03C9, "o"),
+    (0x03CA, "i"),
+    (0x03CB, "y"),
+    (0x03CC, "o"),
+    (0x03CD, "y"),
+    (0x03CE, "o"),
+    (0x0400, "E"),
+    (0x0401, "Yo"),
+    (0x0402, "D"),
+    (0x0404, "Ye"),
+    (0x0405, "Dz"),
+    (0x0406, "I"),
+    (0x0407, "Yi"),
+    (0x0408, "J"),
+    (0x0409, "Lj"),
+    (0x040A, "Nj"),
+    (0x040B, "C"),
+    (0x040D, "I"),
+    (0x040E, "U"),
+    (0x040F, "Dzh"),
+    (0x0410, "A"),
+    (0x0411, "B"),
+    (0x0412, "V"),
+    (0x0413, "G"),
+    (0x0414, "D"),
+    (0x0415, "E"),
+    (0x0416, "Zh"),
+    (0x0417, "Z"),
+    (0x0418, "I"),
+    (0x0419, "Y"),
+    (0x041A, "K"),
+    (0x041B, "L"),
+    (0x041C, "M"),
+    (0x041D, "N"),
+    (0x041E, "O"),
+    (0x041F, "P"),
+    (0x0420, "R"),
+    (0x0421, "S"),
+    (0x0422, "T"),
+    (0x0423, "U"),
+    (0x0424, "F"),
+    (0x0425, "Kh"),
+    (0x0426, "Ts"),
+    (0x0427, "Ch"),
+    (0x0428, "Sh"),
+    (0x0429, "Shch"),
+    (0x042A, ""),
+    (0x042B, "Y"),
+    (0x042C, ""),
+    (0x042D, "E"),
+    (0x042E, "Yu"),
+    (0x042F, "Ya"),
+    (0x0430, "a"),
+    (0x0431, "b"),
+    (0x0432, "v"),
+    (0x0433, "g"),
+    (0x0434, "d"),
+    (0x0435, "e"),
+    (0x0436, "zh"),
+    (0x0437, "z"),
+    (0x0438, "i"),
+    (0x0439, "y"),
+    (0x043A, "k"),
+    (0x043B, "l"),
+    (0x043C, "m"),
+    (0x043D, "n"),
+    (0x043E, "o"),
+    (0x043F, "p"),
+    (0x0440, "r"),
+    (0x0441, "s"),
+    (0x0442, "t"),
+    (0x0443, "u"),
+    (0x0444, "f"),
+    (0x0445, "kh"),
+    (0x0446, "ts"),
+    (0x0447, "ch"),
+    (0x0448, "sh"),
+    (0x0449, "shch"),
+    (0x044A, ""),
+    (0x044B, "y"),
+    (0x044C, ""),
+    (0x044D, "e"),
+    (0x044E, "yu"),
+    (0x044F, "ya"),
+    (0x0450, "e"),
+    (0x0451, "yo"),
+    (0x0452, "d"),
+    (0x0454, "ye"),
+    (0x0455, "dz"),
+    (0x0456, "i"),
+    (0x0457, "yi"),
+    (0x0458, "j"),
+    (0x0459, "lj"),
+    (0x045A, "nj"),
+    (0x045B, "c"),
+    (0x045D, "i"),
+    (0x045E, "u"),
+    (0x045F, "dzh"),
+    (0x048A, "Y"),
+    (0x048B, "y"),
+    (0x048E, "R"),
+    (0x048F, "r"),
+    (0x0490, "G"),
+    (0x0491, "g"),
+    (0x0492, "G"),
+    (0x0493, "g"),
+    (0x0494, "G"),
+    (0x0495, "g"),
+    (0x0496, "Zh"),
+    (0x0497, "zh"),
+    (0x0498, "Z"),
+    (0x0499, "z"),
+    (0x049A, "K"),
+    (0x049B, "k"),
+    (0x049C, "K"),
+    (0x049D, "k"),
+    (0x049E, "K"),
+    (0x049F, "k"),
+    (0x04A2, "N"),
+    (0x04A3, "n"),
+    (0x04A6, "P"),
+    (0x04A7, "p"),
+    (0x04AA, "S"),
+    (0x04AB, "s"),
+    (0x04AC, "T"),
+    (0x04AD, "t"),
+    (0x04B2, "Kh"),
+    (0x04B3, "kh"),
+    (0x04B6, "Ch"),
+    (0x04B7, "ch"),
+    (0x04B8, "Ch"),
+    (0x04B9, "ch"),
+    (0x04C1, "Zh"),
+    (0x04C2, "zh"),
+    (0x04C3, "K"),
+    (0x04C4, "k"),
+    (0x04C5, "L"),
+    (0x04C6, "l"),
+    (0x04C7, "N"),
+    (0x04C8, "n"),
+    (0x04C9, "N"),
+    (0x04CA, "n"),
+    (0x04CD, "M"),
+    (0x04CE, "m"),
+    (0x04D0, "A"),
+    (0x04D1, "a"),
+    (0x04D2, "A"),
+    (0x04D3, "a"),
+    (0x04D6, "E"),
+    (0x04D7, "e"),
+    (0x04DC, "Zh"),
+    (0x04DD, "zh"),
+    (0x04DE, "Z"),
+    (0x04DF, "z"),
+    (0x04E2, "I"),
+    (0x04E3, "i"),
+    (0x04E4, "I"),
+    (0x04E5, "i"),
+    (0x04E6, "O"),
+    (0x04E7, "o"),
+    (0x04EC, "E"),
+    (0x04ED, "e"),
+    (0x04EE, "U"),
+    (0x04EF, "u"),
+    (0x04F0, "U"),
+    (0x04F1, "u"),
+    (0x04F2, "U"),
+    (0x04F3, "u"),
+    (0x04F4, "Ch"),
+    (0x04F5, "ch"),
+    (0x04F6, "G"),
+    (0x04F7, "g"),
+    (0x04F8, "Y"),
+    (0x04F9, "y"),
+    (0x04FA, "G"),
+    (0x04FB, "g"),
+    (0x04FC, "Kh"),
+    (0x04FD, "kh"),
+    (0x04FE, "Kh"),
+    (0x04FF, "kh"),
+    (0x0512, "L"),
+    (0x0513, "l"),
+    (0x0520, "L"),
+    (0x0521, "l"),
+    (0x0522, "N"),
+    (0x0523, "n"),
+    (0x0524, "P"),
+    (0x0525, "p"),
+    (0x0528, "N"),
+    (0x0529, "n"),
+    (0x052E, "L"),
+    (0x052F, "l"),
+    (0x1D02, "ae"),
+    (0x1D08, "e"),
+    (0x1D09, "i"),
+    (0x1D14, "oe"),
+    (0x1D2C, "A"),
+    (0x1D2E, "B"),
+    (0x1D30, "D"),
+    (0x1D31, "E"),
+    (0x1D33, "G"),
+    (0x1D34, "H"),
+    (0x1D35, "I"),
+    (0x1D36, "J"),
+    (0x1D37, "K"),
+    (0x1D38, "L"),
+    (0x1D39, "M"),
+    (0x1D3A, "N"),
+    (0x1D3C, "O"),
+    (0x1D3E, "P"),
+    (0x1D3F, "R"),
+    (0x1D40, "T"),
+    (0x1D41, "U"),
+    (0x1D42, "W"),
+    (0x1D43, "a"),
+    (0x1D47, "b"),
+    (0x1D48, "d"),
+    (0x1D49, "e"),
+    (0x1D4D, "g"),
+    (0x1D4F, "k"),
+    (0x1D50, "m"),
+    (0x1D52, "o"),
+    (0x1D56, "p"),
+    (0x1D57, "t"),
+    (0x1D58, "u"),
+    (0x1D5B, "v"),
+    (0x1D62, "i"),
+    (0x1D63, "r"),
+    (0x1D64, "u"),
+    (0x1D65, "v"),
+    (0x1D6B, "ue"),
+    (0x1D6C, "b"),
+    (0x1D6D, "d"),
+    (0x1D6E, "f"),
+    (0x1D6F, "m"),
+    (0x1D70, "n"),
+    (0x1D71, "p"),
+    (0x1D72, "r"),
+    (0x1D73, "r"),
+    (0x1D74, "s"),
+    (0x1D75, "t"),
+    (0x1D76, "z"),
+    (0x1D77, "g"),
+    (0x1D7A, "th"),
+    (0x1D7D, "p"),
+    (0x1D80, "b"),
+    (0x1D81, "d"),
+    (0x1D82, "f"),
+    (0x1D83, "g"),
+    (0x1D84, "k"),
+    (0x1D85, "l"),
+    (0x1D86, "m"),
+    (0x1D87, "n"),
+    (0x1D88, "p"),
+    (0x1D89, "r"),
+    (0x1D8A, "s"),
+    (0x1D8C, "v"),
+    (0x1D8D, "x"),
+    (0x1D8E, "z"),
+    (0x1D8F, "a"),
+    (0x1D91, "d"),
+    (0x1D92, "e"),
+    (0x1D93, "e"),
+    (0x1D94, "e"),
+    (0x1D96, "i"),
+    (0x1D97, "o"),
+    (0x1D99, "u"),
+    (0x1D9C, "c"),
+    (0x1DA0, "f"),
+    (0x1DBB, "z"),
+    (0x1E00, "A"),
+    (0x1E01, "a"),
+    (0x1E02, "B"),
+    (0x1E03, "b"),
+    (0x1E04, "B"),
+    (0x1E05, "b"),
+    (0x1E06, "B"),
+    (0x1E07, "b"),
+    (0x1E08, "C"),
+    (0x1E09, "c"),
+    (0x1E0A, "D"),
+    (0x1E0B, "d"),
+    (0x1E0C, "D"),
+    (0x1E0D, "d"),
+    (0x1E0E, "D"),
+    (0x1E0F, "d"),
+    (0x1E10, "D"),
+    (0x1E11, "d"),
+    (0x1E12, "D"),
+    (0x1E13, "d"),
+    (0x1E14, "E"),
+    (0x1E15, "e"),
+    (0x1E16, "E"),
+    (0x1E17, "e"),
+    (0x1E18, "E"),
+    (0x1E19, "e"),
+    (0x1E1A, "E"),
+    (0x1E1B, "e"),
+    (0x1E1C, "E"),
+    (0x1E1D, "e"),
+    (0x1E1E, "F"),
+    (0x1E1F, "f"),
+    (0x1E20, "G"),
+    (0x1E21, "g"),
+    (0x1E22, "H"),
+    (0x1E23, "h"),
+    (0x1E24, "H"),
+    (0x1E25, "h"),
+    (0x1E26, "H"),
+    (0x1E27, "h"),
+    (0x1E28, "H"),
+    (0x1E29, "h"),
+    (0x1E2A, "H"),
+    (0x1E2B, "h"),
+    (0x1E2C, "I"),
+    (0x1E2D, "i"),
+    (0x1E2E, "I"),
+    (0x1E2F, "i"),
+    (0x1E30, "K"),
+    (0x1E31, "k"),
+    (0x1E32, "K"),
+    (0x1E33, "k"),
+    (0x1E34, "K"),
+    (0x1E35, "k"),
+    (0x1E36, "L"),
+    (0x1E37, "l"),
+    (0x1E38, "L"),
+    (0x1E39, "l"),
+    (0x1E3A, "L"),
+    (0x1E3B, "l"),
+    (0x1E3C, "L"),
+    (0x1E3D, "l"),
+    (0x1E3E, "M"),
+    (0x1E3F, "m"),
+    (0x1E40, "M"),
+    (0x1E41, "m"),
+    (0x1E42, "M"),
+    (0x1E43, "m"),
+    (0x1E44, "N"),
+    (0x1E45, "n"),
+    (0x1E46, "N"),
+    (0x1E47, "n"),
+    (0x1E48, "N"),
+    (0x1E49, "n"),
+    (0x1E4A, "N"),
+    (0x1E4B, "n"),
+    (0x1E4C, "O"),
+    (0x1E4D, "o"),
+    (0x1E4E, "O"),
+    (0x1E4F, "o"),
+    (0x1E50, "O"),
+    (0x1E51, "o"),
+    (0x1E52, "O"),
+    (0x1E53, "o"),
+    (0x1E54, "P"),
+    (0x1E55, "p"),
+    (0x1E56, "P"),
+    (0x1E57, "p"),
+    (0x1E58, "R"),
+    (0x1E59, "r"),
+    (0x1E5A, "R"),
+    (0x1E5B, "r"),
+    (0x1E5C, "R"),
+    (0x1E5D, "r"),
+    (0x1E5E, "R"),
+    (0x1E5F, "r"),
+    (0x1E60, "S"),
+    (0x1E61, "s"),
+    (0x1E62, "S"),
+    (0x1E63, "s"),
+    (0x1E64, "S"),
+    (0x1E65, "s"),
+    (0x1E66, "S"),
+    (0x1E67, "s"),
+    (0x1E68, "S"),
+    (0x1E69, "s"),
+    (0x1E6A, "T"),
+    (0x1E6B, "t"),
+    (0x1E6C, "T"),
+    (0x1E6D, "t"),
+    (0x1E6E, "T"),
+    (0x1E6F, "t"),
+    (0x1E70, "T"),
+    (0x1E71, "t"),
+    (0x1E72, "U"),
+    (0x1E73, "u"),
+    (0x1E74, "U"),
+    (0x1E75, "u"),
+    (0x1E76, "U"),
+    (0x1E77, "u"),
+    (0x1E78, "U"),
+    (0x1E79, "u"),
+    (0x1E7A, "U"),
+    (0x1E7B, "u"),
+    (0x1E7C, "V"),
+    (0x1E7D, "v"),
+    (0x1E7E, "V"),
+    (0x1E7F, "v"),
+    (0x1E80, "W"),
+    (0x1E81, "w"),
+    (0x1E82, "W"),
+    (0x1E83, "w"),
+    (0x1E84, "W"),
+    (0x1E85, "w"),
+    (0x1E86, "W"),
+    (0x1E87, "w"),
+    (0x1E88, "W"),
+    (0x1E89, "w"),
+    (0x1E8A, "X"),
+    (0x1E8B, "x"),
+    (0x1E8C, "X"),
+    (0x1E8D, "x"),
+    (0x1E8E, "Y"),
+    (0x1E8F, "y"),
+    (0x1E90, "Z"),
+    (0x1E91, "z"),
+    (0x1E92, "Z"),
+    (0x1E93, "z"),
+    (0x1E94, "Z"),
+    (0x1E95, "z"),
+    (0x1E96, "h"),
+    (0x1E97, "t"),
+    (0x1E98, "w"),
+    (0x1E99, "y"),
+    (0x1E9A, "a"),
+    (0x1E9B, "s"),
+    (0x1E9E, "SS"),
+    (0x1EA0, "A"),
+    (0x1EA1, "a"),
+    (0x1EA2, "A"),
+    (0x1EA3, "a"),
+    (0x1EA4, "A"),
+    (0x1EA5, "a"),
+    (0x1EA6, "A"),
+    (0x1EA7, "a"),
+    (0x1EA8, "A"),
+    (0x1EA9, "a"),
+    (0x1EAA, "A"),
+    (0x1EAB, "a"),
+    (0x1EAC, "A"),
+    (0x1EAD, "a"),
+    (0x1EAE, "A"),
+    (0x1EAF, "a"),
+    (0x1EB0, "A"),
+    (0x1EB1, "a"),
+    (0x1EB2, "A"),
+    (0x1EB3, "a"),
+    (0x1EB4, "A"),
+    (0x1EB5, "a"),
+    (0x1EB6, "A"),
+    (0x1EB7, "a"),
+    (0x1EB8, "E"),
+    (0x1EB9, "e"),
+    (0x1EBA, "E"),
+    (0x1EBB, "e"),
+    (0x1EBC, "E"),
+    (0x1EBD, "e"),
+    (0x1EBE, "E"),
+    (0x1EBF, "e"),
+    (0x1EC0, "E"),
+    (0x1EC1, "e"),
+    (0x1EC2, "E"),
+    (0x1EC3, "e"),
+    (0x1EC4, "E"),
+    (0x1EC5, "e"),
+    (0x1EC6, "E"),
+    (0x1EC7, "e"),
+    (0x1EC8, "I"),
+    (0x1EC9, "i"),
+    (0x1ECA, "I"),
+    (0x1ECB, "i"),
+    (0x1ECC, "O"),
+    (0x1ECD, "o"),
+    (0x1ECE, "O"),
+    (0x1ECF, "o"),
+    (0x1ED0, "O"),
+    (0x1ED1, "o"),
+    (0x1ED2, "O"),
+    (0x1ED3, "o"),
+    (0x1ED4, "O"),
+    (0x1ED5, "o"),
+    (0x1ED6, "O"),
+    (0x1ED7, "o"),
+    (0x1ED8, "O"),
+    (0x1ED9, "o"),
+    (0x1EDA, "O"),
+    (0x1EDB, "o"),
+    (0x1EDC, "O"),
+    (0x1EDD, "o"),
+    (0x1EDE, "O"),
+    (0x1EDF, "o"),
+    (0x1EE0, "O"),
+    (0x1EE1, "o"),
+    (0x1EE2, "O"),
+    (0x1EE3, "o"),
+    (0x1EE4, "U"),
+    (0x1EE5, "u"),
+    (0x1EE6, "U"),
+    (0x1EE7, "u"),
+    (0x1EE8, "U"),
+    (0x1EE9, "u"),
+    (0x1EEA, "U"),
+    (0x1EEB, "u"),
+    (0x1EEC, "U"),
+    (0x1EED, "u"),
+    (0x1EEE, "U"),
+    (0x1EEF, "u"),
+    (0x1EF0, "U"),
+    (0x1EF1, "u"),
+    (0x1EF2, "Y"),
+    (0x1EF3, "y"),
+    (0x1EF4, "Y"),
+    (0x1EF5, "y"),
+    (0x1EF6, "Y"),
+    (0x1EF7, "y"),
+    (0x1EF8, "Y"),
+    (0x1EF9, "y"),
+    (0x1EFE, "Y"),
+    (0x1EFF, "y"),
+    (0x1F00, "a"),
+    (0x1F01, "a"),
+    (0x1F02, "a"),
+    (0x1F03, "a"),
+    (0x1F04, "a"),
+    (0x1F05, "a"),
+    (0x1F06, "a"),
+    (0x1F07, "a"),
+    (0x1F08, "A"),
+    (0x1F09, "A"),
+    (0x1F0A, "A"),
+    (0x1F0B, "A"),
+    (0x1F0C, "A"),
+    (0x1F0D, "A"),
+    (0x1F0E, "A"),
+    (0x1F0F, "A"),
+    (0x1F10, "e"),
+    (0x1F11, "e"),
+    (0x1F12, "e"),
+    (0x1F13, "e"),
+    (0x1F14, "e"),
+    (0x1F15, "e"),
+    (0x1F18, "E"),
+    (0x1F19, "E"),
+    (0x1F1A, "E"),
+    (0x1F1B, "E"),
+    (0x1F1C, "E"),
+    (0x1F1D, "E"),
+    (0x1F20, "e"),
+    (0x1F21, "e"),
+    (0x1F22, "e"),
+    (0x1F23, "e"),
+    (0x1F24, "e"),
+    (0x1F25, "e"),
+    (0x1F26, "e"),
+    (0x1F27, "e"),
+    (0x1F28, "E"),
+    (0x1F29, "E"),
+    (0x1F2A, "E"),
+    (0x1F2B, "E"),
+    (0x1F2C, "E"),
+    (0x1F2D, "E"),
+    (0x1F2E, "E"),
+    (0x1F2F, "E"),
+    (0x1F30, "i"),
+    (0x1F31, "i"),
+    (0x1F32, "i"),
+    (0x1F33, "i"),
+    (0x1F34, "i"),
+    (0x1F35, "i"),
+    (0x1F36, "i"),
+    (0x1F37, "i"),
+    (0x1F38, "I"),
+    (0x1F39, "I"),
+    (0x1F3A, "I"),
+    (0x1F3B, "I"),
+    (0x1F3C, "I"),
+    (0x1F3D, "I"),
+    (0x1F3E, "I"),
+    (0x1F3F, "I"),
+    (0x1F40, "o"),
+    (0x1F41, "o"),
+    (0x1F42, "o"),
+    (0x1F43, "o"),
+    (0x1F44, "o"),
+    (0x1F45, "o"),
+    (0x1F48, "O"),
+    (0x1F49, "O"),
+    (0x1F4A, "O"),
+    (0x1F4B, "O"),
+    (0x1F4C, "O"),
+    (0x1F4D, "O"),
+    (0x1F50, "y"),
+    (0x1F51, "y"),
+    (0x1F52, "y"),
+    (0x1F53, "y"),
+    (0x1F54, "y"),
+    (0x1F55, "y"),
+    (0x1F56, "y"),
+    (0x1F57, "y"),
+    (0x1F59, "Y"),
+    (0x1F5B, "Y"),
+    (0x1F5D, "Y"),
+    (0x1F5F, "Y"),
+    (0x1F60, "o"),
+    (0x1F61, "o"),
+    (0x1F62, "o"),
+    (0x1F63, "o"),
+    (0x1F64, "o"),
+    (0x1F65, "o"),
+    (0x1F66, "o"),
+    (0x1F67, "o"),
+    (0x1F68, "O"),
+    (0x1F69, "O"),
+    (0x1F6A, "O"),
+    (0x1F6B, "O"),
+    (0x1F6C, "O"),
+    (0x1F6D, "O"),
+    (0x1F6E, "O"),
+    (0x1F6F, "O"),
+    (0x1F70, "a"),
+    (0x1F71, "a"),
+    (0x1F72, "e"),
+    (0x1F73, "e"),
+    (0x1F74, "e"),
+    (0x1F75, "e"),
+    (0x1F76, "i"),
+    (0x1F77, "i"),
+    (0x1F78, "o"),
+    (0x1F79, "o"),
+    (0x1F7A, "y"),
+    (0x1F7B, "y"),
+    (0x1F7C, "o"),
+    (0x1F7D, "o"),
+    (0x1F80, "a"),
+    (0x1F81, "a"),
+    (0x1F82, "a"),
+    (0x1F83, "a"),
+    (0x1F84, "a"),
+    (0x1F85, "a"),
+    (0x1F86, "a"),
+    (0x1F87, "a"),
+    (0x1F88, "A"),
+    (0x1F89, "A"),
+    (0x1F8A, "A"),
+    (0x1F8B, "A"),
+    (0x1F8C, "A"),
+    (0x1F8D, "A"),
+    (0x1F8E, "A"),
+    (0x1F8F, "A"),
+    (0x1F90, "e"),
+    (0x1F91, "e"),
+    (0x1F92, "e"),
+    (0x1F93, "e"),
+    (0x1F94, "e"),
+    (0x1F95, "e"),
+    (0x1F96, "e"),
+    (0x1F97, "e"),
+    (0x1F98, "E"),
+    (0x1F99, "E"),
+    (0x1F9A, "E"),
+    (0x1F9B, "E"),
+    (0x1F9C, "E"),
+    (0x1F9D, "E"),
+    (0x1F9E, "E"),
+    (0x1F9F, "E"),
+    (0x1FA0, "o"),
+    (0x1FA1, "o"),
+    (0x1FA2, "o"),
+    (0x1FA3, "o"),
+    (0x1FA4, "o"),
+    (0x1FA5, "o"),
+    (0x1FA6, "o"),
+    (0x1FA7, "o"),
+    (0x1FA8, "O"),
+    (0x1FA9, "O"),
+    (0x1FAA, "O"),
+    (0x1FAB, "O"),
+    (0x1FAC, "O"),
+    (0x1FAD, "O"),
+    (0x1FAE, "O"),
+    (0x1FAF, "O"),
+    (0x1FB0, "a"),
+    (0x1FB1, "a"),
+    (0x1FB2, "a"),
+    (0x1FB3, "a"),
+    (0x1FB4, "a"),
+    (0x1FB6, "a"),
+    (0x1FB7, "a"),
+    (0x1FB8, "A"),
+    (0x1FB9, "A"),
+    (0x1FBA, "A"),
+    (0x1FBB, "A"),
+    (0x1FBC, "A"),
+    (0x1FC2, "e"),
+    (0x1FC3, "e"),
+    (0x1FC4, "e"),
+    (0x1FC6, "e"),
+    (0x1FC7, "e"),
+    (0x1FC8, "E"),
+    (0x1FC9, "E"),
+    (0x1FCA, "E"),
+    (0x1FCB, "E"),
+    (0x1FCC, "E"),
+    (0x1FD0, "i"),
+    (0x1FD1, "i"),
+    (0x1FD2, "i"),
+    (0x1FD3, "i"),
+    (0x1FD6, "i"),
+    (0x1FD7, "i"),
+    (0x1FD8, "I"),
+    (0x1FD9, "I"),
+    (0x1FDA, "I"),
+    (0x1FDB, "I"),
+    (0x1FE0, "y"),
+    (0x1FE1, "y"),
+    (0x1FE2, "y"),
+    (0x1FE3, "y"),
+    (0x1FE4, "r"),
+    (0x1FE5, "r"),
+    (0x1FE6, "y"),
+    (0x1FE7, "y"),
+    (0x1FE8, "Y"),
+    (0x1FE9, "Y"),
+    (0x1FEA, "Y"),
+    (0x1FEB, "Y"),
+    (0x1FEC, "R"),
+    (0x1FEF, "`"),
+    (0x1FF2, "o"),
+    (0x1FF3, "o"),
+    (0x1FF4, "o"),
+    (0x1FF6, "o"),
+    (0x1FF7, "o"),
+    (0x1FF8, "O"),
+    (0x1FF9, "O"),
+    (0x1FFA, "O"),
+    (0x1FFB, "O"),
+    (0x1FFC, "O"),
+    (0x2000, " "),
+    (0x2001, " "),
+    (0x2002, " "),
+    (0x2003, " "),
+    (0x2004, " "),
+    (0x2005, " "),
+    (0x2006, " "),
+    (0x2007, " "),
+    (0x2008, " "),
+    (0x2009, " "),
+    (0x200A, " "),
+    (0x2010, "-"),
+    (0x2011, "-"),
+    (0x2012, "-"),
+    (0x2013, "-"),
+    (0x2014, "--"),
+    (0x2015, "--"),
+    (0x2018, "'"),
+    (0x2019, "'"),
+    (0x201A, "'"),
+    (0x201B, "'"),
+    (0x201C, "\""),
+    (0x201D, "\""),
+    (0x201E, "\""),
+    (0x201F, "\""),
+    (0x2022, "*"),
+    (0x2024, "."),
+    (0x2025, ".."),
+    (0x2026, "..."),
+    (0x202F, " "),
+    (0x2039, "<"),
+    (0x203A, ">"),
+    (0x203C, "!!"),
+    (0x2047, "??"),
+    (0x2048, "?!"),
+    (0x2049, "!?"),
+    (0x205F, " "),
+    (0x2070, "0"),
+    (0x2071, "i"),
+    (0x2074, "4"),
+    (0x2075, "5"),
+    (0x2076, "6"),
+    (0x2077, "7"),
+    (0x2078, "8"),
+    (0x2079, "9"),
+    (0x207A, "+"),
+    (0x207C, "="),
+    (0x207D, "("),
+    (0x207E, ")"),
+    (0x207F, "n"),
+    (0x2080, "0"),
+    (0x2081, "1"),
+    (0x2082, "2"),
+    (0x2083, "3"),
+    (0x2084, "4"),
+    (0x2085, "5"),
+    (0x2086, "6"),
+    (0x2087, "7"),
+    (0x2088, "8"),
+    (0x2089, "9"),
+    (0x208A, "+"),
+    (0x208C, "="),
+    (0x208D, "("),
+    (0x208E, ")"),
+    (0x2090, "a"),
+    (0x2091, "e"),
+    (0x2092, "o"),
+    (0x2093, "x"),
+    (0x2095, "h"),
+    (0x2096, "k"),
+    (0x2097, "l"),
+    (0x2098, "m"),
+    (0x2099, "n"),
+    (0x209A, "p"),
+    (0x209B, "s"),
+    (0x209C, "t"),
+    (0x20A8, "Rs"),
+    (0x20AC, "EUR"),
+    (0x2100, "a/c"),
+    (0x2101, "a/s"),
+    (0x2102, "C"),
+    (0x2105, "c/o"),
+    (0x2106, "c/u"),
+    (0x210A, "g"),
+    (0x210B, "H"),
+    (0x210C, "H"),
+    (0x210D, "H"),
+    (0x210E, "h"),
+    (0x2110, "I"),
+    (0x2111, "I"),
+    (0x2112, "L"),
+    (0x2113, "l"),
+    (0x2115, "N"),
+    (0x2116, "No"),
+    (0x2119, "P"),
+    (0x211A, "Q"),
+    (0x211B, "R"),
+    (0x211C, "R"),
+    (0x211D, "R"),
+    (0x2120, "SM"),
+    (0x2121, "TEL"),
+    (0x2122, "TM"),
+    (0x2124, "Z"),
+    (0x2128, "Z"),
+    (0x212A, "K"),
+    (0x212B, "A"),
+    (0x212C, "B"),
+    (0x212D, "C"),
+    (0x212F, "e"),
+    (0x2130, "E"),
+    (0x2131, "F"),
+    (0x2133, "M"),
+    (0x2134, "o"),
+    (0x2139, "i"),
+    (0x213B, "FAX"),
+    (0x2145, "D"),
+    (0x2146, "d"),
+    (0x2147, "e"),
+    (0x2148, "i"),
+    (0x2149, "j"),
+    (0x2160, "I"),
+    (0x2161, "II"),
+    (0x2162, "III"),
+    (0x2163, "IV"),
+    (0x2164, "V"),
+    (0x2165, "VI"),
+    (0x2166, "VII"),
+    (0x2167, "VIII"),
+    (0x2168, "IX"),
+    (0x2169, "X"),
+    (0x216A, "XI"),
+    (0x216B, "XII"),
+    (0x216C, "L"),
+    (0x216D, "C"),
+    (0x216E, "D"),
+    (0x216F, "M"),
+    (0x2170, "i"),
+    (0x2171, "ii"),
+    (0x2172, "iii"),
+    (0x2173, "iv"),
+    (0x2174, "v"),
+    (0x2175, "vi"),
+    (0x2176, "vii"),
+    (0x2177, "viii"),
+    (0x2178, "ix"),
+    (0x2179, "x"),
+    (0x217A, "xi"),
+    (0x217B, "xii"),
+    (0x217C, "l"),
+    (0x217D, "c"),
+    (0x217E, "d"),
+    (0x217F, "m"),
+    (0x2184, "c"),
+    (0x2212, "-"),
+    (0x2260, "="),
+    (0x226E, "<"),
+    (0x226F, ">"),
+    (0x2460, "1"),
+    (0x2461, "2"),
+    (0x2462, "3"),
+    (0x2463, "4"),
+    (0x2464, "5"),
+    (0x2465, "6"),
+    (0x2466, "7"),
+    (0x2467, "8"),
+    (0x2468, "9"),
+    (0x2469, "10"),
+    (0x246A, "11"),
+    (0x246B, "12"),
+    (0x246C, "13"),
+    (0x246D, "14"),
+    (0x246E, "15"),
+    (0x246F, "16"),
+    (0x2470, "17"),
+    (0x2471, "18"),
+    (0x2472, "19"),
+    (0x2473, "20"),
+    (0x2474, "(1)"),
+    (0x2475, "(2)"),
+    (0x2476, "(3)"),
+    (0x2477, "(4)"),
+    (0x2478, "(5)"),
+    (0x2479, "(6)"),
+    (0x247A, "(7)"),
+    (0x247B, "(8)"),
+    (0x247C, "(9)"),
+    (0x247D, "(10)"),
+    (0x247E, "(11)"),
+    (0x247F, "(12)"),
+    (0x2480, "(13)"),
+    (0x2481, "(14)"),
+    (0x2482, "(15)"),
+    (0x2483, "(16)"),
+    (0x2484, "(17)"),
+    (0x2485, "(18)"),
+    (0x2486, "(19)"),
+    (0x2487, "(20)"),
+    (0x2488, "1."),
+    (0x2489, "2."),
+    (0x248A, "3."),
+    (0x248B, "4."),
+    (0x248C, "5."),
+    (0x248D, "6."),
+    (0x248E, "7."),
+    (0x248F, "8."),
+    (0x2490, "9."),
+    (0x2491, "10."),
+    (0x2492, "11."),
+    (0x2493, "12."),
+    (0x2494, "13."),
+    (0x2495, "14."),
+    (0x2496, "15."),
+    (0x2497, "16."),
+    (0x2498, "17."),
+    (0x2499, "18."),
+    (0x249A, "19."),
+    (0x249B, "20."),
+    (0x249C, "(a)"),
+    (0x249D, "(b)"),
+    (0x249E, "(c)"),
+    (0x249F, "(d)"),
+    (0x24A0, "(e)"),
+    (0x24A1, "(f)"),
+    (0x24A2, "(g)"),
+    (0x24A3, "(h)"),
+    (0x24A4, "(i)"),
+    (0x24A5, "(j)"),
+    (0x24A6, "(k)"),
+    (0x24A7, "(l)"),
+    (0x24A8, "(m)"),
+    (0x24A9, "(n)"),
+    (0x24AA, "(o)"),
+    (0x24AB, "(p)"),
+    (0x24AC, "(q)"),
+    (0x24AD, "(r)"),
+    (0x24AE, "(s)"),
+    (0x24AF, "(t)"),
+    (0x24B0, "(u)"),
+    (0x24B1, "(v)"),
+    (0x24B2, "(w)"),
+    (0x24B3, "(x)"),
+    (0x24B4, "(y)"),
+    (0x24B5, "(z)"),
+    (0x24B6, "A"),
+    (0x24B7, "B"),
+    (0x24B8, "C"),
+    (0x24B9, "D"),
+    (0x24BA, "E"),
+    (0x24BB, "F"),
+    (0x24BC, "G"),
+    (0x24BD, "H"),
+    (0x24BE, "I"),
+    (0x24BF, "J"),
+    (0x24C0, "K"),
+    (0x24C1, "L"),
+    (0x24C2, "M"),
+    (0x24C3, "N"),
+    (0x24C4, "O"),
+    (0x24C5, "P"),
+    (0x24C6, "Q"),
+    (0x24C7, "R"),
+    (0x24C8, "S"),
+    (0x24C9, "T"),
+    (0x24CA, "U"),
+    (0x24CB, "V"),
+    (0x24CC, "W"),
+    (0x24CD, "X"),
+    (0x24CE, "Y"),
+    (0x24CF, "Z"),
+    (0x24D0, "a"),
+    (0x24D1, "b"),
+    (0x24D2, "c"),
+    (0x24D3, "d"),
+    (0x24D4, "e"),
+    (0x24D5, "f"),
+    (0x24D6, "g"),
+    (0x24D7, "h"),
+    (0x24D8, "i"),
+    (0x24D9, "j"),
+    (0x24DA, "k"),
+    (0x24DB, "l"),
+    (0x24DC, "m"),
+    (0x24DD, "n"),
+    (0x24DE, "o"),
+    (0x24DF, "p"),
+    (0x24E0, "q"),
+    (0x24E1, "r"),
+    (0x24E2, "s"),
+    (0x24E3, "t"),
+    (0x24E4, "u"),
+    (0x24E5, "v"),
+    (0x24E6, "w"),
+    (0x24E7, "x"),
+    (0x24E8, "y"),
+    (0x24E9, "z"),
+    (0x24EA, "0"),
+    (0x2A74, "::="),
+    (0x2A75, "=="),
+    (0x2A76, "==="),
+    (0x2C60, "L"),
+    (0x2C61, "l"),
+    (0x2C62, "L"),
+    (0x2C63, "P"),
+    (0x2C64, "R"),
+    (0x2C65, "a"),
+    (0x2C66, "t"),
+    (0x2C67, "H"),
+    (0x2C68, "h"),
+    (0x2C69, "K"),
+    (0x2C6A, "k"),
+    (0x2C6B, "Z"),
+    (0x2C6C, "z"),
+    (0x2C6E, "M"),
+    (0x2C6F, "A"),
+    (0x2C71, "v"),
+    (0x2C72, "W"),
+    (0x2C73, "w"),
+    (0x2C74, "v"),
+    (0x2C78, "e"),
+    (0x2C79, "r"),
+    (0x2C7A, "o"),
+    (0x2C7C, "j"),
+    (0x2C7D, "V"),
+    (0x2C7E, "S"),
+    (0x2C7F, "Z"),
+    (0x3000, " "),
+    (0x3250, "PTE"),
+    (0x3251, "21"),
+    (0x3252, "22"),
+    (0x3253, "23"),
+    (0x3254, "24"),
+    (0x3255, "25"),
+    (0x3256, "26"),
+    (0x3257, "27"),
+    (0x3258, "28"),
+    (0x3259, "29"),
+    (0x325A, "30"),
+    (0x325B, "31"),
+    (0x325C, "32"),
+    (0x325D, "33"),
+    (0x325E, "34"),
+    (0x325F, "35"),
+    (0x32B1, "36"),
+    (0x32B2, "37"),
+    (0x32B3, "38"),
+    (0x32B4, "39"),
+    (0x32B5, "40"),
+    (0x32B6, "41"),
+    (0x32B7, "42"),
+    (0x32B8, "43"),
+    (0x32B9, "44"),
+    (0x32BA, "45"),
+    (0x32BB, "46"),
+    (0x32BC, "47"),
+    (0x32BD, "48"),
+    (0x32BE, "49"),
+    (0x32BF, "50"),
+    (0x32CC, "Hg"),
+    (0x32CD, "erg"),
+    (0x32CE, "eV"),
+    (0x32CF, "LTD"),
+    (0x3371, "hPa"),
+    (0x3372, "da"),
+    (0x3373, "AU"),
+    (0x3374, "bar"),
+    (0x3375, "oV"),
+    (0x3376, "pc"),
+    (0x3377, "dm"),
+    (0x3378, "dm2"),
+    (0x3379, "dm3"),
+    (0x337A, "IU"),
+    (0x3380, "pA"),
+    (0x3381, "nA"),
+    (0x3383, "mA"),
+    (0x3384, "kA"),
+    (0x3385, "KB"),
+    (0x3386, "MB"),
+    (0x3387, "GB"),
+    (0x3388, "cal"),
+    (0x3389, "kcal"),
+    (0x338A, "pF"),
+    (0x338B, "nF"),
+    (0x338E, "mg"),
+    (0x338F, "kg"),
+    (0x3390, "Hz"),
+    (0x3391, "kHz"),
+    (0x3392, "MHz"),
+    (0x3393, "GHz"),
+    (0x3394, "THz"),
+    (0x3396, "ml"),
+    (0x3397, "dl"),
+    (0x3398, "kl"),
+    (0x3399, "fm"),
+    (0x339A, "nm"),
+    (0x339C, "mm"),
+    (0x339D, "cm"),
+    (0x339E, "km"),
+    (0x339F, "mm2"),
+    (0x33A0, "cm2"),
+    (0x33A1, "m2"),
+    (0x33A2, "km2"),
+    (0x33A3, "mm3"),
+    (0x33A4, "cm3"),
+    (0x33A5, "m3"),
+    (0x33A6, "km3"),
+    (0x33A9, "Pa"),
+    (0x33AA, "kPa"),
+    (0x33AB, "MPa"),
+    (0x33AC, "GPa"),
+    (0x33AD, "rad"),
+    (0x33B0, "ps"),
+    (0x33B1, "ns"),
+    (0x33B3, "ms"),
+    (0x33B4, "pV"),
+    (0x33B5, "nV"),
+    (0x33B7, "mV"),
+    (0x33B8, "kV"),
+    (0x33B9, "MV"),
+    (0x33BA, "pW"),
+    (0x33BB, "nW"),
+    (0x33BD, "mW"),
+    (0x33BE, "kW"),
+    (0x33BF, "MW"),
+    (0x33C2, "a.m."),
+    (0x33C3, "Bq"),
+    (0x33C4, "cc"),
+    (0x33C5, "cd"),
+    (0x33C7, "Co."),
+    (0x33C8, "dB"),
+    (0x33C9, "Gy"),
+    (0x33CA, "ha"),
+    (0x33CB, "HP"),
+    (0x33CC, "in"),
+    (0x33CD, "KK"),
+    (0x33CE, "KM"),
+    (0x33CF, "kt"),
+    (0x33D0, "lm"),
+    (0x33D1, "ln"),
+    (0x33D2, "log"),
+    (0x33D3, "lx"),
+    (0x33D4, "mb"),
+    (0x33D5, "mil"),
+    (0x33D6, "mol"),
+    (0x33D7, "PH"),
+    (0x33D8, "p.m."),
+    (0x33D9, "PPM"),
+    (0x33DA, "PR"),
+    (0x33DB, "sr"),
+    (0x33DC, "Sv"),
+    (0x33DD, "Wb"),
+    (0x33FF, "gal"),
+    (0xA650, "Y"),
+    (0xA651, "y"),
+    (0xA68A, "T"),
+    (0xA68B, "t"),
+    (0xA728, "TZ"),
+    (0xA729, "tz"),
+    (0xA732, "AA"),
+    (0xA733, "aa"),
+    (0xA734, "AO"),
+    (0xA735, "ao"),
+    (0xA736, "AU"),
+    (0xA737, "au"),
+    (0xA738, "AV"),
+    (0xA739, "av"),
+    (0xA73A, "AV"),
+    (0xA73B, "av"),
+    (0xA73C, "AY"),
+    (0xA73D, "ay"),
+    (0xA73E, "C"),
+    (0xA73F, "c"),
+    (0xA740, "K"),
+    (0xA741, "k"),
+    (0xA742, "K"),
+    (0xA743, "k"),
+    (0xA744, "K"),
+    (0xA745, "k"),
+    (0xA748, "L"),
+    (0xA749, "l"),
+    (0xA74A, "O"),
+    (0xA74B, "o"),
+    (0xA74C, "O"),
+    (0xA74D, "o"),
+    (0xA74E, "OO"),
+    (0xA74F, "oo"),
+    (0xA750, "P"),
+    (0xA751, "p"),
+    (0xA752, "P"),
+    (0xA753, "p"),
+    (0xA754, "P"),
+    (0xA755, "p"),
+    (0xA756, "Q"),
+    (0xA757, "q"),
+    (0xA758, "Q"),
+    (0xA759, "q"),
+    (0xA75E, "V"),
+    (0xA75F, "v"),
+    (0xA760, "VY"),
+    (0xA761, "vy"),
+    (0xA76A, "ET"),
+    (0xA76B, "et"),
+    (0xA76C, "IS"),
+    (0xA76D, "is"),
+    (0xA778, "um"),
+    (0xA780, "L"),
+    (0xA781, "l"),
+    (0xA78D, "H"),
+    (0xA78E, "l"),
+    (0xA790, "N"),
+    (0xA791, "n"),
+    (0xA792, "C"),
+    (0xA793, "c"),
+    (0xA794, "c"),
+    (0xA795, "h"),
+    (0xA796, "B"),
+    (0xA797, "b"),
+    (0xA798, "F"),
+    (0xA799, "f"),
+    (0xA7A0, "G"),
+    (0xA7A1, "g"),
+    (0xA7A2, "K"),
+    (0xA7A3, "k"),
+    (0xA7A4, "N"),
+    (0xA7A5, "n"),
+    (0xA7A6, "R"),
+    (0xA7A7, "r"),
+    (0xA7A8, "S"),
+    (0xA7A9, "s"),
+    (0xA7AA, "H"),
+    (0xA7AB, "E"),
+    (0xA7AC, "G"),
+    (0xA7AD, "L"),
+    (0xA7AE, "I"),
+    (0xA7B0, "K"),
+    (0xA7B1, "T"),
+    (0xA7B2, "J"),
+    (0xA7B8, "U"),
+    (0xA7B9, "u"),
+    (0xA7C4, "C"),
+    (0xA7C5, "S"),
+    (0xA7C6, "Z"),
+    (0xA7C7, "D"),
+    (0xA7C8, "d"),
+    (0xA7C9, "S"),
+    (0xA7CA, "s"),
+    (0xA7F2, "C"),
+    (0xA7F3, "F"),
+    (0xA7F4, "Q"),
+    (0xAB34, "e"),
+    (0xAB36, "g"),
+    (0xAB37, "l"),
+    (0xAB38, "l"),
+    (0xAB39, "l"),
+    (0xAB3A, "m"),
+    (0xAB3B, "n"),
+    (0xAB3F, "o"),
+    (0xAB41, "oe"),
+    (0xAB42, "oe"),
+    (0xAB49, "r"),
+    (0xAB4B, "r"),
+    (0xAB4C, "r"),
+    (0xAB4E, "u"),
+    (0xAB50, "ui"),
+    (0xAB51, "ui"),
+    (0xAB52, "u"),
+    (0xAB56, "x"),
+    (0xAB57, "x"),
+    (0xAB58, "x"),
+    (0xAB59, "x"),
+    (0xAB5A, "y"),
+    (0xAB62, "oe"),
+    (0xAB63, "uo"),
+    (0xAB68, "r"),
+    (0xFB00, "ff"),
+    (0xFB01, "fi"),
+    (0xFB02, "fl"),
+    (0xFB03, "ffi"),
+    (0xFB04, "ffl"),
+    (0xFB05, "st"),
+    (0xFB06, "st"),
+    (0xFB29, "+"),
+    (0xFE10, ","),
+    (0xFE13, ":"),
+    (0xFE14, ";"),
+    (0xFE15, "!"),
+    (0xFE16, "?"),
+    (0xFE19, "..."),
+    (0xFE30, ".."),
+    (0xFE33, "_"),
+    (0xFE34, "_"),
+    (0xFE35, "("),
+    (0xFE36, ")"),
+    (0xFE37, "{"),
+    (0xFE38, "}"),
+    (0xFE47, "["),
+    (0xFE48, "]"),
+    (0xFE4D, "_"),
+    (0xFE4E, "_"),
+    (0xFE4F, "_"),
+    (0xFE50, ","),
+    (0xFE52, "."),
+    (0xFE54, ";"),
+    (0xFE55, ":"),
+    (0xFE56, "?"),
+    (0xFE57, "!"),
+    (0xFE59, "("),
+    (0xFE5A, ")"),
+    (0xFE5B, "{"),
+    (0xFE5C, "}"),
+    (0xFE5F, "#"),
+    (0xFE60, "&"),
+    (0xFE61, "*"),
+    (0xFE62, "+"),
+    (0xFE63, "-"),
+    (0xFE64, "<"),
+    (0xFE65, ">"),
+    (0xFE66, "="),
+    (0xFE68, "\\"),
+    (0xFE69, "$"),
+    (0xFE6A, "%"),
+    (0xFE6B, "@"),
+    (0xFF01, "!"),
+    (0xFF02, "\""),
+    (0xFF03, "#"),
+    (0xFF04, "$"),
+    (0xFF05, "%"),
+    (0xFF06, "&"),
+    (0xFF07, "'"),
+    (0xFF08, "("),
+    (0xFF09, ")"),
+    (0xFF0A, "*"),
+    (0xFF0B, "+"),
+    (0xFF0C, ","),
+    (0xFF0D, "-"),
+    (0xFF0E, "."),
+    (0xFF0F, "/"),
+    (0xFF10, "0"),
+    (0xFF11, "1"),
+    (0xFF12, "2"),
+    (0xFF13, "3"),
+    (0xFF14, "4"),
+    (0xFF15, "5"),
+    (0xFF16, "6"),
+    (0xFF17, "7"),
+    (0xFF18, "8"),
+    (0xFF19, "9"),
+    (0xFF1A, ":"),
+    (0xFF1B, ";"),
+    (0xFF1C, "<"),
+    (0xFF1D, "="),
+    (0xFF1E, ">"),
+    (0xFF1F, "?"),
+    (0xFF20, "@"),
+    (0xFF21, "A"),
+    (0xFF22, "B"),
+    (0xFF23, "C"),
+    (0xFF24, "D"),
+    (0xFF25, "E"),
+    (0xFF26, "F"),
+    (0xFF27, "G"),
+    (0xFF28, "H"),
+    (0xFF29, "I"),
+    (0xFF2A, "J"),
+    (0xFF2B, "K"),
+    (0xFF2C, "L"),
+    (0xFF2D, "M"),
+    (0xFF2E, "N"),
+    (0xFF2F, "O"),
+    (0xFF30, "P"),
+    (0xFF31, "Q"),
+    (0xFF32, "R"),
+    (0xFF33, "S"),
+    (0xFF34, "T"),
+    (0xFF35, "U"),
+    (0xFF36, "V"),
+    (0xFF37, "W"),
+    (0xFF38, "X"),
+    (0xFF39, "Y"),
+    (0xFF3A, "Z"),
+    (0xFF3B, "["),
+    (0xFF3C, "\\"),
+    (0xFF3D, "]"),
+    (0xFF3E, "^"),
+    (0xFF3F, "_"),
+    (0xFF40, "`"),
+    (0xFF41, "a"),
+    (0xFF42, "b"),
+    (0xFF43, "c"),
+    (0xFF44, "d"),
+    (0xFF45, "e"),
+    (0xFF46, "f"),
+    (0xFF47, "g"),
+    (0xFF48, "h"),
+    (0xFF49, "i"),
+    (0xFF4A, "j"),
+    (0xFF4B, "k"),
+    (0xFF4C, "l"),
+    (0xFF4D, "m"),
+    (0xFF4E, "n"),
+    (0xFF4F, "o"),
+    (0xFF50, "p"),
+    (0xFF51, "q"),
+    (0xFF52, "r"),
+    (0xFF53, "s"),
+    (0xFF54, "t"),
+    (0xFF55, "u"),
+    (0xFF56, "v"),
+    (0xFF57, "w"),
+    (0xFF58, "x"),
+    (0xFF59, "y"),
+    (0xFF5A, "z"),
+    (0xFF5B, "{"),
+    (0xFF5C, "|"),
+    (0xFF5D, "}"),
+    (0xFF5E, "~"),
+    (0x107A5, "q"),
+    (0x1D400, "A"),
+    (0x1D401, "B"),
+    (0x1D402, "C"),
+    (0x1D403, "D"),
+    (0x1D404, "E"),
+    (0x1D405, "F"),
+    (0x1D406, "G"),
+    (0x1D407, "H"),
+    (0x1D408, "I"),
+    (0x1D409, "J"),
+    (0x1D40A, "K"),
+    (0x1D40B, "L"),
+    (0x1D40C, "M"),
+    (0x1D40D, "N"),
+    (0x1D40E, "O"),
+    (0x1D40F, "P"),
+    (0x1D410, "Q"),
+    (0x1D411, "R"),
+    (0x1D412, "S"),
+    (0x1D413, "T"),
+    (0x1D414, "U"),
+    (0x1D415, "V"),
+    (0x1D416, "W"),
+    (0x1D417, "X"),
+    (0x1D418, "Y"),
+    (0x1D419, "Z"),
+    (0x1D41A, "a"),
+    (0x1D41B, "b"),
+    (0x1D41C, "c"),
+    (0x1D41D, "d"),
+    (0x1D41E, "e"),
+    (0x1D41F, "f"),
+    (0x1D420, "g"),
+    (0x1D421, "h"),
+    (0x1D422, "i"),
+    (0x1D423, "j"),
+    (0x1D424, "k"),
+    (0x1D425, "l"),
+    (0x1D426, "m"),
+    (0x1D427, "n"),
+    (0x1D428, "o"),
+    (0x1D429, "p"),
+    (0x1D42A, "q"),
+    (0x1D42B, "r"),
+    (0x1D42C, "s"),
+    (0x1D42D, "t"),
+    (0x1D42E, "u"),
+    (0x1D42F, "v"),
+    (0x1D430, "w"),
+    (0x1D431, "x"),
+    (0x1D432, "y"),
+    (0x1D433, "z"),
+    (0x1D434, "A"),
+    (0x1D435, "B"),
+    (0x1D436, "C"),
+    (0x1D437, "D"),
+    (0x1D438, "E"),
+    (0x1D439, "F"),
+    (0x1D43A, "G"),
+    (0x1D43B, "H"),
+    (0x1D43C, "I"),
+    (0x1D43D, "J"),
+    (0x1D43E, "K"),
+    (0x1D43F, "L"),
+    (0x1D440, "M"),
+    (0x1D441, "N"),
+    (0x1D442, "O"),
+    (0x1D443, "P"),
+    (0x1D444, "Q"),
+    (0x1D445, "R"),
+    (0x1D446, "S"),
+    (0x1D447, "T"),
+    (0x1D448, "U"),
+    (0x1D449, "V"),
+    (0x1D44A, "W"),
+    (0x1D44B, "X"),
+    (0x1D44C, "Y"),
+    (0x1D44D, "Z"),
+    (0x1D44E, "a"),
+    (0x1D44F, "b"),
+    (0x1D450, "c"),
+    (0x1D451, "d"),
+    (0x1D452, "e"),
+    (0x1D453, "f"),
+    (0x1D454, "g"),
+    (0x1D456, "i"),
+    (0x1D457, "j"),
+    (0x1D458, "k"),
+    (0x1D459, "l"),
+    (0x1D45A, "m"),
+    (0x1D45B, "n"),
+    (0x1D45C, "o"),
+    (0x1D45D, "p"),
+    (0x1D45E, "q"),
+    (0x1D45F, "r"),
+    (0x1D460, "s"),
+    (0x1D461, "t"),
+    (0x1D462, "u"),
+    (0x1D463, "v"),
+    (0x1D464, "w"),
+    (0x1D465, "x"),
+    (0x1D466, "y"),
+    (0x1D467, "z"),
+    (0x1D468, "A"),
+    (0x1D469, "B"),
+    (0x1D46A, "C"),
+    (0x1D46B, "D"),
+    (0x1D46C, "E"),
+    (0x1D46D, "F"),
+    (0x1D46E, "G"),
+    (0x1D46F, "H"),
+    (0x1D470, "I"),
+    (0x1D471, "J"),
+    (0x1D472, "K"),
+    (0x1D473, "L"),
+    (0x1D474, "M"),
+    (0x1D475, "N"),
+    (0x1D476, "O"),
+    (0x1D477, "P"),
+    (0x1D478, "Q"),
+    (0x1D479, "R"),
+    (0x1D47A, "S"),
+    (0x1D47B, "T"),
+    (0x1D47C, "U"),
+    (0x1D47D, "V"),
+    (0x1D47E, "W"),
+    (0x1D47F, "X"),
+    (0x1D480, "Y"),
+    (0x1D481, "Z"),
+    (0x1D482, "a"),
+    (0x1D483, "b"),
+    (0x1D484, "c"),
+    (0x1D485, "d"),
+    (0x1D486, "e"),
+    (0x1D487, "f"),
+    (0x1D488, "g"),
+    (0x1D489, "h"),
+    (0x1D48A, "i"),
+    (0x1D48B, "j"),
+    (0x1D48C, "k"),
+    (0x1D48D, "l"),
+    (0x1D48E, "m"),
+    (0x1D48F, "n"),
+    (0x1D490, "o"),
+    (0x1D491, "p"),
+    (0x1D492, "q"),
+    (0x1D493, "r"),
+    (0x1D494, "s"),
+    (0x1D495, "t"),
+    (0x1D496, "u"),
+    (0x1D497, "v"),
+    (0x1D498, "w"),
+    (0x1D499, "x"),
+    (0x1D49A, "y"),
+    (0x1D49B, "z"),
+    (0x1D49C, "A"),
+    (0x1D49E, "C"),
+    (0x1D49F, "D"),
+    (0x1D4A2, "G"),
+    (0x1D4A5, "J"),
+    (0x1D4A6, "K"),
+    (0x1D4A9, "N"),
+    (0x1D4AA, "O"),
+    (0x1D4AB, "P"),
+    (0x1D4AC, "Q"),
+    (0x1D4AE, "S"),
+    (0x1D4AF, "T"),
+    (0x1D4B0, "U"),
+    (0x1D4B1, "V"),
+    (0x1D4B2, "W"),
+    (0x1D4B3, "X"),
+    (0x1D4B4, "Y"),
+    (0x1D4B5, "Z"),
+    (0x1D4B6, "a"),
+    (0x1D4B7, "b"),
+    (0x1D4B8, "c"),
+    (0x1D4B9, "d"),
+    (0x1D4BB, "f"),
+    (0x1D4BD, "h"),
+    (0x1D4BE, "i"),
+    (0x1D4BF, "j"),
+    (0x1D4C0, "k"),
+    (0x1D4C1, "l"),
+    (0x1D4C2, "m"),
+    (0x1D4C3, "n"),
+    (0x1D4C5, "p"),
+    (0x1D4C6, "q"),
+    (0x1D4C7, "r"),
+    (0x1D4C8, "s"),
+    (0x1D4C9, "t"),
+    (0x1D4CA, "u"),
+    (0x1D4CB, "v"),
+    (0x1D4CC, "w"),
+    (0x1D4CD, "x"),
+    (0x1D4CE, "y"),
+    (0x1D4CF, "z"),
+    (0x1D4D0, "A"),
+    (0x1D4D1, "B"),
+    (0x1D4D2, "C"),
+    (0x1D4D3, "D"),
+    (0x1D4D4, "E"),
+    (0x1D4D5, "F"),
+    (0x1D4D6, "G"),
+    (0x1D4D7, "H"),
+    (0x1D4D8, "I"),
+    (0x1D4D9, "J"),
+    (0x1D4DA, "K"),
+    (0x1D4DB, "L"),
+    (0x1D4DC, "M"),
+    (0x1D4DD, "N"),
+    (0x1D4DE, "O"),
+    (0x1D4DF, "P"),
+    (0x1D4E0, "Q"),
+    (0x1D4E1, "R"),
+    (0x1D4E2, "S"),
+    (0x1D4E3, "T"),
+    (0x1D4E4, "U"),
+    (0x1D4E5, "V"),
+    (0x1D4E6, "W"),
+    (0x1D4E7, "X"),
+    (0x1D4E8, "Y"),
+    (0x1D4E9, "Z"),
+    (0x1D4EA, "a"),
+    (0x1D4EB, "b"),
+    (0x1D4EC, "c"),
+    (0x1D4ED, "d"),
+    (0x1D4EE, "e"),
+    (0x1D4EF, "f"),
+    (0x1D4F0, "g"),
+    (0x1D4F1, "h"),
+    (0x1D4F2, "i"),
+    (0x1D4F3, "j"),
+    (0x1D4F4, "k"),
+    (0x1D4F5, "l"),
+    (0x1D4F6, "m"),
+    (0x1D4F7, "n"),
+    (0x1D4F8, "o"),
+    (0x1D4F9, "p"),
+    (0x1D4FA, "q"),
+    (0x1D4FB, "r"),
+    (0x1D4FC, "s"),
+    (0x1D4FD, "t"),
+    (0x1D4FE, "u"),
+    (0x1D4FF, "v"),
+    (0x1D500, "w"),
+    (0x1D501, "x"),
+    (0x1D502, "y"),
+    (0x1D503, "z"),
+    (0x1D504, "A"),
+    (0x1D505, "B"),
+    (0x1D507, "D"),
+    (0x1D508, "E"),
+    (0x1D509, "F"),
+    (0x1D50A, "G"),
+    (0x1D50D, "J"),
+    (0x1D50E, "K"),
+    (0x1D50F, "L"),
+    (0x1D510, "M"),
+    (0x1D511, "N"),
+    (0x1D512, "O"),
+    (0x1D513, "P"),
+    (0x1D514, "Q"),
+    (0x1D516, "S"),
+    (0x1D517, "T"),
+    (0x1D518, "U"),
+    (0x1D519, "V"),
+    (0x1D51A, "W"),
+    (0x1D51B, "X"),
+    (0x1D51C, "Y"),
+    (0x1D51E, "a"),
+    (0x1D51F, "b"),
+    (0x1D520, "c"),
+    (0x1D521, "d"),
+    (0x1D522, "e"),
+    (0x1D523, "f"),
+    (0x1D524, "g"),
+    (0x1D525, "h"),
+    (0x1D526, "i"),
+    (0x1D527, "j"),
+    (0x1D528, "k"),
+    (0x1D529, "l"),
+    (0x1D52A, "m"),
+    (0x1D52B, "n"),
+    (0x1D52C, "o"),
+    (0x1D52D, "p"),
+    (0x1D52E, "q"),
+    (0x1D52F, "r"),
+    (0x1D530, "s"),
+    (0x1D531, "t"),
+    (0x1D532, "u"),
+    (0x1D533, "v"),
+    (0x1D534, "w"),
+    (0x1D535, "x"),
+    (0x1D536, "y"),
+    (0x1D537, "z"),
+    (0x1D538, "A"),
+    (0x1D539, "B"),
+    (0x1D53B, "D"),
+    (0x1D53C, "E"),
+    (0x1D53D, "F"),
+    (0x1D53E, "G"),
+    (0x1D540, "I"),
+    (0x1D541, "J"),
+    (0x1D542, "K"),
+    (0x1D543, "L"),
+    (0x1D544, "M"),
+    (0x1D546, "O"),
+    (0x1D54A, "S"),
+    (0x1D54B, "T"),
+    (0x1D54C, "U"),
+    (0x1D54D, "V"),
+    (0x1D54E, "W"),
+    (0x1D54F, "X"),
+    (0x1D550, "Y"),
+    (0x1D552, "a"),
+    (0x1D553, "b"),
+    (0x1D554, "c"),
+    (0x1D555, "d"),
+    (0x1D556, "e"),
+    (0x1D557, "f"),
+    (0x1D558, "g"),
+    (0x1D559, "h"),
+    (0x1D55A, "i"),
+    (0x1D55B, "j"),
+    (0x1D55C, "k"),
+    (0x1D55D, "l"),
+    (0x1D55E, "m"),
+    (0x1D55F, "n"),
+    (0x1D560, "o"),
+    (0x1D561, "p"),
+    (0x1D562, "q"),
+    (0x1D563, "r"),
+    (0x1D564, "s"),
+    (0x1D565, "t"),
+    (0x1D566, "u"),
+    (0x1D567, "v"),
+    (0x1D568, "w"),
+    (0x1D569, "x"),
+    (0x1D56A, "y"),
+    (0x1D56B, "z"),
+    (0x1D56C, "A"),
+    (0x1D56D, "B"),
+    (0x1D56E, "C"),
+    (0x1D56F, "D"),
+    (0x1D570, "E"),
+    (0x1D571, "F"),
+    (0x1D572, "G"),
+    (0x1D573, "H"),
+    (0x1D574, "I"),
+    (0x1D575, "J"),
+    (0x1D576, "K"),
+    (0x1D577, "L"),
+    (0x1D578, "M"),
+    (0x1D579, "N"),
+    (0x1D57A, "O"),
+    (0x1D57B, "P"),
+    (0x1D57C, "Q"),
+    (0x1D57D, "R"),
+    (0x1D57E, "S"),
+    (0x1D57F, "T"),
+    (0x1D580, "U"),
+    (0x1D581, "V"),
+    (0x1D582, "W"),
+    (0x1D583, "X"),
+    (0x1D584, "Y"),
+    (0x1D585, "Z"),
+    (0x1D586, "a"),
+    (0x1D587, "b"),
+    (0x1D588, "c"),
+    (0x1D589, "d"),
+    (0x1D58A, "e"),
+    (0x1D58B, "f"),
+    (0x1D58C, "g"),
+    (0x1D58D, "h"),
+    (0x1D58E, "i"),
+    (0x1D58F, "j"),
+    (0x1D590, "k"),
+    (0x1D591, "l"),
+    (0x1D592, "m"),
+    (0x1D593, "n"),
+    (0x1D594, "o"),
+    (0x1D595, "p"),
+    (0x1D596, "q"),
+    (0x1D597, "r"),
+    (0x1D598, "s"),
+    (0x1D599, "t"),
+    (0x1D59A, "u"),
+    (0x1D59B, "v"),
+    (0x1D59C, "w"),
+    (0x1D59D, "x"),
+    (0x1D59E, "y"),
+    (0x1D59F, "z"),
+    (0x1D5A0, "A"),
+    (0x1D5A1, "B"),
+    (0x1D5A2, "C"),
+    (0x1D5A3, "D"),
+    (0x1D5A4, "E"),
+    (0x1D5A5, "F"),
+    (0x1D5A6, "G"),
+    (0x1D5A7, "H"),
+    (0x1D5A8, "I"),
+    (0x1D5A9, "J"),
+    (0x1D5AA, "K"),
+    (0x1D5AB, "L"),
+    (0x1D5AC, "M"),
+    (0x1D5AD, "N"),
+    (0x1D5AE, "O"),
+    (0x1D5AF, "P"),
+    (0x1D5B0, "Q"),
+    (0x1D5B1, "R"),
+    (0x1D5B2, "S"),
+    (0x1D5B3, "T"),
+    (0x1D5B4, "U"),
+    (0x1D5B5, "V"),
+    (0x1D5B6, "W"),
+    (0x1D5B7, "X"),
+    (0x1D5B8, "Y"),
+    (0x1D5B9, "Z"),
+    (0x1D5BA, "a"),
+    (0x1D5BB, "b"),
+    (0x1D5BC, "c"),
+    (0x1D5BD, "d"),
+    (0x1D5BE, "e"),
+    (0x1D5BF, "f"),
+    (0x1D5C0, "g"),
+    (0x1D5C1, "h"),
+    (0x1D5C2, "i"),
+    (0x1D5C3, "j"),
+    (0x1D5C4, "k"),
+    (0x1D5C5, "l"),
+    (0x1D5C6, "m"),
+    (0x1D5C7, "n"),
+    (0x1D5C8, "o"),
+    (0x1D5C9, "p"),
+    (0x1D5CA, "q"),
+    (0x1D5CB, "r"),
+    (0x1D5CC, "s"),
+    (0x1D5CD, "t"),
+    (0x1D5CE, "u"),
+    (0x1D5CF, "v"),
+    (0x1D5D0, "w"),
+    (0x1D5D1, "x"),
+    (0x1D5D2, "y"),
+    (0x1D5D3, "z"),
+    (0x1D5D4, "A"),
+    (0x1D5D5, "B"),
+    (0x1D5D6, "C"),
+    (0x1D5D7, "D"),
+    (0x1D5D8, "E"),
+    (0x1D5D9, "F"),
+    (0x1D5DA, "G"),
+    (0x1D5DB, "H"),
+    (0x1D5DC, "I"),
+    (0x1D5DD, "J"),
+    (0x1D5DE, "K"),
+    (0x1D5DF, "L"),
+    (0x1D5E0, "M"),
+    (0x1D5E1, "N"),
+    (0x1D5E2, "O"),
+    (0x1D5E3, "P"),
+    (0x1D5E4, "Q"),
+    (0x1D5E5, "R"),
+    (0x1D5E6, "S"),
+    (0x1D5E7, "T"),
+    (0x1D5E8, "U"),
+    (0x1D5E9, "V"),
+    (0x1D5EA, "W"),
+    (0x1D5EB, "X"),
+    (0x1D5EC, "Y"),
+    (0x1D5ED, "Z"),
+    (0x1D5EE, "a"),
+    (0x1D5EF, "b"),
+    (0x1D5F0, "c"),
+    (0x1D5F1, "d"),
+    (0x1D5F2, "e"),
+    (0x1D5F3, "f"),
+    (0x1D5F4, "g"),
+    (0x1D5F5, "h"),
+    (0x1D5F6, "i"),
+    (0x1D5F7, "j"),
+    (0x1D5F8, "k"),
+    (0x1D5F9, "l"),
+    (0x1D5FA, "m"),
+    (0x1D5FB, "n"),
+    (0x1D5FC, "o"),
+    (0x1D5FD, "p"),
+    (0x1D5FE, "q"),
+    (0x1D5FF, "r"),
+    (0x1D600, "s"),
+    (0x1D601, "t"),
+    (0x1D602, "u"),
+    (0x1D603, "v"),
+    (0x1D604, "w"),
+    (0x1D605, "x"),
+    (0x1D606, "y"),
+    (0x1D607, "z"),
+    (0x1D608, "A"),
+    (0x1D609, "B"),
+    (0x1D60A, "C"),
+    (0x1D60B, "D"),
+    (0x1D60C, "E"),
+    (0x1D60D, "F"),
+    (0x1D60E, "G"),
+    (0x1D60F, "H"),
+    (0x1D610, "I"),
+    (0x1D611, "J"),
+    (0x1D612, "K"),
+    (0x1D613, "L"),
+    (0x1D614, "M"),
+    (0x1D615, "N"),
+    (0x1D616, "O"),
+    (0x1D617, "P"),
+    (0x1D618, "Q"),
+    (0x1D619, "R"),
+    (0x1D61A, "S"),
+    (0x1D61B, "T"),
+    (0x1D61C, "U"),
+    (0x1D61D, "V"),
+    (0x1D61E, "W"),
+    (0x1D61F, "X"),
+    (0x1D620, "Y"),
+    (0x1D621, "Z"),
+    (0x1D622, "a"),
+    (0x1D623, "b"),
+    (0x1D624, "c"),
+    (0x1D625, "d"),
+    (0x1D626, "e"),
+    (0x1D627, "f"),
+    (0x1D628, "g"),
+    (0x1D629, "h"),
+    (0x1D62A, "i"),
+    (0x1D62B, "j"),
+    (0x1D62C, "k"),
+    (0x1D62D, "l"),
+    (0x1D62E, "m"),
+    (0x1D62F, "n"),
+    (0x1D630, "o"),
+    (0x1D631, "p"),
+    (0x1D632, "q"),
+    (0x1D633, "r"),
+    (0x1D634, "s"),
+    (0x1D635, "t"),
+    (0x1D636, "u"),
+    (0x1D637, "v"),
+    (0x1D638, "w"),
+    (0x1D639, "x"),
+    (0x1D63A, "y"),
+    (0x1D63B, "z"),
+    (0x1D63C, "A"),
+    (0x1D63D, "B"),
+    (0x1D63E, "C"),
+    (0x1D63F, "D"),
+    (0x1D640, "E"),
+    (0x1D641, "F"),
+    (0x1D642, "G"),
+    (0x1D643, "H"),
+    (0x1D644, "I"),
+    (0x1D645, "J"),
+    (0x1D646, "K"),
+    (0x1D647, "L"),
+    (0x1D648, "M"),
+    (0x1D649, "N"),
+    (0x1D64A, "O"),
+    (0x1D64B, "P"),
+    (0x1D64C, "Q"),
+    (0x1D64D, "R"),
+    (0x1D64E, "S"),
+    (0x1D64F, "T"),
+    (0x1D650, "U"),
+    (0x1D651, "V"),
+    (0x1D652, "W"),
+    (0x1D653, "X"),
+    (0x1D654, "Y"),
+    (0x1D655, "Z"),
+    (0x1D656, "a"),
+    (0x1D657, "b"),
+    (0x1D658, "c"),
+    (0x1D659, "d"),
+    (0x1D65A, "e"),
+    (0x1D65B, "f"),
+    (0x1D65C, "g"),
+    (0x1D65D, "h"),
+    (0x1D65E, "i"),
+    (0x1D65F, "j"),
+    (0x1D660, "k"),
+    (0x1D661, "l"),
+    (0x1D662, "m"),
+    (0x1D663, "n"),
+    (0x1D664, "o"),
+    (0x1D665, "p"),
+    (0x1D666, "q"),
+    (0x1D667, "r"),
+    (0x1D668, "s"),
+    (0x1D669, "t"),
+    (0x1D66A, "u"),
+    (0x1D66B, "v"),
+    (0x1D66C, "w"),
+    (0x1D66D, "x"),
+    (0x1D66E, "y"),
+    (0x1D66F, "z"),
+    (0x1D670, "A"),
+    (0x1D671, "B"),
+    (0x1D672, "C"),
+    (0x1D673, "D"),
+    (0x1D674, "E"),
+    (0x1D675, "F"),
+    (0x1D676, "G"),
+    (0x1D677, "H"),
+    (0x1D678, "I"),
+    (0x1D679, "J"),
+    (0x1D67A, "K"),
+    (0x1D67B, "L"),
+    (0x1D67C, "M"),
+    (0x1D67D, "N"),
+    (0x1D67E, "O"),
+    (0x1D67F, "P"),
+    (0x1D680, "Q"),
+    (0x1D681, "R"),
+    (0x1D682, "S"),
+    (0x1D683, "T"),
+    (0x1D684, "U"),
+    (0x1D685, "V"),
+    (0x1D686, "W"),
+    (0x1D687, "X"),
+    (0x1D688, "Y"),
+    (0x1D689, "Z"),
+    (0x1D68A, "a"),
+    (0x1D68B, "b"),
+    (0x1D68C, "c"),
+    (0x1D68D, "d"),
+    (0x1D68E, "e"),
+    (0x1D68F, "f"),
+    (0x1D690, "g"),
+    (0x1D691, "h"),
+    (0x1D692, "i"),
+    (0x1D693, "j"),
+    (0x1D694, "k"),
+    (0x1D695, "l"),
+    (0x1D696, "m"),
+    (0x1D697, "n"),
+    (0x1D698, "o"),
+    (0x1D699, "p"),
+    (0x1D69A, "q"),
+    (0x1D69B, "r"),
+    (0x1D69C, "s"),
+    (0x1D69D, "t"),
+    (0x1D69E, "u"),
+    (0x1D69F, "v"),
+    (0x1D6A0, "w"),
+    (0x1D6A1, "x"),
+    (0x1D6A2, "y"),
+    (0x1D6A3, "z"),
+    (0x1D7CE, "0"),
+    (0x1D7CF, "1"),
+    (0x1D7D0, "2"),
+    (0x1D7D1, "3"),
+    (0x1D7D2, "4"),
+    (0x1D7D3, "5"),
+    (0x1D7D4, "6"),
+    (0x1D7D5, "7"),
+    (0x1D7D6, "8"),
+    (0x1D7D7, "9"),
+    (0x1D7D8, "0"),
+    (0x1D7D9, "1"),
+    (0x1D7DA, "2"),
+    (0x1D7DB, "3"),
+    (0x1D7DC, "4"),
+    (0x1D7DD, "5"),
+    (0x1D7DE, "6"),
+    (0x1D7DF, "7"),
+    (0x1D7E0, "8"),
+    (0x1D7E1, "9"),
+    (0x1D7E2, "0"),
+    (0x1D7E3, "1"),
+    (0x1D7E4, "2"),
+    (0x1D7E5, "3"),
+    (0x1D7E6, "4"),
+    (0x1D7E7, "5"),
+    (0x1D7E8, "6"),
+    (0x1D7E9, "7"),
+    (0x1D7EA, "8"),
+    (0x1D7EB, "9"),
+    (0x1D7EC, "0"),
+    (0x1D7ED, "1"),
+    (0x1D7EE, "2"),
+    (0x1D7EF, "3"),
+    (0x1D7F0, "4"),
+    (0x1D7F1, "5"),
+    (0x1D7F2, "6"),
+    (0x1D7F3, "7"),
+    (0x1D7F4, "8"),
+    (0x1D7F5, "9"),
+    (0x1D7F6, "0"),
+    (0x1D7F7, "1"),
+    (0x1D7F8, "2"),
+    (0x1D7F9, "3"),
+    (0x1D7FA, "4"),
+    (0x1D7FB, "5"),
+    (0x1D7FC, "6"),
+    (0x1D7FD, "7"),
+    (0x1D7FE, "8"),
+    (0x1D7FF, "9"),
+    (0x1DF01, "g"),
+    (0x1DF03, "k"),
+    (0x1DF06, "y"),
+    (0x1DF08, "r"),
+    (0x1DF09, "t"),
+    (0x1DF0D, "t"),
+    (0x1DF11, "l"),
+    (0x1DF13, "l"),
+    (0x1DF15, "r"),
+    (0x1DF16, "r"),
+    (0x1DF1A, "i"),
+    (0x1DF1B, "o"),
+    (0x1DF1D, "c"),
+    (0x1DF1E, "s"),
+    (0x1F100, "0."),
+    (0x1F101, "0,"),
+    (0x1F102, "1,"),
+    (0x1F103, "2,"),
+    (0x1F104, "3,"),
+    (0x1F105, "4,"),
+    (0x1F106, "5,"),
+    (0x1F107, "6,"),
+    (0x1F108, "7,"),
+    (0x1F109, "8,"),
+    (0x1F10A, "9,"),
+    (0x1F110, "(A)"),
+    (0x1F111, "(B)"),
+    (0x1F112, "(C)"),
+    (0x1F113, "(D)"),
+    (0x1F114, "(E)"),
+    (0x1F115, "(F)"),
+    (0x1F116, "(G)"),
+    (0x1F117, "(H)"),
+    (0x1F118, "(I)"),
+    (0x1F119, "(J)"),
+    (0x1F11A, "(K)"),
+    (0x1F11B, "(L)"),
+    (0x1F11C, "(M)"),
+    (0x1F11D, "(N)"),
+    (0x1F11E, "(O)"),
+    (0x1F11F, "(P)"),
+    (0x1F120, "(Q)"),
+    (0x1F121, "(R)"),
+    (0x1F122, "(S)"),
+    (0x1F123, "(T)"),
+    (0x1F124, "(U)"),
+    (0x1F125, "(V)"),
+    (0x1F126, "(W)"),
+    (0x1F127, "(X)"),
+    (0x1F128, "(Y)"),
+    (0x1F129, "(Z)"),
+    (0x1F12B, "C"),
+    (0x1F12C, "R"),
+    (0x1F12D, "CD"),
+    (0x1F12E, "WZ"),
+    (0x1F130, "A"),
+    (0x1F131, "B"),
+    (0x1F132, "C"),
+    (0x1F133, "D"),
+    (0x1F134, "E"),
+    (0x1F135, "F"),
+    (0x1F136, "G"),
+    (0x1F137, "H"),
+    (0x1F138, "I"),
+    (0x1F139, "J"),
+    (0x1F13A, "K"),
+    (0x1F13B, "L"),
+    (0x1F13C, "M"),
+    (0x1F13D, "N"),
+    (0x1F13E, "O"),
+    (0x1F13F, "P"),
+    (0x1F140, "Q"),
+    (0x1F141, "R"),
+    (0x1F142, "S"),
+    (0x1F143, "T"),
+    (0x1F144, "U"),
+    (0x1F145, "V"),
+    (0x1F146, "W"),
+    (0x1F147, "X"),
+    (0x1F148, "Y"),
+    (0x1F149, "Z"),
+    (0x1F14A, "HV"),
+    (0x1F14B, "MV"),
+    (0x1F14C, "SD"),
+    (0x1F14D, "SS"),
+    (0x1F14E, "PPV"),
+    (0x1F14F, "WC"),
+    (0x1F16A, "MC"),
+    (0x1F16B, "MD"),
+    (0x1F16C, "MR"),
+    (0x1F190, "DJ"),
+    (0x1FBF0, "0"),
+    (0x1FBF1, "1"),
+    (0x1FBF2, "2"),
+    (0x1FBF3, "3"),
+    (0x1FBF4, "4"),
+    (0x1FBF5, "5"),
+    (0x1FBF6, "6"),
+    (0x1FBF7, "7"),
+    (0x1FBF8, "8"),
+    (0x1FBF9, "9"),
+];
@@ -0,0 +1,127 @@
+//! Reports what `decode_with` does, for diagnosing which inputs trigger errors and fallbacks.
+//!
+//! This module is only available with the `trace` feature. While a hook is installed on a thread,
+//! `decode_with` calls it with a `TraceEvent` for every escape sequence it decodes, for the error
+//! it fails with, and for every decision that changes the decoded text beyond the escape
+//! sequences themselves, such as replacing a noncharacter. The other functions parsing escape
+//! sequences with the decoder, such as `decode_with_map` and `lint`, report their escape
+//! sequences and errors as well, without the `Started` and `Finished` events. Without the
+//! feature, no events are produced and decoding runs at full speed.
+//!
+//! The hook is a plain function, so events can be forwarded to any logging framework, e.g. to
+//! the `tracing` crate:
+//!
+//! ```ignore
+//! fn forward(event: &TraceEvent) {
+//!     tracing::debug!(target: "unicode_escape", ?event);
+//! }
+//!
+//! let decoded = trace::with_hook(forward, || decode_with(input, &options));
+//! ```
+use std::cell::Cell;
+use std::ops::Range;
+
+use crate::{DecodeError, EscapeKind};
+
+/// A function receiving the events of `decode_with`.
+pub type TraceHook = fn(&TraceEvent);
+
+/// A decision of `decode_with` that changes the decoded text beyond decoding escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Fallback {
+    /// An escape sequence produced the given noncharacter, which was replaced with U+FFFD.
+    NoncharacterReplaced(char),
+    /// An escape sequence produced the given surrogate code point, which is not part of a
+    /// surrogate pair and was replaced with U+FFFD.
+    SurrogateReplaced(u32),
+    /// The byte order mark at the start of the decoded string was removed.
+    ByteOrderMarkStripped,
+    /// The error reported by the preceding `Error` event was replaced by the replacement of
+    /// `decode_lossy_with`, and decoding continued.
+    ErrorReplaced,
+}
+
+/// Something `decode_with` did while decoding a string.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TraceEvent<'a> {
+    /// Decoding of `input` started.
+    Started {
+        /// The escaped input.
+        input: &'a str,
+    },
+    /// An escape sequence was decoded.
+    Escape {
+        /// The family of the escape sequence.
+        kind: EscapeKind,
+        /// The byte range of the escape sequence in the input.
+        range: Range<usize>,
+        /// The character the escape sequence decoded to.
+        value: char,
+    },
+    /// Decoding failed.
+    Error {
+        /// The byte range of the invalid escape sequence in the input, or an empty range at the
+        /// start for errors concerning the whole string, such as `ErrorKind::ByteOrderMark`.
+        range: Range<usize>,
+        /// The error `decode_with` returns.
+        error: &'a DecodeError,
+    },
+    /// The decoded text was changed by a fallback. Fallbacks affecting an escape sequence are
+    /// reported before the `Escape` event of the escape sequence.
+    Fallback(Fallback),
+    /// Decoding finished successfully.
+    Finished {
+        /// The length of the decoded string in bytes.
+        output_len: usize,
+    },
+}
+
+thread_local! {
+    static HOOK: Cell<Option<TraceHook>> = const { Cell::new(None) };
+}
+
+/// Installs a hook receiving the events of `decode_with` on the current thread.
+///
+/// # Parameters
+///
+/// * `hook`: The new hook, or `None` to stop tracing.
+///
+/// # Returns
+///
+/// The previously installed hook, if any.
+pub fn set_hook(hook: Option<TraceHook>) -> Option<TraceHook> {
+    HOOK.with(|current| current.replace(hook))
+}
+
+/// Runs a function with a hook installed on the current thread, restoring the previous hook
+/// afterwards, even if the function panics.
+///
+/// # Parameters
+///
+/// * `hook`: The hook receiving the events of `decode_with` while `f` runs.
+/// * `f`: The function to run.
+///
+/// # Returns
+///
+/// The result of `f`.
+pub fn with_hook<R>(hook: TraceHook, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<TraceHook>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_hook(self.0);
+        }
+    }
+
+    let _restore = Restore(set_hook(Some(hook)));
+    f()
+}
+
+/// Passes an event to the hook of the current thread, if any.
+pub(crate) fn emit(event: &TraceEvent) {
+    if let Some(hook) = HOOK.with(Cell::get) {
+        hook(event);
+    }
+}
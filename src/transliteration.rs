@@ -0,0 +1,28 @@
+//! Transliterates characters into ASCII.
+//!
+//! This module is only available with the `transliteration` feature, which embeds a table of the
+//! closest ASCII spelling of Latin, Greek and Cyrillic letters, compatibility characters and common
+//! punctuation, e.g. `e` for `é`, `ss` for `ß` and `Zh` for `Ж`. The feature also enables
+//! `EncodeOptions::transliterate`, for generating identifiers and file names from display strings.
+//! The table is derived from the Unicode Character Database by `scripts/generate_tables.py`.
+
+include!("tables/transliteration.rs");
+
+/// Returns the closest ASCII spelling of a non-ASCII character.
+///
+/// # Parameters
+///
+/// * `c`: The character to transliterate.
+///
+/// # Returns
+///
+/// The ASCII spelling, which may be empty or longer than one character, e.g. `ae` for `æ`. `None`
+/// is returned for ASCII characters, which need no transliteration, and for characters without a
+/// known spelling, such as CJK ideographs and emoji.
+pub fn transliterate(c: char) -> Option<&'static str> {
+    let code = u32::from(c);
+    TRANSLITERATION
+        .binary_search_by_key(&code, |&(code, _)| code)
+        .ok()
+        .map(|index| TRANSLITERATION[index].1)
+}
@@ -0,0 +1,83 @@
+//! Checks strings for invalid escape sequences without decoding them.
+//!
+//! This module contains the `validate` and `validate_all` functions, which walk the input without
+//! constructing any output, for callers that only need to know whether a string is well-formed.
+use crate::scan::scan_escape;
+use crate::{DecodeError, SpannedError};
+
+/// Checks that every escape sequence in a string is valid.
+///
+/// This function accepts exactly the inputs `decode` accepts, but does not allocate.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// `Ok(())` if the input can be decoded, or the first error otherwise.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode`.
+pub fn validate(input: &str) -> Result<(), DecodeError> {
+    let mut pos = 0;
+    while let Some(offset) = input[pos..].find('\\') {
+        let (range, result) = scan_escape(input, pos + offset);
+        result?;
+        pos = range.end;
+    }
+    Ok(())
+}
+
+/// Checks that every escape sequence in a string is valid like `validate`, reporting where the
+/// first invalid one is.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// `Ok(())` if the input can be decoded, or the first error together with its position otherwise.
+///
+/// # Errors
+///
+/// This function returns the same errors as `decode_spanned` with the default options.
+pub fn validate_spanned(input: &str) -> Result<(), SpannedError> {
+    let mut pos = 0;
+    while let Some(offset) = input[pos..].find('\\') {
+        let (span, result) = scan_escape(input, pos + offset);
+        if let Err(error) = result {
+            return Err(SpannedError::new(error, input, span));
+        }
+        pos = span.end;
+    }
+    Ok(())
+}
+
+/// Finds every invalid escape sequence in a string.
+///
+/// Unlike `validate`, this function does not stop at the first error, so that all problems in the
+/// input can be reported at once.
+///
+/// # Parameters
+///
+/// * &str: A string slice or raw string slice
+///
+/// # Returns
+///
+/// The error of every invalid escape sequence, in input order, each spanning the escape sequence.
+/// The vector is empty if the input can be decoded.
+pub fn validate_all(input: &str) -> Vec<DecodeError> {
+    let mut errors = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = input[pos..].find('\\') {
+        let (range, result) = scan_escape(input, pos + offset);
+        pos = range.end;
+        if let Err(error) = result {
+            errors.push(error);
+        }
+    }
+    errors
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "unicode-age")]
+
+use unicode_escape::age::{age, is_assigned, UnicodeVersion};
+use unicode_escape::{decode_with, DecodeOptions, ErrorKind};
+
+#[test]
+fn test_age() {
+    let cases = vec![
+        ('A', Some(UnicodeVersion::new(1, 1))),
+        ('\u{20AC}', Some(UnicodeVersion::new(2, 1))),
+        ('\u{1F600}', Some(UnicodeVersion::new(6, 1))),
+        ('\u{1FAE0}', Some(UnicodeVersion::new(14, 0))),
+        ('\u{FFFF}', Some(UnicodeVersion::new(1, 1))),
+        ('\u{E000}', Some(UnicodeVersion::new(1, 1))),
+        ('\u{0378}', None),
+        ('\u{10FFFD}', Some(UnicodeVersion::new(2, 0))),
+    ];
+    for (c, expected) in cases {
+        assert_eq!(age(c), expected, "{:?}", c);
+    }
+
+    assert!(is_assigned('\u{1F600}', UnicodeVersion::new(6, 1)));
+    assert!(!is_assigned('\u{1F600}', UnicodeVersion::new(6, 0)));
+    assert!(!is_assigned('\u{0378}', UnicodeVersion::LATEST));
+    assert_eq!(UnicodeVersion::LATEST.to_string(), "14.0");
+}
+
+#[test]
+fn test_decode_assigned_only() {
+    let options = DecodeOptions::new().assigned_only(Some(UnicodeVersion::new(6, 0)));
+    assert_eq!(decode_with(r"\u{20AC}\x41", &options).unwrap(), "\u{20AC}A");
+    assert_eq!(
+        decode_with(r"\u{1F600}", &options).unwrap_err().kind(),
+        ErrorKind::Unassigned
+    );
+    assert_eq!(
+        decode_with(r"\u{378}", &options).unwrap_err().kind(),
+        ErrorKind::Unassigned
+    );
+    assert_eq!(decode_with("\u{1F600}", &options).unwrap(), "\u{1F600}");
+    assert!(decode_with(r"\u{1F600}", &DecodeOptions::new()).is_ok());
+}
@@ -0,0 +1,65 @@
+use unicode_escape::{ansi_tokens, contains_ansi, decode, strip_ansi, AnsiKind, AnsiToken};
+
+#[test]
+fn test_strip_ansi() {
+    let cases = vec![
+        ("plain", "plain"),
+        ("\x1b[1;32mOK\x1b[0m done", "OK done"),
+        ("\x1b]0;title\x07prompt", "prompt"),
+        ("\x1b]8;;http://example.com\x1b\\link\x1b]8;;\x1b\\", "link"),
+        ("\x1b7saved\x1b8 \x1b(Bascii", "saved ascii"),
+        ("\u{9B}31mred\u{9B}m", "red"),
+        ("\x1bPq#0\x1b\\image", "image"),
+        ("cut \x1b[1;3", "cut "),
+        ("lone \x1b\n", "lone \n"),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(strip_ansi(text), expected, "{text:?}");
+        assert_eq!(contains_ansi(text), text != expected, "{text:?}");
+    }
+}
+
+#[test]
+fn test_ansi_tokens() {
+    let text = decode(r"\x1b[1;32mOK\x1b[2J\x1b]0;log\x07!").unwrap();
+    let tokens: Vec<AnsiToken> = ansi_tokens(&text).collect();
+    assert_eq!(tokens.len(), 5);
+    let AnsiToken::Sequence(sgr) = &tokens[0] else {
+        panic!("expected a sequence");
+    };
+    assert_eq!(sgr.kind, AnsiKind::Sgr);
+    assert_eq!(sgr.range, 0..7);
+    assert_eq!(sgr.parameters(), vec![1, 32]);
+    assert_eq!(tokens[1], AnsiToken::Text("OK"));
+    let AnsiToken::Sequence(clear) = &tokens[2] else {
+        panic!("expected a sequence");
+    };
+    assert_eq!(clear.kind, AnsiKind::Csi);
+    assert_eq!(clear.source, "\x1b[2J");
+    let AnsiToken::Sequence(osc) = &tokens[3] else {
+        panic!("expected a sequence");
+    };
+    assert_eq!(osc.kind, AnsiKind::Osc);
+    assert_eq!(osc.payload(), "0;log");
+    assert_eq!(tokens[4], AnsiToken::Text("!"));
+
+    let pieces: String = ansi_tokens(&text)
+        .map(|token| match token {
+            AnsiToken::Text(text) => text,
+            AnsiToken::Sequence(sequence) => sequence.source,
+        })
+        .collect();
+    assert_eq!(pieces, text);
+}
+
+#[test]
+fn test_ansi_parameters() {
+    let parameters = |text: &str| match ansi_tokens(text).next() {
+        Some(AnsiToken::Sequence(sequence)) => sequence.parameters(),
+        _ => panic!("expected a sequence"),
+    };
+    assert_eq!(parameters("\x1b[m"), vec![0]);
+    assert_eq!(parameters("\x1b[38:5:196m"), vec![38, 5, 196]);
+    assert_eq!(parameters("\x1b[;99999H"), vec![0, 65535]);
+    assert_eq!(parameters("\x1b]0;title\x07"), Vec::<u16>::new());
+}
@@ -0,0 +1,56 @@
+use std::io;
+
+use unicode_escape::{decode_bytes, decode_bytes_to, DecodeError, ErrorKind};
+
+#[test]
+fn test_decode_bytes() {
+    assert_eq!(
+        decode_bytes(r"\x02 65480\r\n").unwrap(),
+        b"\x02 65480\r\n".to_vec()
+    );
+    assert_eq!(
+        decode_bytes(r"\xFF\x80\x00").unwrap(),
+        vec![0xFF, 0x80, 0x00]
+    );
+    assert_eq!(decode_bytes(r"\u{FF}").unwrap(), vec![0xC3, 0xBF]);
+    assert_eq!(decode_bytes("é\\t").unwrap(), vec![0xC3, 0xA9, b'\t']);
+    assert_eq!(decode_bytes(r"\u{1F600}").unwrap(), "\u{1F600}".as_bytes());
+    assert!(decode_bytes("").unwrap().is_empty());
+}
+
+#[test]
+fn test_decode_bytes_errors() {
+    assert_eq!(
+        decode_bytes(r"\xG0").unwrap_err().kind(),
+        ErrorKind::InvalidHexDigit('G')
+    );
+    assert_eq!(
+        decode_bytes(r"ok \q").unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+}
+
+#[test]
+fn test_decode_bytes_to() {
+    let mut out = Vec::new();
+    decode_bytes_to(r"\x02 65480\r\n\xFF", &mut out).unwrap();
+    assert_eq!(out, b"\x02 65480\r\n\xFF".to_vec());
+
+    // Large payloads are written in several chunks with the same result
+    let input = format!("{}{}", r"\xFF\u{E9} 65480".repeat(2000), "x".repeat(20000));
+    let mut out = Vec::new();
+    decode_bytes_to(&input, &mut out).unwrap();
+    assert_eq!(out, decode_bytes(&input).unwrap());
+
+    let mut out = Vec::new();
+    let error = decode_bytes_to(r"ok \q", &mut out).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(matches!(
+        error
+            .into_inner()
+            .unwrap()
+            .downcast_ref::<DecodeError>()
+            .map(DecodeError::kind),
+        Some(ErrorKind::UnknownEscape(_))
+    ));
+}
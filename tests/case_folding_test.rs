@@ -0,0 +1,37 @@
+use unicode_escape::{decode_with, fold_case, CaseFolding, DecodeOptions};
+
+#[test]
+fn test_fold_case() {
+    // (input, simple folding, full folding)
+    let cases = vec![
+        ("Hello", "hello", "hello"),
+        (
+            "STRASSE stra\u{DF}e",
+            "strasse stra\u{DF}e",
+            "strasse strasse",
+        ),
+        ("\u{1E9E}", "\u{DF}", "ss"),
+        ("\u{130}I", "\u{130}i", "i\u{307}i"),
+        ("\u{3A3}\u{3C2}", "\u{3C3}\u{3C3}", "\u{3C3}\u{3C3}"),
+        ("\u{FB01}", "\u{FB01}", "fi"),
+        ("\u{10400}", "\u{10428}", "\u{10428}"),
+        ("", "", ""),
+    ];
+    for (input, simple, full) in cases {
+        assert_eq!(fold_case(input, CaseFolding::Simple), simple);
+        assert_eq!(fold_case(input, CaseFolding::Full), full);
+    }
+}
+
+#[test]
+fn test_decode_case_folded() {
+    let options = DecodeOptions::new().case_folding(Some(CaseFolding::Full));
+    assert_eq!(
+        decode_with(r"Stra\u{DF}e\tX", &options).unwrap(),
+        "strasse\tx"
+    );
+    assert_eq!(
+        decode_with(r"\u{DF}", &DecodeOptions::new()).unwrap(),
+        "\u{DF}"
+    );
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "unicode-categories")]
+
+use unicode_escape::category::{general_category, CategorySet, GeneralCategory};
+use unicode_escape::{decode, encode_with, EncodeOptions};
+
+#[test]
+fn test_general_category() {
+    let cases = vec![
+        ('A', GeneralCategory::Lu),
+        ('\u{E9}', GeneralCategory::Ll),
+        ('\t', GeneralCategory::Cc),
+        ('\u{200B}', GeneralCategory::Cf),
+        ('\u{2028}', GeneralCategory::Zl),
+        ('\u{4E00}', GeneralCategory::Lo),
+        ('\u{E000}', GeneralCategory::Co),
+        ('\u{0378}', GeneralCategory::Cn),
+    ];
+    for (c, expected) in cases {
+        assert_eq!(general_category(c), expected, "{:?}", c);
+    }
+
+    let set = CategorySet::parse(r"Cc, Cf \p{Zl}").unwrap();
+    assert!(set.contains(GeneralCategory::Zl));
+    assert!(!set.contains(GeneralCategory::Zp));
+    assert_eq!(CategorySet::parse("Z"), CategorySet::parse("Zs Zl Zp"));
+    assert!(CategorySet::parse("Cx").is_none());
+    assert!(CategorySet::parse("").unwrap().is_empty());
+}
+
+#[test]
+fn test_encode_categories() {
+    let options = EncodeOptions::new().escape_categories(CategorySet::parse("Cf Zs Sc").unwrap());
+    let cases = vec![
+        ("a\u{2061}b", r"a\u{2061}b"),
+        ("1 000\u{A0}\u{20AC}", r"1\x20000\u{A0}\u{20AC}"),
+        ("$5\n", r"\x245\n"),
+        ("caf\u{E9}", "caf\u{E9}"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(encode_with(input, &options), expected);
+        assert_eq!(decode(expected).unwrap(), input);
+    }
+}
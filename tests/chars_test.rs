@@ -0,0 +1,32 @@
+use unicode_escape::{decode, decode_chars, ErrorKind};
+
+#[test]
+fn test_decode_chars() {
+    let chars: Vec<char> = decode_chars(r"a\x41\u{E9}é\t")
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(chars, ['a', 'A', '\u{E9}', 'é', '\t']);
+    assert_eq!(decode_chars("").count(), 0);
+    assert_eq!(decode_chars(r"\u{1F600}\u{D83D}\u{DE00}").count(), 2);
+}
+
+#[test]
+fn test_decode_chars_error() {
+    let mut chars = decode_chars(r"ab\qc\x4");
+    assert!(matches!(chars.next(), Some(Ok('a'))));
+    assert!(matches!(chars.next(), Some(Ok('b'))));
+    assert!(matches!(
+        chars.next().unwrap().unwrap_err().kind(),
+        ErrorKind::UnknownEscape(_)
+    ));
+    assert!(chars.next().is_none());
+    assert!(chars.next().is_none());
+}
+
+#[test]
+fn test_decode_chars_matches_decode() {
+    for input in [r"\x02 65480 LGM\x03\r\n", r"caf\u{E9} \\ \'", r"\u{110000}"] {
+        let lazy: Result<String, _> = decode_chars(input).collect();
+        assert_eq!(format!("{lazy:?}"), format!("{:?}", decode(input)));
+    }
+}
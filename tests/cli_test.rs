@@ -0,0 +1,64 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Runs the command line tool with `args`, writing `input` to its standard input.
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_unicode-escape"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_cli() {
+    let output = run(&["decode"], r"a\tb\u{e9}");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, "a\tb\u{e9}".as_bytes());
+
+    let output = run(&["decode", "--dialect", "json"], r"😀\/");
+    assert_eq!(output.stdout, "\u{1F600}/".as_bytes());
+
+    let output = run(&["decode"], r"ok\q");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("-: error: "), "{stderr}");
+
+    let output = run(&["decode", "--errors", "replace"], r"ok\q");
+    assert_eq!(output.stdout, "ok\u{FFFD}".as_bytes());
+    let output = run(&["decode", "--errors", "preserve"], r"ok\q");
+    assert_eq!(output.stdout, br"ok\q");
+
+    let output = run(&["encode"], "a\tb\u{e9}");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, "a\\tb\u{e9}".as_bytes());
+
+    let output = run(&["decode", "--format", "json"], "a\\q\"\\x4");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        concat!(
+            r#"{"file":"-","offset":1,"end":3,"snippet":"\\q","kind":"UnknownEscape","#,
+            r#""message":"invalid escape '\\q'"}"#,
+            "\n",
+            r#"{"file":"-","offset":4,"end":7,"snippet":"\\x4","kind":"UnexpectedEof","#,
+            r#""message":"unexpected end of input in escape sequence"}"#,
+            "\n",
+        )
+    );
+
+    let output = run(&["decode", "--dialect", "cobol"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
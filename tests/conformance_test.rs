@@ -0,0 +1,326 @@
+use std::collections::BTreeMap;
+
+use unicode_escape::{
+    decode_with, BomPolicy, DecodeOptions, Dialect, LineContinuation, NoncharacterPolicy,
+    OctalEscapes,
+};
+
+/// The conformance test vectors, described in `tests/data/CONFORMANCE.md`.
+const VECTORS: &str = include_str!("data/conformance.json");
+
+/// A parsed JSON value.
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.get(key),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Json::String(s) => s,
+            _ => panic!("expected a string, found {self:?}"),
+        }
+    }
+
+    fn as_bool(&self) -> bool {
+        match self {
+            Json::Bool(b) => *b,
+            _ => panic!("expected a boolean, found {self:?}"),
+        }
+    }
+}
+
+/// A minimal JSON parser, sufficient for the test vectors.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn parse(input: &str) -> Json {
+        let mut parser = Parser { input, pos: 0 };
+        let value = parser.value();
+        parser.whitespace();
+        assert_eq!(parser.pos, input.len(), "trailing characters");
+        value
+    }
+
+    fn whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, token: &str) {
+        self.whitespace();
+        assert!(
+            self.input[self.pos..].starts_with(token),
+            "expected {token:?} at byte {}",
+            self.pos
+        );
+        self.pos += token.len();
+    }
+
+    fn peek(&mut self) -> char {
+        self.whitespace();
+        self.input[self.pos..]
+            .chars()
+            .next()
+            .expect("unexpected end")
+    }
+
+    fn value(&mut self) -> Json {
+        match self.peek() {
+            'n' => {
+                self.expect("null");
+                Json::Null
+            }
+            't' => {
+                self.expect("true");
+                Json::Bool(true)
+            }
+            'f' => {
+                self.expect("false");
+                Json::Bool(false)
+            }
+            '"' => Json::String(self.string()),
+            '[' => {
+                self.expect("[");
+                let mut items = Vec::new();
+                while self.peek() != ']' {
+                    if !items.is_empty() {
+                        self.expect(",");
+                    }
+                    items.push(self.value());
+                }
+                self.expect("]");
+                Json::Array(items)
+            }
+            '{' => {
+                self.expect("{");
+                let mut members = BTreeMap::new();
+                while self.peek() != '}' {
+                    if !members.is_empty() {
+                        self.expect(",");
+                    }
+                    self.whitespace();
+                    let key = self.string();
+                    self.expect(":");
+                    members.insert(key, self.value());
+                }
+                self.expect("}");
+                Json::Object(members)
+            }
+            _ => {
+                let rest = &self.input[self.pos..];
+                let len = rest
+                    .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+                    .unwrap_or(rest.len());
+                self.pos += len;
+                Json::Number(rest[..len].parse().expect("invalid number"))
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> u32 {
+        let digits = &self.input[self.pos..self.pos + 4];
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).expect("invalid escape")
+    }
+
+    fn string(&mut self) -> String {
+        self.expect("\"");
+        let mut result = String::new();
+        loop {
+            let c = self.input[self.pos..]
+                .chars()
+                .next()
+                .expect("unterminated string");
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return result,
+                '\\' => {
+                    let escape = self.input.as_bytes()[self.pos];
+                    self.pos += 1;
+                    match escape {
+                        b'"' | b'\\' | b'/' => result.push(char::from(escape)),
+                        b'b' => result.push('\u{8}'),
+                        b'f' => result.push('\u{C}'),
+                        b'n' => result.push('\n'),
+                        b'r' => result.push('\r'),
+                        b't' => result.push('\t'),
+                        b'u' => {
+                            let mut code = self.hex4();
+                            if (0xD800..0xDC00).contains(&code) {
+                                self.expect("\\u");
+                                code = 0x10000 + ((code - 0xD800) << 10) + (self.hex4() - 0xDC00);
+                            }
+                            result.push(char::from_u32(code).expect("invalid code point"));
+                        }
+                        _ => panic!("invalid escape at byte {}", self.pos),
+                    }
+                }
+                _ => result.push(c),
+            }
+        }
+    }
+}
+
+/// Builds the options of a vector from its dialect and option overrides.
+fn options(vector: &Json) -> DecodeOptions {
+    let mut options = match vector.get("dialect").map_or("default", Json::as_str) {
+        "default" => DecodeOptions::new(),
+        "rustc" => DecodeOptions::rustc_compat(),
+        "json" => DecodeOptions::dialect(Dialect::Json),
+        "python" => DecodeOptions::dialect(Dialect::Python),
+        "c" => DecodeOptions::dialect(Dialect::C),
+        "rust" => DecodeOptions::dialect(Dialect::Rust),
+        "toml" => DecodeOptions::dialect(Dialect::Toml),
+        "shell" => DecodeOptions::dialect(Dialect::Shell),
+        "javascript" => DecodeOptions::dialect(Dialect::JavaScript),
+        "go" => DecodeOptions::dialect(Dialect::Go),
+        "java" => DecodeOptions::dialect(Dialect::Java),
+        "csharp" => DecodeOptions::dialect(Dialect::CSharp),
+        "lua" => DecodeOptions::dialect(Dialect::Lua),
+        "postgresql" => DecodeOptions::dialect(Dialect::PostgreSql),
+        "csv" => DecodeOptions::dialect(Dialect::Csv),
+        "mysql" => DecodeOptions::dialect(Dialect::MySql),
+        dialect => panic!("unknown dialect {dialect:?}"),
+    };
+    let Some(Json::Object(overrides)) = vector.get("options") else {
+        return options;
+    };
+    for (name, value) in overrides {
+        options = match name.as_str() {
+            "backslash_escapes" => options.backslash_escapes(value.as_bool()),
+            "simple_escapes" => options.simple_escapes(value.as_bool()),
+            "hex_escapes" => options.hex_escapes(value.as_bool()),
+            "unicode_escapes" => options.unicode_escapes(value.as_bool()),
+            "braced_unicode" => options.braced_unicode(value.as_bool()),
+            "fixed_unicode" => options.fixed_unicode(value.as_bool()),
+            "long_unicode" => options.long_unicode(value.as_bool()),
+            "short_unicode" => options.short_unicode(value.as_bool()),
+            "unicode_prepass" => options.unicode_prepass(value.as_bool()),
+            "control_escapes" => options.control_escapes(value.as_bool()),
+            "decimal_escapes" => options.decimal_escapes(value.as_bool()),
+            "skip_whitespace" => options.skip_whitespace(value.as_bool()),
+            "octal_zero" => options.octal_zero(value.as_bool()),
+            "combine_surrogates" => options.combine_surrogates(value.as_bool()),
+            "replace_lone_surrogates" => options.replace_lone_surrogates(value.as_bool()),
+            "digit_separators" => options.digit_separators(value.as_bool()),
+            "code_point_groups" => options.code_point_groups(value.as_bool()),
+            "ascii_hex" => options.ascii_hex(value.as_bool()),
+            "message_format" => options.message_format(value.as_bool()),
+            "max_unicode_digits" => options.max_unicode_digits(match value {
+                Json::Number(n) => Some(*n as usize),
+                _ => None,
+            }),
+            "hex_digits" => match value {
+                Json::Array(range) => match range.as_slice() {
+                    [Json::Number(min), Json::Number(max)] => {
+                        options.hex_digits(*min as usize, Some(*max as usize))
+                    }
+                    [Json::Number(min), Json::Null] => options.hex_digits(*min as usize, None),
+                    _ => panic!("invalid hex_digits {value:?}"),
+                },
+                _ => panic!("invalid hex_digits {value:?}"),
+            },
+            "octal_escapes" => options.octal_escapes(match value {
+                Json::String(octal) => match octal.as_str() {
+                    "up_to_three" => Some(OctalEscapes::UpToThree),
+                    "up_to_byte" => Some(OctalEscapes::UpToByte),
+                    "exactly_three" => Some(OctalEscapes::ExactlyThree),
+                    octal => panic!("unknown octal escapes {octal:?}"),
+                },
+                _ => None,
+            }),
+            "line_continuation" => options.line_continuation(match value {
+                Json::String(continuation) => match continuation.as_str() {
+                    "remove" => Some(LineContinuation::Remove),
+                    "remove_unicode" => Some(LineContinuation::RemoveUnicode),
+                    "trim_whitespace" => Some(LineContinuation::TrimWhitespace),
+                    "trim_all_whitespace" => Some(LineContinuation::TrimAllWhitespace),
+                    "newline" => Some(LineContinuation::Newline),
+                    continuation => panic!("unknown line continuation {continuation:?}"),
+                },
+                _ => None,
+            }),
+            "doubled_quote" => options.doubled_quote(match value {
+                Json::String(quote) => quote.chars().next(),
+                _ => None,
+            }),
+            "noncharacters" => options.noncharacters(match value.as_str() {
+                "allow" => NoncharacterPolicy::Allow,
+                "reject" => NoncharacterPolicy::Reject,
+                "replace" => NoncharacterPolicy::Replace,
+                policy => panic!("unknown noncharacter policy {policy:?}"),
+            }),
+            "bom" => options.bom(match value.as_str() {
+                "preserve" => BomPolicy::Preserve,
+                "strip" => BomPolicy::Strip,
+                "reject" => BomPolicy::Reject,
+                policy => panic!("unknown byte order mark policy {policy:?}"),
+            }),
+            _ => panic!("unknown option {name:?}"),
+        };
+    }
+    options
+}
+
+#[test]
+fn test_conformance_vectors() {
+    let document = Parser::parse(VECTORS);
+    assert_eq!(document.get("version"), Some(&Json::Number(1.0)));
+    let Some(Json::Array(vectors)) = document.get("vectors") else {
+        panic!("missing vectors");
+    };
+    assert!(!vectors.is_empty());
+
+    for vector in vectors {
+        let description = vector.get("description").map_or("", Json::as_str);
+        let input = vector.get("input").expect("missing input").as_str();
+        let result = decode_with(input, &options(vector));
+        match (vector.get("output"), vector.get("error")) {
+            (Some(output), None) => {
+                assert_eq!(
+                    result.ok().as_deref(),
+                    Some(output.as_str()),
+                    "{description}"
+                )
+            }
+            (None, Some(error)) => match result {
+                Ok(decoded) => panic!("{description}: decoded to {decoded:?}"),
+                Err(actual) => {
+                    assert_eq!(actual.kind().code(), error.as_str(), "{description}")
+                }
+            },
+            _ => panic!("{description}: expected exactly one of output and error"),
+        }
+    }
+}
+
+#[test]
+fn test_json_parser() {
+    let value = Parser::parse(r#" {"a": [1, -2.5e1, true, null], "b": "x\"\u00e9\ud83d\ude00"} "#);
+    assert_eq!(
+        value.get("a"),
+        Some(&Json::Array(vec![
+            Json::Number(1.0),
+            Json::Number(-25.0),
+            Json::Bool(true),
+            Json::Null
+        ]))
+    );
+    assert_eq!(value.get("b").unwrap().as_str(), "x\"\u{E9}\u{1F600}");
+}
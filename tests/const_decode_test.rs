@@ -0,0 +1,67 @@
+use unicode_escape::{decode, ConstDecoded, ErrorKind};
+
+const PAYLOAD: ConstDecoded<16> = ConstDecoded::new(r"AT+SEND=\x02\u{B0}C\r\n");
+
+#[test]
+fn test_const_decoded() {
+    assert_eq!(PAYLOAD.as_str(), "AT+SEND=\x02\u{B0}C\r\n");
+    assert_eq!(PAYLOAD.len(), 14);
+    assert_eq!(PAYLOAD.as_bytes(), PAYLOAD.as_str().as_bytes());
+    assert!(ConstDecoded::<0>::new("").is_empty());
+
+    let error = ConstDecoded::<4>::try_new(r"abc\u{E9}").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall);
+    assert_eq!(error.span(), Some(3..9));
+}
+
+#[test]
+#[should_panic(expected = "invalid escape")]
+fn test_const_decoded_invalid() {
+    ConstDecoded::<8>::new(r"\q");
+}
+
+#[test]
+fn test_const_decoded_matches_decode() {
+    let cases = [
+        "plain é ✓ 😀",
+        r#"\t\n\r\0\\\"\'"#,
+        r"\x41\xff\x7F",
+        r"\u{41}\u{E9}\u{1F600}\u{0000041}\u{10FFFF}",
+        r"\u{D83D}\u{DE00}",
+        r"\u{D83D}x",
+        r"\u{D83D}\u{41}",
+        r"\u{D83D}\u{ZZ}",
+        r"\u{DE00}",
+        r"\u{}",
+        r"\u{41",
+        r"\u{41 42}",
+        r"\u41",
+        r"\u",
+        r"\u{110000}",
+        r"\u{FFFFFFFFFF}",
+        r"\u{FFFFFFFFF\",
+        r"\u{FFFFFFFFF",
+        r"z\u{DE00DE005c\u{",
+        r"\u{4_1}",
+        r"\x4",
+        r"\xG0",
+        r"\xé",
+        r"\é",
+        r"\q",
+        r"ok \",
+    ];
+    for case in cases {
+        let expected = decode(case).map_err(|error| (error.kind(), error.span()));
+        let actual = ConstDecoded::<64>::try_new(case)
+            .map(|decoded| decoded.as_str().to_string())
+            .map_err(|error| (error.kind(), error.span()));
+        assert_eq!(actual, expected, "{case:?}");
+    }
+}
+
+#[test]
+fn test_decode_macro() {
+    const SEPARATOR: &str = unicode_escape::decode!(r"\u{2029}\t");
+    assert_eq!(SEPARATOR, "\u{2029}\t");
+    assert_eq!(unicode_escape::decode!("plain"), "plain");
+}
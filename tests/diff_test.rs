@@ -0,0 +1,42 @@
+use unicode_escape::{decode_diff, DiffSegment, EscapeKind};
+
+#[test]
+fn test_decode_diff() {
+    let diff = decode_diff(r"\x02 65480\r\q");
+    assert!(!diff.is_valid());
+    let segments = diff.segments();
+    assert_eq!(segments.len(), 4);
+    assert!(matches!(
+        segments[0],
+        DiffSegment::Escape {
+            source: r"\x02",
+            kind: EscapeKind::Hex,
+            decoded: '\x02'
+        }
+    ));
+    assert!(matches!(segments[1], DiffSegment::Literal(" 65480")));
+    assert!(matches!(
+        segments[2],
+        DiffSegment::Escape {
+            source: r"\r",
+            kind: EscapeKind::Simple,
+            decoded: '\r'
+        }
+    ));
+    assert!(matches!(
+        segments[3],
+        DiffSegment::Invalid { source: r"\q", .. }
+    ));
+}
+
+#[test]
+fn test_decode_diff_display() {
+    let diff = decode_diff(r"LGM\u{21B5}\n");
+    assert!(diff.is_valid());
+    let expected = concat!(
+        "literal  \"LGM\"\n",
+        "unicode  \\u{21B5} -> '↵'\n",
+        "simple   \\n -> '\\n'\n",
+    );
+    assert_eq!(diff.to_string(), expected);
+}
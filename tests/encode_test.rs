@@ -0,0 +1,100 @@
+use unicode_escape::{
+    decode, encode, encode_canonical, encode_with, EncodeOptions, Escaped,
+    CANONICAL_ENCODING_VERSION,
+};
+
+#[test]
+fn test_encode() {
+    let mut cases = Vec::new();
+    cases.push(("\x02 65480 LGM\r\n", r"\x02 65480 LGM\r\n"));
+    cases.push(("tab\there", r"tab\there"));
+    cases.push((r#"say "hi" \ 'bye'"#, r#"say \"hi\" \\ \'bye\'"#));
+    cases.push(("caf\u{e9} \u{21B5}", "caf\u{e9} \u{21B5}"));
+    cases.push((
+        "\u{7f}\u{85}\u{200B}\u{2028}\u{FEFF}",
+        r"\x7F\u{85}\u{200B}\u{2028}\u{FEFF}",
+    ));
+    cases.push(("", ""));
+
+    for case in cases {
+        assert_eq!(encode(case.0), case.1);
+        assert_eq!(decode(&encode(case.0)).unwrap(), case.0);
+    }
+}
+
+#[test]
+fn test_escaped() {
+    for input in [
+        "\x02 65480 LGM\r\n",
+        r#"say "hi" \ 'bye'"#,
+        "caf\u{e9}\u{200B}\u{FFFF}",
+        "",
+    ] {
+        assert_eq!(Escaped(input).to_string(), encode(input));
+    }
+    assert_eq!(
+        format!("{:?}", Escaped("say \"hi\"\n")),
+        r#""say \"hi\"\n""#
+    );
+}
+
+#[test]
+fn test_encode_invisible_characters() {
+    // Bidirectional isolates, interlinear annotations and noncharacters
+    assert_eq!(
+        encode("a\u{2066}b\u{2069}\u{61C}\u{FFF9}\u{FDD0}\u{FFFE}\u{10FFFF}"),
+        r"a\u{2066}b\u{2069}\u{61C}\u{FFF9}\u{FDD0}\u{FFFE}\u{10FFFF}"
+    );
+    assert_eq!(encode("\u{E9}\u{1F600}\u{E000}"), "\u{E9}\u{1F600}\u{E000}");
+}
+
+#[test]
+fn test_encode_round_trips_every_code_point() {
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let input = format!("a{c}\\{c}");
+        assert_eq!(decode(&encode(&input)).unwrap(), input, "{c:?}");
+    }
+}
+
+#[test]
+fn test_encode_byte_order_mark() {
+    let options = EncodeOptions::new().byte_order_mark(true);
+    assert_eq!(encode_with("a\u{FEFF}", &options), "\u{FEFF}a\\u{FEFF}");
+    assert_eq!(encode_with("", &options), "\u{FEFF}");
+    assert_eq!(encode_with("a", &EncodeOptions::new()), "a");
+}
+
+/// The pinned outputs of `encode_canonical`, described in the file header.
+const CANONICAL_V1: &str = include_str!("data/canonical_v1.txt");
+
+#[test]
+fn test_encode_canonical_golden() {
+    assert_eq!(CANONICAL_ENCODING_VERSION, 1);
+    let mut count = 0;
+    for line in CANONICAL_V1.lines().filter(|line| !line.starts_with('#')) {
+        let (codes, expected) = line.split_once('\t').unwrap();
+        let input: String = codes
+            .split_whitespace()
+            .map(|code| char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap())
+            .collect();
+        assert_eq!(encode_canonical(&input), expected, "{input:?}");
+        assert_eq!(decode(expected).unwrap(), input);
+        count += 1;
+    }
+    assert_eq!(count, 15);
+}
+
+#[test]
+fn test_encode_canonical_every_code_point() {
+    // The FNV-1a hash of the canonical encoding of every Unicode scalar value, in order, pinned
+    // so that any change of the output of any character is noticed
+    let mut hash = 0xCBF2_9CE4_8422_2325u64;
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let encoded = encode_canonical(c.encode_utf8(&mut [0; 4]));
+        assert!(encoded.is_ascii());
+        for b in encoded.bytes() {
+            hash = (hash ^ u64::from(b)).wrapping_mul(0x100_0000_01B3);
+        }
+    }
+    assert_eq!(hash, 0x6132_CCA7_E038_A3D7);
+}
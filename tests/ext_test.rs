@@ -0,0 +1,27 @@
+use unicode_escape::{DecodeOptions, Dialect, EncodeOptions, ErrorKind, StrExt};
+
+#[test]
+fn test_str_ext() {
+    let raw = r"tab\there \u{E9}";
+    assert_eq!(raw.decode_escapes().unwrap(), "tab\there \u{E9}");
+    assert!(raw.decode_escapes_cow().unwrap().contains('\t'));
+    assert!(raw.validate_escapes().is_ok());
+    assert_eq!(
+        r"\q".validate_escapes().unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+    assert_eq!(r"\q!".decode_escapes_lossy(), "\u{FFFD}!");
+
+    let options = DecodeOptions::dialect(Dialect::Json);
+    assert_eq!(r"\u00E9".decode_escapes_with(&options).unwrap(), "\u{E9}");
+
+    // The methods are available on `String` as well
+    let text = String::from("say \"hi\"\n");
+    assert_eq!(text.escape_sequences(), r#"say \"hi\"\n"#);
+    assert_eq!(text.escaped().to_string(), text.escape_sequences());
+    assert_eq!(
+        text.escape_sequences_with(&EncodeOptions::new().codepoint_notation(true)),
+        "say \"hi\"U+000A"
+    );
+    assert_eq!(text.escape_sequences().decode_escapes().unwrap(), text);
+}
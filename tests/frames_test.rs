@@ -0,0 +1,75 @@
+use unicode_escape::{
+    decode_frames, Checksum, DecodeOptions, ErrorKind, FrameErrorKind, FrameOptions,
+};
+
+#[test]
+fn test_decode_frames() {
+    let stream = "\x02 65480 LGM\\r\\n\x03\r\n\x02 65490 LGM\\r\\n\x03\r\n";
+    let options = FrameOptions::new();
+    let frames: Vec<_> = decode_frames(stream, &options)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0].decoded, " 65480 LGM\r\n");
+    assert_eq!(frames[0].payload, r" 65480 LGM\r\n");
+    assert_eq!(frames[0].range, 0..16);
+    assert_eq!(frames[1].decoded, " 65490 LGM\r\n");
+    assert_eq!(&stream[frames[1].range.clone()], "\x02 65490 LGM\\r\\n\x03");
+
+    // Escaped framing characters belong to the payload
+    let frames: Vec<_> = decode_frames("\x02a\\x03b\x03", &options).collect();
+    assert_eq!(frames[0].as_ref().unwrap().decoded, "a\x03b");
+}
+
+#[test]
+fn test_decode_frames_errors() {
+    let options = FrameOptions::new();
+    let stream = "\x02bad\\q\x03\x02cut\x02ok\x03\x02open";
+    let results: Vec<_> = decode_frames(stream, &options).collect();
+    assert_eq!(results.len(), 4);
+    let error = results[0].as_ref().unwrap_err();
+    assert!(matches!(
+        &error.kind,
+        FrameErrorKind::Decode(decode) if matches!(decode.kind(), ErrorKind::UnknownEscape(_))
+    ));
+    assert_eq!(error.range, 0..7);
+    let error = results[1].as_ref().unwrap_err();
+    assert!(matches!(error.kind, FrameErrorKind::Unterminated));
+    assert_eq!(&stream[error.range.clone()], "\x02cut");
+    assert_eq!(results[2].as_ref().unwrap().decoded, "ok");
+    let error = results[3].as_ref().unwrap_err();
+    assert!(matches!(error.kind, FrameErrorKind::Unterminated));
+    assert_eq!(error.range.end, stream.len());
+}
+
+#[test]
+fn test_decode_frames_checksum() {
+    // 'A' ^ 'B' = 0x03, 'A' + 'B' = 0x83
+    let xor = FrameOptions::new().checksum(Some(Checksum::Xor));
+    let frames: Vec<_> = decode_frames("\x02\\x41B\x0303\x02AB\x0304\x02AB\x03", &xor).collect();
+    assert_eq!(frames[0].as_ref().unwrap().decoded, "AB");
+    assert_eq!(frames[0].as_ref().unwrap().range, 0..9);
+    assert!(matches!(
+        frames[1].as_ref().unwrap_err().kind,
+        FrameErrorKind::ChecksumMismatch {
+            expected: 4,
+            actual: 3
+        }
+    ));
+    assert!(matches!(
+        frames[2].as_ref().unwrap_err().kind,
+        FrameErrorKind::MissingChecksum
+    ));
+    assert_eq!(Checksum::Sum.compute(b"AB"), 0x83);
+
+    let custom = FrameOptions::new()
+        .start('[')
+        .end(']')
+        .checksum(Some(Checksum::Sum))
+        .decode_options(DecodeOptions::new().fixed_unicode(true));
+    let frames: Vec<_> = decode_frames("[AB]83 [\\x41\\u{42}]83", &custom)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[1].decoded, "AB");
+}
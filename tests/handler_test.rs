@@ -0,0 +1,53 @@
+use unicode_escape::{DecodeError, DecodeOptions, ErrorKind, EscapeHandler, EscapeRegistry};
+
+/// Decodes `\d{N}` to the control character with the decimal code N.
+struct DelimiterEscapes;
+
+impl EscapeHandler for DelimiterEscapes {
+    fn decode(&self, rest: &str, out: &mut String) -> Option<Result<usize, DecodeError>> {
+        let body = rest.strip_prefix("d{")?;
+        let Some(end) = body.find('}') else {
+            return Some(Err(ErrorKind::UnterminatedUnicode.into()));
+        };
+        match body[..end].parse().ok().filter(|&code: &u8| code < 0x20) {
+            Some(code) => {
+                out.push(char::from(code));
+                Some(Ok(end + 3))
+            }
+            None => Some(Err(ErrorKind::CodePointOutOfRange(0x20).into())),
+        }
+    }
+}
+
+#[test]
+fn test_escape_registry() {
+    let registry =
+        EscapeRegistry::new()
+            .with(DelimiterEscapes)
+            .with(|rest: &str, out: &mut String| {
+                rest.starts_with('p').then(|| {
+                    out.push('\u{2029}');
+                    Ok(1)
+                })
+            });
+    let options = DecodeOptions::new();
+    assert_eq!(
+        registry.decode(r"a\d{29}b\p\n\x41", &options).unwrap(),
+        "a\u{1D}b\u{2029}\nA"
+    );
+    assert!(matches!(
+        registry.decode(r"\d{300}", &options).unwrap_err().kind(),
+        ErrorKind::CodePointOutOfRange(_)
+    ));
+    assert_eq!(
+        registry.decode(r"\q", &options).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+    // Escape sequences the options accept are not offered to the handlers
+    let registry = EscapeRegistry::new().with(|_: &str, out: &mut String| {
+        out.push('!');
+        Some(Ok(1))
+    });
+    assert_eq!(registry.decode(r"\n\q", &options).unwrap(), "\n!");
+    assert_eq!(EscapeRegistry::new().decode(r"\t", &options).unwrap(), "\t");
+}
@@ -0,0 +1,105 @@
+use unicode_escape::{decode_with_map, IncrementalDecoder};
+
+/// Checks that the decoder agrees with decoding its input at once.
+fn assert_consistent(decoder: &IncrementalDecoder) {
+    match decode_with_map(decoder.input()) {
+        Ok((decoded, map)) => {
+            assert_eq!(
+                decoder.decoded().ok(),
+                Some(decoded.as_str()),
+                "{:?}",
+                decoder.input()
+            );
+            assert_eq!(decoder.source_map(), Some(&map), "{:?}", decoder.input());
+        }
+        Err(error) => {
+            let actual = decoder.decoded().expect_err(decoder.input());
+            assert_eq!(format!("{actual:?}"), format!("{error:?}"));
+            assert!(decoder.source_map().is_none());
+        }
+    }
+}
+
+#[test]
+fn test_incremental_edit() {
+    let mut decoder = IncrementalDecoder::new(r"a\u{41}b\tc");
+    assert_eq!(decoder.decoded().ok(), Some("aAb\tc"));
+
+    assert!(decoder.edit(2..3, "x").is_err());
+    assert_consistent(&decoder);
+    assert_eq!(decoder.input(), r"a\x{41}b\tc");
+
+    assert_eq!(decoder.edit(2..3, "u").ok(), Some("aAb\tc"));
+    assert_eq!(decoder.edit(5..6, "2").ok(), Some("aBb\tc"));
+    assert_eq!(decoder.edit(8..8, r"\n").ok(), Some("aBb\n\tc"));
+    assert_eq!(decoder.edit(0..1, "").ok(), Some("Bb\n\tc"));
+    assert_consistent(&decoder);
+    let map = decoder.source_map().unwrap();
+    assert_eq!(map.input_range(2), Some(7..9));
+    assert_eq!(map.input_offset(map.len()), Some(decoder.input().len()));
+}
+
+#[test]
+fn test_incremental_recovers_from_errors() {
+    let mut decoder = IncrementalDecoder::new(r"\q");
+    assert!(decoder.decoded().is_err());
+    assert!(decoder.source_map().is_none());
+    assert_eq!(decoder.edit(1..2, "n").ok(), Some("\n"));
+    assert!(decoder.edit(0..0, r"\u{").is_err());
+    assert_eq!(decoder.edit(3..3, "E9}").ok(), Some("\u{E9}\n"));
+    assert_consistent(&decoder);
+}
+
+#[test]
+fn test_incremental_surrogate_pairs() {
+    // Surrogate pairs are rejected like `decode` does by default
+    let mut decoder = IncrementalDecoder::new(r"\u{D83D}\u{DE00}!");
+    assert!(decoder.decoded().is_err());
+    assert_eq!(decoder.edit(0..16, r"\u{1F600}").ok(), Some("\u{1F600}!"));
+    assert!(decoder.edit(9..9, r"\u{DE00}").is_err());
+    assert_eq!(decoder.edit(9..17, r"\\").ok(), Some("\u{1F600}\\!"));
+    assert_consistent(&decoder);
+}
+
+#[test]
+fn test_incremental_random_edits() {
+    const FRAGMENTS: [&str; 16] = [
+        "a", "é", "😀", "\\", "\\\\", "n", "u", "x", "{", "}", "4", "1", "D83D", "DE00", "\\u{41}",
+        "\\x7F",
+    ];
+    // A deterministic linear congruential generator, so that failures are reproducible
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    let mut next = |bound: usize| {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as usize % bound
+    };
+
+    let mut decoder = IncrementalDecoder::new(r"start \u{1F600} \t end");
+    for _ in 0..2000 {
+        let boundaries: Vec<usize> = decoder
+            .input()
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain([decoder.input().len()])
+            .collect();
+        let start = boundaries[next(boundaries.len())];
+        let end = boundaries[next(boundaries.len())].max(start);
+        let end = if next(3) == 0 {
+            start
+        } else {
+            end.min(start + 8)
+        };
+        let end = *boundaries.iter().find(|&&b| b >= end).unwrap();
+        let replacement: String = (0..next(3))
+            .map(|_| FRAGMENTS[next(FRAGMENTS.len())])
+            .collect();
+        decoder.edit(start..end, &replacement).ok();
+        assert_consistent(&decoder);
+        if decoder.input().len() > 200 {
+            let end = boundaries.iter().rev().find(|&&b| b <= 100).unwrap();
+            decoder = IncrementalDecoder::new(&decoder.input()[..*end]);
+        }
+    }
+}
@@ -1,4 +1,11 @@
-use unicode_escape::decode;
+use std::borrow::Cow;
+
+use unicode_escape::{
+    decode, decode_all, decode_all_with, decode_cow, decode_in_place, decode_into, decode_lossy,
+    decode_lossy_with, decode_spanned, decode_sql_literal, decode_to, decode_with,
+    decode_with_handler, BomPolicy, DecodeError, DecodeOptions, Decoder, Dialect, ErrorKind,
+    LineContinuation, NoncharacterPolicy, OctalEscapes, SimpleEscapes, UnknownEscapePolicy,
+};
 
 #[test]
 fn test_simple_escape() {
@@ -23,12 +30,118 @@ fn test_weight_string() {
     assert_eq!(expected, decode(case).unwrap());
 }
 
+#[test]
+fn test_decode_cow() {
+    assert!(matches!(
+        decode_cow("65480 LGM"),
+        Ok(Cow::Borrowed("65480 LGM"))
+    ));
+    assert!(matches!(decode_cow(""), Ok(Cow::Borrowed(""))));
+    match decode_cow(r"65480\tLGM") {
+        Ok(Cow::Owned(decoded)) => assert_eq!(decoded, "65480\tLGM"),
+        other => panic!("expected an owned string, found {other:?}"),
+    }
+    assert!(matches!(decode_cow(r"\\"), Ok(Cow::Owned(_))));
+    assert_eq!(
+        decode_cow(r"\q").unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+}
+
+#[test]
+fn test_decode_in_place() {
+    let mut string = String::with_capacity(64);
+    string.push_str(r"\x02 65480 LGM\r\n \u{21B5}");
+    let capacity = string.capacity();
+    decode_in_place(&mut string).unwrap();
+    assert_eq!(string, "\x02 65480 LGM\r\n \u{21B5}");
+    assert_eq!(string.capacity(), capacity);
+
+    // Adjacent escape sequences, multibyte text and a trailing escape sequence
+    let input = r"\\\\ café\u{1F600}\\x\t\'é\u{E9}";
+    let mut string = input.to_string();
+    decode_in_place(&mut string).unwrap();
+    assert_eq!(string, decode(input).unwrap());
+
+    let mut plain = String::from("plain");
+    decode_in_place(&mut plain).unwrap();
+    assert_eq!(plain, "plain");
+
+    let mut invalid = String::from(r"ok \q");
+    assert_eq!(
+        decode_in_place(&mut invalid).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+    assert_eq!(invalid, r"ok \q");
+}
+
+#[test]
+fn test_decode_to() {
+    use std::fmt::{self, Write};
+
+    let mut out = String::from("reading: ");
+    decode_to(r"65480 LGM\u{21B5}", &mut out).unwrap();
+    assert_eq!(out, "reading: 65480 LGM\u{21B5}");
+
+    let mut out = String::new();
+    assert_eq!(
+        decode_to(r"ok\q", &mut out).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+    assert_eq!(out, "ok");
+
+    struct Full;
+    impl Write for Full {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+    assert_eq!(
+        decode_to(r"\n", &mut Full).unwrap_err().kind(),
+        ErrorKind::WriteFailed
+    );
+}
+
+#[test]
+fn test_decode_into() {
+    let mut buf = [0; 16];
+    let len = decode_into(r"AT+SEND=\x02\u{B0}C", &mut buf).unwrap();
+    assert_eq!(&buf[..len], "AT+SEND=\x02\u{B0}C".as_bytes());
+    assert_eq!(decode_into("", &mut []).unwrap(), 0);
+
+    let mut buf = [0; 4];
+    let error = decode_into(r"abc\u{E9}", &mut buf).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall);
+    assert_eq!(error.span(), Some(3..9));
+    assert_eq!(&buf[..3], b"abc");
+    assert_eq!(
+        decode_into(r"a\q", &mut buf).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+}
+
 #[test]
 fn test_invalid_escape() {
     let case = r"\x02 \65480 LGM\r\n";
     assert!(decode(case).is_err());
 }
 
+#[test]
+fn test_decode_error() {
+    let error = decode(r"ok \q \u{110000}").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownEscape('q'));
+    assert_eq!(error.span(), Some(3..5));
+    assert_eq!(error.offset(), Some(3));
+    assert_eq!(error.kind().code(), "UnknownEscape");
+    assert_eq!(error.to_string(), r"invalid escape '\q' at byte 3");
+    assert_eq!(error.clone(), error);
+    assert_ne!(decode(r"\q").unwrap_err(), decode(r" \q").unwrap_err());
+
+    let error = DecodeError::from(ErrorKind::UnpairedQuote);
+    assert_eq!(error.span(), None);
+    assert_eq!(error.to_string(), ErrorKind::UnpairedQuote.to_string());
+}
+
 #[test]
 fn test_unicode_sequence() {
     let expected = "↵";
@@ -52,3 +165,661 @@ fn test_unicode_codepoints() {
         assert!(decode(case).is_err());
     }
 }
+
+#[test]
+fn test_surrogate_pairs() {
+    // Surrogate pairs are rejected by default, like any other surrogate code point
+    assert_eq!(
+        decode(r"\u{D83D}\u{DE00}").unwrap_err().kind(),
+        ErrorKind::SurrogateCodePoint(0xD83D)
+    );
+    assert_eq!(decode(r"\u{1F600}").unwrap(), "\u{1F600}");
+
+    let options = DecodeOptions::new().combine_surrogates(true);
+    assert_eq!(
+        decode_with(r"\u{D83D}\u{DE00}", &options).unwrap(),
+        "\u{1F600}"
+    );
+    assert_eq!(
+        decode_with(r"x\u{d83d}\u{de00}y", &options).unwrap(),
+        "x\u{1F600}y"
+    );
+    let invalid_cases = vec![r"\u{D83D}", r"\u{DE00}", r"\u{D83D}\u{D83D}", r"\u{D83D}x"];
+    for case in invalid_cases {
+        assert!(decode_with(case, &options).is_err());
+    }
+}
+
+#[test]
+fn test_digit_separators() {
+    assert!(decode(r"\u{1_F600}").is_err());
+
+    let options = DecodeOptions::new()
+        .digit_separators(true)
+        .combine_surrogates(true);
+    let cases = vec![
+        (r"\u{1_F600}", "\u{1F600}"),
+        (r"\u{1F_6_00}", "\u{1F600}"),
+        (r"\u{E9_}", "\u{E9}"),
+        (r"\u{D83D}\u{DE_00}", "\u{1F600}"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(decode_with(input, &options).unwrap(), expected);
+    }
+
+    let invalid_cases = vec![r"\u{_1F600}", r"\u{_}", r"\u{}", r"\u{1_G}"];
+    for case in invalid_cases {
+        assert!(decode_with(case, &options).is_err());
+    }
+}
+
+#[test]
+fn test_code_point_groups() {
+    assert!(decode(r"\u{1F468 200D 1F4BB}").is_err());
+
+    let options = DecodeOptions::new()
+        .code_point_groups(true)
+        .combine_surrogates(true);
+    let cases = vec![
+        (r"\u{1F468 200D 1F4BB}", "\u{1F468}\u{200D}\u{1F4BB}"),
+        (r"a\u{65  301}b", "a\u{65}\u{301}b"),
+        (r"\u{D83D DE00 41}", "\u{1F600}A"),
+        (r"\u{E9}\u{E8 EA}", "\u{E9}\u{E8}\u{EA}"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(decode_with(input, &options).unwrap(), expected);
+    }
+
+    let invalid_cases = vec![r"\u{ 41}", r"\u{41 }", r"\u{41 G}", r"\u{41 D800}"];
+    for case in invalid_cases {
+        assert!(decode_with(case, &options).is_err());
+    }
+}
+
+#[test]
+fn test_noncharacters() {
+    let noncharacters = vec![
+        r"\u{FFFE}",
+        r"\u{FFFF}",
+        r"\u{FDD0}",
+        r"\u{FDEF}",
+        r"\u{10FFFF}",
+        r"\u{1FFFE}",
+    ];
+    for case in &noncharacters {
+        assert!(decode(case).is_ok());
+    }
+
+    let reject = DecodeOptions::new().noncharacters(NoncharacterPolicy::Reject);
+    let replace = DecodeOptions::new().noncharacters(NoncharacterPolicy::Replace);
+    for case in &noncharacters {
+        assert_eq!(
+            decode_with(case, &reject).unwrap_err().kind(),
+            ErrorKind::Noncharacter
+        );
+        assert_eq!(decode_with(case, &replace).unwrap(), "\u{FFFD}");
+    }
+
+    // Neighbouring code points and literal noncharacters are left alone
+    assert_eq!(
+        decode_with(r"\u{FDCF}\u{FFFD}", &reject).unwrap(),
+        "\u{FDCF}\u{FFFD}"
+    );
+    assert_eq!(decode_with("\u{FFFF}", &reject).unwrap(), "\u{FFFF}");
+}
+
+#[test]
+fn test_byte_order_mark() {
+    let cases = vec![r"\u{FEFF}key", "\u{FEFF}key"];
+    for case in cases {
+        assert_eq!(decode(case).unwrap(), "\u{FEFF}key");
+
+        let strip = DecodeOptions::new().bom(BomPolicy::Strip);
+        assert_eq!(decode_with(case, &strip).unwrap(), "key");
+
+        let reject = DecodeOptions::new().bom(BomPolicy::Reject);
+        assert_eq!(
+            decode_with(case, &reject).unwrap_err().kind(),
+            ErrorKind::ByteOrderMark
+        );
+    }
+
+    // Only a leading byte order mark is affected
+    let strip = DecodeOptions::new().bom(BomPolicy::Strip);
+    assert_eq!(decode_with(r"a\u{FEFF}", &strip).unwrap(), "a\u{FEFF}");
+
+    // The byte order mark is reported before the invalid escape sequences following it
+    let reject = DecodeOptions::new().bom(BomPolicy::Reject);
+    let input = "\u{FEFF}a\\q";
+    let error = decode_with(input, &reject).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ByteOrderMark);
+    assert_eq!(error.span(), Some(0..0));
+    let errors = decode_all_with(input, &reject).unwrap_err();
+    let kinds: Vec<_> = errors.iter().map(DecodeError::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![ErrorKind::ByteOrderMark, ErrorKind::UnknownEscape('q')]
+    );
+    assert_eq!(errors[1].span(), Some(4..6));
+    let mut decoder = Decoder::with_options(reject);
+    assert_eq!(decoder.feed(input).unwrap_err(), error);
+}
+
+#[test]
+fn test_unicode_syntax() {
+    let options = DecodeOptions::new().fixed_unicode(true).long_unicode(true);
+    let cases = vec![
+        (r"\u00E9", "\u{E9}"),
+        (r"\u00E9t\u00e9", "\u{E9}t\u{E9}"),
+        (r"\u{E9}\u00E9", "\u{E9}\u{E9}"),
+        (r"\U0001F600", "\u{1F600}"),
+        (r"\u21B5\u{21B5}\U000021B5", "\u{21B5}\u{21B5}\u{21B5}"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(decode_with(input, &options).unwrap(), expected);
+    }
+
+    let invalid_cases = vec![r"\u00E", r"\uD800", r"\U0001F60", r"\U00110000", r"\u{}"];
+    for case in invalid_cases {
+        assert!(decode_with(case, &options).is_err(), "{}", case);
+    }
+
+    // Every syntax can be toggled on its own
+    assert!(decode(r"\u00E9").is_err());
+    assert!(decode(r"\U0001F600").is_err());
+    let fixed_only = DecodeOptions::new()
+        .braced_unicode(false)
+        .fixed_unicode(true);
+    assert!(decode_with(r"\u{E9}", &fixed_only).is_err());
+    assert_eq!(decode_with(r"\u00E9", &fixed_only).unwrap(), "\u{E9}");
+
+    let rust = DecodeOptions::new().max_unicode_digits(Some(6));
+    assert_eq!(decode_with(r"\u{10FFFF}", &rust).unwrap(), "\u{10FFFF}");
+    assert!(decode_with(r"\u{0000041}", &rust).is_err());
+    assert_eq!(decode(r"\u{0000041}").unwrap(), "A");
+}
+
+#[test]
+fn test_decode_spanned() {
+    let options = DecodeOptions::new();
+    assert_eq!(
+        decode_spanned(r"65480\tLGM", &options).unwrap(),
+        "65480\tLGM"
+    );
+
+    let error = decode_spanned(r"größe: 5\q kg", &options).unwrap_err();
+    assert_eq!(error.error.kind(), ErrorKind::UnknownEscape('q'));
+    assert_eq!(error.span, 10..12);
+    assert_eq!(error.offset(), 10);
+    assert_eq!(error.char_index, 8);
+    assert_eq!(
+        error.to_string(),
+        r#"invalid escape '\q' at byte 10 in "größe: 5\q kg""#
+    );
+
+    // The context is cut off 16 characters away from the escape sequence
+    let input = r"\x02 65480 TEMP 21.5 LGM\q OK\r\n checksum 7F\r\n";
+    let error = decode_spanned(input, &options).unwrap_err();
+    assert_eq!(error.context, r"...80 TEMP 21.5 LGM\q OK\r\n checksum...");
+    assert_eq!(
+        error.to_string(),
+        r#"invalid escape '\q' at byte 24 in "...80 TEMP 21.5 LGM\q OK\r\n checksum...""#
+    );
+
+    let error = decode_spanned(r"ok \x4G", &options).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        r#"invalid digit 'G' in escape sequence at byte 3 in "ok \x4G""#
+    );
+    assert_eq!(error.span, 3..6);
+    // Named escape sequences are covered up to their closing brace, with or without `names`
+    let error = decode_spanned(r"ok \N{NOPE} \t", &options).unwrap_err();
+    assert_eq!(error.span, 3..11);
+
+    let error = decode_spanned(r"ok \u{110000} \x", &options).unwrap_err();
+    assert_eq!(error.error.kind(), ErrorKind::CodePointOutOfRange(0x110000));
+    assert_eq!(error.span, 3..13);
+    assert_eq!(error.char_index, 3);
+
+    let options = DecodeOptions::new().bom(BomPolicy::Reject);
+    let error = decode_spanned(r"\u{FEFF}text", &options).unwrap_err();
+    assert_eq!(error.error.kind(), ErrorKind::ByteOrderMark);
+    assert_eq!(error.span, 0..0);
+}
+
+#[test]
+fn test_decode_lossy() {
+    assert_eq!(decode_lossy(r"65480\tLGM"), "65480\tLGM");
+    assert_eq!(decode_lossy(r"a\qb\x4"), "a\u{FFFD}b\u{FFFD}");
+    assert_eq!(decode_lossy(r"\u{110000}\u{41}"), "\u{FFFD}A");
+    // Every invalid escape sequence is replaced as a whole by a single replacement character
+    assert_eq!(decode_lossy(r"\u{110000}"), "\u{FFFD}");
+    assert_eq!(decode_lossy(r"\N{NOPE}"), "\u{FFFD}");
+    assert_eq!(decode_lossy(r"a \N{NOPE} b"), "a \u{FFFD} b");
+    assert_eq!(decode_lossy("\\"), "\u{FFFD}");
+
+    let options = DecodeOptions::new().bom(BomPolicy::Reject);
+    assert_eq!(decode_lossy_with(r"\u{FEFF}x\q", &options, ""), "x");
+    assert_eq!(decode_lossy_with(r"\q\x41", &options, "<?>"), "<?>A");
+    let options = DecodeOptions::new().noncharacters(NoncharacterPolicy::Reject);
+    assert_eq!(decode_lossy_with(r"\u{FFFF}!", &options, "?"), "?!");
+}
+
+#[test]
+fn test_decode_all() {
+    assert_eq!(decode_all(r"65480\tLGM").unwrap(), "65480\tLGM");
+    let errors = decode_all(r"\q ok \xGG \u{ZZZZ}\").unwrap_err();
+    let kinds: Vec<_> = errors.iter().map(DecodeError::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ErrorKind::UnknownEscape('q'),
+            ErrorKind::InvalidHexDigit('G'),
+            ErrorKind::InvalidHexDigit('Z'),
+            ErrorKind::UnexpectedEof,
+        ]
+    );
+    let spans: Vec<_> = errors.iter().filter_map(DecodeError::span).collect();
+    assert_eq!(spans, vec![0..2, 6..8, 11..19, 19..20]);
+    let errors = decode_all(r"\u{110000} \x4").unwrap_err();
+    let spans: Vec<_> = errors.iter().filter_map(DecodeError::span).collect();
+    assert_eq!(spans, vec![0..10, 11..14]);
+
+    let options = DecodeOptions::new().bom(BomPolicy::Reject);
+    let errors = decode_all_with(r"\u{FEFF}x\q", &options).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].kind(), ErrorKind::ByteOrderMark);
+    assert_eq!(errors[1].kind(), ErrorKind::UnknownEscape('q'));
+}
+
+#[test]
+fn test_escape_families() {
+    let options = DecodeOptions::new().simple_escapes(false);
+    assert_eq!(
+        decode_with(r"\0", &options).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('0')
+    );
+    assert_eq!(decode_with(r"\x00\u{0}", &options).unwrap(), "\0\0");
+
+    let options = DecodeOptions::new().hex_escapes(false);
+    assert_eq!(
+        decode_with(r"\x02", &options).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('x')
+    );
+    assert_eq!(
+        decode_with(r"\xGG", &options).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('x')
+    );
+    assert_eq!(decode_with(r"\t\u{2}", &options).unwrap(), "\t\x02");
+
+    let options = DecodeOptions::new()
+        .unicode_escapes(false)
+        .fixed_unicode(true)
+        .long_unicode(true);
+    for input in [r"\u{E9}", r"\u00E9", r"\U000000E9", r"\u{ZZ}"] {
+        assert!(matches!(
+            decode_with(input, &options).unwrap_err().kind(),
+            ErrorKind::UnknownEscape('u' | 'U')
+        ));
+    }
+    assert_eq!(decode_with(r"\xE9", &options).unwrap(), "\u{E9}");
+}
+
+#[test]
+fn test_simple_escape_set() {
+    let options =
+        DecodeOptions::new().simple_escape_set(SimpleEscapes::DEFAULT.with('e').with('?'));
+    assert_eq!(decode_with(r"\e[1m\?\n", &options).unwrap(), "\x1B[1m?\n");
+    let options = DecodeOptions::new().simple_escape_set(SimpleEscapes::DEFAULT.without('0'));
+    assert_eq!(
+        decode_with(r"\0", &options).unwrap_err().kind(),
+        ErrorKind::UnknownEscape('0')
+    );
+    let options = DecodeOptions::new().simple_escape_set(SimpleEscapes::CONTROLS);
+    assert_eq!(
+        decode_with(r"\a\b\f\v\e\t", &options).unwrap(),
+        "\x07\x08\x0C\x0B\x1B\t"
+    );
+    assert!(decode_with(r"\a", &DecodeOptions::new()).is_err());
+    assert!(SimpleEscapes::DEFAULT.contains('\\'));
+    assert!(!SimpleEscapes::DEFAULT.contains('a'));
+    assert!(!SimpleEscapes::DEFAULT.contains('\u{E9}'));
+}
+
+#[test]
+fn test_json_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Json);
+    assert_eq!(
+        decode_with(r#"\"\\\/\b\f\n\r\t"#, &options).unwrap(),
+        "\"\\/\x08\x0C\n\r\t"
+    );
+    assert_eq!(
+        decode_with(r"caf \uD83D\uDE00\u00e9", &options).unwrap(),
+        "caf \u{1F600}\u{E9}"
+    );
+    for input in [r"\x41", r"\'", r"\0", r"\u{41}", r"\U0001F600"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+    for input in [r"\uD83D", r"\uDE00\uD83D", r"\uD83D\u0041", r"\uD83D\uDE0"] {
+        assert!(matches!(
+            decode_with(input, &options).unwrap_err().kind(),
+            ErrorKind::SurrogateCodePoint(_) | ErrorKind::UnexpectedEof
+        ));
+    }
+}
+
+#[test]
+fn test_python_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Python);
+    assert_eq!(
+        decode_with(r"\a\v\101\0\1234\x41\u00e9\U0001F600", &options).unwrap(),
+        "\x07\x0BA\0S4A\u{E9}\u{1F600}"
+    );
+    assert_eq!(decode_with(r"\q \d \{", &options).unwrap(), r"\q \d \{");
+    assert_eq!(decode_with("one\\\ntwo", &options).unwrap(), "onetwo");
+    for input in [r"\x4", r"\u{41}", r"\uD83D\uDE00", r"\U00110000"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_legacy_escape_options() {
+    let options = DecodeOptions::new().octal_escapes(Some(OctalEscapes::UpToThree));
+    assert!(decode_with(r"\8", &options).is_err());
+    assert_eq!(decode_with(r"\0\00\101", &options).unwrap(), "\0\0A");
+    let options = options.octal_zero(false);
+    assert_eq!(decode_with(r"\012\101", &options).unwrap(), "\x0012A");
+
+    let options = DecodeOptions::new().line_continuation(Some(LineContinuation::Remove));
+    assert_eq!(decode_with("a\\\r\nb\\\rc", &options).unwrap(), "abc");
+    assert!(decode_with("a\\\n", &DecodeOptions::new()).is_err());
+
+    let options = DecodeOptions::new().unknown_escapes(UnknownEscapePolicy::Preserve);
+    assert_eq!(decode_with(r"\q \x41", &options).unwrap(), r"\q A");
+    assert_eq!(
+        decode_with(r"\x4", &options).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+    let options = options.hex_escapes(false);
+    assert_eq!(decode_with(r"\x41", &options).unwrap(), r"\x41");
+
+    let options = DecodeOptions::new().unknown_escapes(UnknownEscapePolicy::Replace('\u{FFFD}'));
+    assert_eq!(
+        decode_with(r"\q\n\é", &options).unwrap(),
+        "\u{FFFD}\n\u{FFFD}"
+    );
+    assert!(decode_with(r"\u{110000}", &options).is_err());
+}
+
+#[test]
+fn test_decode_with_handler() {
+    let handler = |c: char, out: &mut String| match c {
+        'd' => {
+            out.push('\u{1D}');
+            Ok(())
+        }
+        'q' => Ok(()),
+        c => Err(ErrorKind::UnknownEscape(c).into()),
+    };
+    let options = DecodeOptions::new();
+    assert_eq!(
+        decode_with_handler(r"a\db\q\n\x41", &options, handler).unwrap(),
+        "a\u{1D}b\nA"
+    );
+    assert_eq!(
+        decode_with_handler(r"\y", &options, handler)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::UnknownEscape('y')
+    );
+    assert_eq!(
+        decode_with_handler(r"\x4", &options, handler)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::UnexpectedEof
+    );
+    // Escape sequences of a kind the options disable are unknown as well
+    let options = options.hex_escapes(false);
+    let mut seen = Vec::new();
+    let decoded = decode_with_handler(r"\x41", &options, |c, out| {
+        seen.push(c);
+        out.push('#');
+        Ok(())
+    });
+    assert_eq!(decoded.unwrap(), "#41");
+    assert_eq!(seen, ['x']);
+}
+
+#[test]
+fn test_c_dialect() {
+    let options = DecodeOptions::dialect(Dialect::C);
+    assert_eq!(
+        decode_with(r"\?\a\101\18\x41BC\x0000041\U0001F600", &options).unwrap(),
+        "?\x07A\x018\u{41BC}A\u{1F600}"
+    );
+    assert_eq!(
+        decode_with("Hello\\\nworld", &options).unwrap(),
+        "Helloworld"
+    );
+    for input in [r"\q", r"\x", r"\xD800", r"\x110000", r"\uD83D\uDE00"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+
+    let options = DecodeOptions::new().hex_digits(1, Some(4));
+    assert_eq!(
+        decode_with(r"\x4\x41BCD", &options).unwrap(),
+        "\x04\u{41BC}D"
+    );
+}
+
+#[test]
+fn test_toml_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Toml);
+    assert_eq!(
+        decode_with(r#"\b\t\n\f\r\"\\\u00e9\U0001F600"#, &options).unwrap(),
+        "\x08\t\n\x0C\r\"\\\u{E9}\u{1F600}"
+    );
+    assert_eq!(
+        decode_with("The quick \\\n\n    brown \\  \t\r\n  fox.", &options).unwrap(),
+        "The quick brown fox."
+    );
+    for input in [r"\x41", r"\e", r"\'", r"\/", r"\uD83D\uDE00", "\\ \t x"] {
+        assert!(decode_with(input, &options).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn test_shell_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Shell);
+    assert_eq!(
+        decode_with(r"\e\E\cA\ca\c?\c\\\101\x41\x4G", &options).unwrap(),
+        "\x1B\x1B\x01\x01\x7F\x1CAA\x04G"
+    );
+    assert_eq!(
+        decode_with(r"\u41\u00e9\U1F600\q", &options).unwrap(),
+        "A\u{E9}\u{1F600}\\q"
+    );
+    for input in [r"\uD83D", r"\U110000", r"\c"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+
+    let options = DecodeOptions::new().control_escapes(true);
+    assert_eq!(decode_with(r"\cZ\c[", &options).unwrap(), "\x1A\x1B");
+    assert!(decode_with(r"\cA", &DecodeOptions::new()).is_err());
+}
+
+#[test]
+fn test_javascript_dialect() {
+    let options = DecodeOptions::dialect(Dialect::JavaScript);
+    assert_eq!(
+        decode_with(r"\v\0\08\101\400\8\q\x41\u{1F600}", &options).unwrap(),
+        "\x0B\0\08A 08qA\u{1F600}"
+    );
+    assert_eq!(
+        decode_with(r"\uD83D\u{DE00}\u{D83D}\uDE00", &options).unwrap(),
+        "\u{1F600}\u{1F600}"
+    );
+    assert_eq!(
+        decode_with(r"\uD83Dx\u{DE00}", &options).unwrap(),
+        "\u{FFFD}x\u{FFFD}"
+    );
+    assert_eq!(
+        decode_with("a\\\u{2028}b\\\r\nc\\\nd", &options).unwrap(),
+        "abcd"
+    );
+    for input in [r"\x4", r"\u12", r"\u{110000}"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+
+    let options = DecodeOptions::new().replace_lone_surrogates(true);
+    assert_eq!(decode_with(r"\u{DFFF}", &options).unwrap(), "\u{FFFD}");
+    assert!(decode_with(r"\u{DFFF}", &DecodeOptions::new()).is_err());
+}
+
+#[test]
+fn test_go_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Go);
+    assert_eq!(
+        decode_with(r#"\a\v\101\377\x41\u00e9\U0001F600\""#, &options).unwrap(),
+        "\x07\x0BA\u{FF}A\u{E9}\u{1F600}\""
+    );
+    for input in [
+        r"\0",
+        r"\12",
+        r"\400",
+        r"\'",
+        r"\e",
+        r"\u{41}",
+        r"\uD800",
+        r"\U00110000",
+    ] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_java_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Java);
+    assert_eq!(
+        decode_with(r"\s\t\101\400\uuu0041\uD83D\uDE00", &options).unwrap(),
+        " \tA 0A\u{1F600}"
+    );
+    // Unicode escapes are translated before the other escape sequences
+    assert_eq!(
+        decode_with(r"\u005cn \u005c\u005c \1\u0032 \\u0041", &options).unwrap(),
+        "\n \\ \n \\u0041"
+    );
+    for input in [r"\u005c", r"\u005cu0041", r"\x41", r"\uD83D", r"\u{41}"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_csharp_dialect() {
+    let options = DecodeOptions::dialect(Dialect::CSharp);
+    assert_eq!(
+        decode_with(
+            r"\0\a\v\x41\x041\x00E9x\u00e9\U0001F600\uD83D\uDE00",
+            &options
+        )
+        .unwrap(),
+        "\0\x07\x0BAA\u{E9}x\u{E9}\u{1F600}\u{1F600}"
+    );
+    for input in [r"\1", r"\x", r"\e", r"\u{41}", r"\U00110000", r"\uD83D"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_lua_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Lua);
+    assert_eq!(
+        decode_with(r"\65\0651\255\x41\u{1F600}\'", &options).unwrap(),
+        "AA1\u{FF}A\u{1F600}'"
+    );
+    assert_eq!(
+        decode_with("a\\z \n\t b\\\r\nc\\\n\rd", &options).unwrap(),
+        "ab\nc\nd"
+    );
+    for input in [r"\256", r"\x4", r"\u0041", r"\u{D800}", r"\e"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_postgresql_dialect() {
+    let options = DecodeOptions::dialect(Dialect::PostgreSql);
+    assert_eq!(
+        decode_with(r"it''s \'\q\101\x4\x414\u00e9\U0001F600", &options).unwrap(),
+        "it's 'qA\x04A4\u{E9}\u{1F600}"
+    );
+    for input in ["it's", r"\xG", r"\uD83D", r"\u12"] {
+        assert!(decode_with(input, &options).is_err(), "{input}");
+    }
+
+    let options = DecodeOptions::new().doubled_quote(Some('"'));
+    assert_eq!(
+        decode_with(r#"say ""hi""\n"#, &options).unwrap(),
+        "say \"hi\"\n"
+    );
+    assert_eq!(
+        decode_with(r#"say "hi""#, &options).unwrap_err().kind(),
+        ErrorKind::UnpairedQuote
+    );
+    // Quote characters outside ASCII are doubled the same way
+    let options = DecodeOptions::new().doubled_quote(Some('»'));
+    assert_eq!(decode_with(r"é»»\t»»", &options).unwrap(), "é»\t»");
+    let options = options.backslash_escapes(false);
+    assert_eq!(decode_with(r"»»\t", &options).unwrap(), r"»\t");
+}
+
+#[test]
+fn test_csv_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Csv);
+    assert_eq!(
+        decode_with(r#"say ""hi"" \t C:\"#, &options).unwrap(),
+        r#"say "hi" \t C:\"#
+    );
+    assert!(decode_with(r#"say "hi""#, &options).is_err());
+
+    let options = options.backslash_escapes(true);
+    assert_eq!(decode_with(r#"""\t\u{41}"#, &options).unwrap(), "\"\tA");
+}
+
+#[test]
+fn test_decode_sql_literal() {
+    assert_eq!(
+        decode_sql_literal(r"it''s C:\n", '\'', false).unwrap(),
+        r"it's C:\n"
+    );
+    assert_eq!(
+        decode_sql_literal(r#"my ""table"""#, '"', false).unwrap(),
+        r#"my "table""#
+    );
+    assert_eq!(
+        decode_sql_literal(r"it''s\n\'\Z\q", '\'', true).unwrap(),
+        "it's\n'\x1Aq"
+    );
+    assert!(decode_sql_literal("it's", '\'', false).is_err());
+    assert_eq!(
+        decode_sql_literal(r"\x41\u{41}", '\'', true).unwrap(),
+        "x41u{41}"
+    );
+}
+
+#[test]
+fn test_mysql_dialect() {
+    let options = DecodeOptions::dialect(Dialect::MySql);
+    assert_eq!(
+        decode_with(r"it''s\n\Z\q\z", &options).unwrap(),
+        "it's\n\x1Aqz"
+    );
+    // The backslash of `\%` and `\_` is kept, so that they match themselves in LIKE patterns
+    assert_eq!(decode_with(r"50\%", &options).unwrap(), r"50\%");
+    assert_eq!(decode_with(r"a\_b", &options).unwrap(), r"a\_b");
+    assert_eq!(decode_with(r"\\\%", &options).unwrap(), r"\\%");
+
+    let options = DecodeOptions::new().preserved_escapes(SimpleEscapes::NONE.with('%'));
+    assert_eq!(decode_with(r"\%\n", &options).unwrap(), "\\%\n");
+    assert!(decode_with(r"\_", &options).is_err());
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "debug-invariants")]
+
+use unicode_escape::{decode, decode_diff, decode_with, decode_with_map, lint, DecodeOptions};
+
+#[test]
+fn test_invariants_hold() {
+    let options = DecodeOptions::new()
+        .fixed_unicode(true)
+        .long_unicode(true)
+        .digit_separators(true)
+        .code_point_groups(true);
+    let cases = vec![
+        "",
+        "plain",
+        r"\t\n\r\0\\\'\x41\x80",
+        r"\u{E9}\u{D83D}\u{DE00}\u{1F468 200D 1F4BB}",
+        r"é\U0001F600\u{1_F600}",
+        r"\q\u{110000}\x4\u{12 \",
+        "\u{FEFF}\u{E9}\\u{ FFFE}",
+    ];
+    for input in cases {
+        let _ = decode(input);
+        let _ = decode_with(input, &options);
+        let _ = decode_with_map(input);
+        let _ = decode_diff(input);
+        let _ = lint(input);
+    }
+}
@@ -0,0 +1,86 @@
+use std::io::{self, Read};
+
+use std::io::Write;
+
+use unicode_escape::{
+    decode_bytes, DecodeError, DecodingReader, EncodeOptions, ErrorKind, EscapingWriter,
+};
+
+/// A reader returning its input one byte at a time.
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let Some((&first, rest)) = self.0.split_first() else {
+            return Ok(0);
+        };
+        buf[0] = first;
+        self.0 = rest;
+        Ok(1)
+    }
+}
+
+#[test]
+fn test_decoding_reader() {
+    let input = r"caf\u{E9} é \x41\r\n";
+    let mut decoded = String::new();
+    DecodingReader::new(Trickle(input.as_bytes()))
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, "caf\u{E9} é A\r\n");
+
+    let mut out = Vec::new();
+    io::copy(&mut DecodingReader::new(&b"\\t65480"[..]), &mut out).unwrap();
+    assert_eq!(out, b"\t65480");
+}
+
+#[test]
+fn test_decoding_reader_errors() {
+    let mut reader = DecodingReader::new(Trickle(br"ok \q"));
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(matches!(
+        error
+            .into_inner()
+            .unwrap()
+            .downcast_ref::<DecodeError>()
+            .map(DecodeError::kind),
+        Some(ErrorKind::UnknownEscape(_))
+    ));
+    // The reader ends after an error
+    assert_eq!(reader.read(&mut [0; 4]).unwrap(), 0);
+
+    let mut reader = DecodingReader::new(Trickle(b"\\x4"));
+    assert!(reader.read_to_end(&mut Vec::new()).is_err());
+    let mut reader = DecodingReader::new(Trickle(b"ok \xC3"));
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_escaping_writer() {
+    let mut writer = EscapingWriter::new(Vec::new());
+    writer.write_all(b"\x02 65480\r\n").unwrap();
+    // A character split across writes is escaped as a whole
+    writer.write_all(&"é\u{200B}".as_bytes()[..3]).unwrap();
+    writer.write_all(&"é\u{200B}".as_bytes()[3..]).unwrap();
+    writer.write_all(b"\xFF\"").unwrap();
+    let out = writer.finish().unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        r#"\x02 65480\r\né\u{200B}\xFF\""#
+    );
+    let mut expected = b"\x02 65480\r\n".to_vec();
+    expected.extend_from_slice("é\u{200B}".as_bytes());
+    expected.extend_from_slice(b"\xFF\"");
+    assert_eq!(
+        decode_bytes(std::str::from_utf8(&out).unwrap()).unwrap(),
+        expected
+    );
+
+    // The bytes of an incomplete character are escaped when flushing
+    let mut writer = EscapingWriter::with_options(Vec::new(), EncodeOptions::new());
+    writer.write_all(b"ok \xC3").unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.finish().unwrap(), br"ok \xC3");
+}
@@ -0,0 +1,79 @@
+use unicode_escape::{decode, is_canonical, lint, normalize, LintKind};
+
+#[test]
+fn test_lint() {
+    let input = r"\u{41}\x0a\u{1b}\xE9\u{00E9}\x1b\n\x02";
+    let warnings = lint(input);
+    let kinds: Vec<LintKind> = warnings.iter().map(|warning| warning.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            LintKind::RedundantEscape,
+            LintKind::PreferSimpleEscape,
+            LintKind::PreferHexEscape,
+            LintKind::PreferUnicodeEscape,
+            LintKind::LeadingZeros,
+            LintKind::LowercaseHex,
+        ]
+    );
+    let replacements: Vec<&str> = warnings.iter().map(|w| w.replacement.as_str()).collect();
+    assert_eq!(
+        replacements,
+        vec!["A", r"\n", r"\x1B", r"\u{E9}", r"\u{E9}", r"\x1B"]
+    );
+    assert_eq!(warnings[0].range, 0..6);
+    assert_eq!(warnings[5].range, 28..32);
+}
+
+#[test]
+fn test_lint_fixes_preserve_meaning() {
+    let input = r"\x5c\u{22}LGM\x0D\x0A";
+    let mut fixed = input.to_string();
+    for warning in lint(input).iter().rev() {
+        fixed.replace_range(warning.range.clone(), &warning.replacement);
+    }
+    assert_eq!(fixed, r#"\\\"LGM\r\n"#);
+    assert_eq!(decode(&fixed).unwrap(), decode(input).unwrap());
+    assert!(lint(&fixed).is_empty());
+}
+
+#[test]
+fn test_normalize() {
+    let input = r"\u{41}\x0a\u{1b}\xE9 \q \u{00e9}\x1b\n\x02";
+    let normalized = normalize(input);
+    assert_eq!(normalized, r"A\n\x1B\u{E9} \q \u{E9}\x1B\n\x02");
+    assert!(!is_canonical(input));
+    assert!(is_canonical(&normalized));
+    assert_eq!(normalize(&normalized), normalized);
+}
+
+#[test]
+fn test_is_canonical() {
+    let valid_cases = vec![
+        r"\x02 65480 LGM\r\n",
+        r"\u{21B5}",
+        r"\x7F\\\'",
+        "plain",
+        r"\q",
+    ];
+    for case in valid_cases {
+        assert!(is_canonical(case), "{case}");
+        assert!(lint(case).is_empty());
+    }
+
+    let invalid_cases = vec![r"\x41", r"\x0D", r"\u{21b5}", r"\u{021B5}", r"\u{7F}"];
+    for case in invalid_cases {
+        assert!(!is_canonical(case), "{case}");
+        assert_eq!(lint(case).len(), 1);
+    }
+}
+
+#[test]
+fn test_lint_surrogate_pair() {
+    let warnings = lint(r"\u{D83D}\u{DE00}");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, LintKind::SurrogatePair);
+    assert_eq!(warnings[0].range, 0..16);
+    assert_eq!(warnings[0].replacement, r"\u{1F600}");
+    assert_eq!(normalize(r"\u{D83D}\u{DE00}"), r"\u{1F600}");
+}
@@ -0,0 +1,42 @@
+use unicode_escape::{decode_with, encode_with, DecodeOptions, EncodeOptions};
+
+#[test]
+fn test_decode_message_format() {
+    let options = DecodeOptions::new().message_format(true);
+    let cases = vec![
+        (r"Hello {name}", "Hello {name}"),
+        (r"\x7Bname}", "'{'name}"),
+        (r"\u{7B}\u{7D}", "'{}'"),
+        (r"\x7B\'\x7D", "'{''}'"),
+        (r"it's \'\x7B", "it's '''{'"),
+        (r"\x7B {n} \x7D", "'{' {n} '}'"),
+        (
+            r"{count, plural, one {# \u{E9}l} other {# items}}",
+            "{count, plural, one {# \u{E9}l} other {# items}}",
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(decode_with(input, &options).unwrap(), expected, "{input:?}");
+    }
+    assert_eq!(decode_with(r"\x7B", &DecodeOptions::new()).unwrap(), "{");
+}
+
+#[test]
+fn test_encode_message_format() {
+    let options = EncodeOptions::new().message_format(true);
+    let decode = DecodeOptions::new()
+        .message_format(true)
+        .fixed_unicode(true)
+        .long_unicode(true);
+    let cases = vec![
+        ("Hello {name}", "Hello {name}"),
+        ("'{'literal'}' it's", "'{'literal'}' it's"),
+        ("{n}\u{200B}\u{1F600}\n", "{n}\\u200B\u{1F600}\\n"),
+        ("a\"b\\c", r#"a\"b\\c"#),
+    ];
+    for (input, expected) in cases {
+        let encoded = encode_with(input, &options);
+        assert_eq!(encoded, expected, "{input:?}");
+        assert_eq!(decode_with(&encoded, &decode).unwrap(), input);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "names")]
+use unicode_escape::names::{char_name, code_point_report, lookup};
+use unicode_escape::{
+    decode, decode_with, encode_with, DecodeOptions, Dialect, EncodeOptions, ErrorKind,
+};
+
+#[test]
+fn test_char_name() {
+    let cases = vec![
+        ('A', "LATIN CAPITAL LETTER A"),
+        ('\u{2011}', "NON-BREAKING HYPHEN"),
+        ('\n', "LINE FEED"),
+        ('\u{4E00}', "CJK UNIFIED IDEOGRAPH-4E00"),
+        ('\u{AC01}', "HANGUL SYLLABLE GAG"),
+        ('\u{1F600}', "GRINNING FACE"),
+    ];
+    for case in cases {
+        assert_eq!(char_name(case.0).as_deref(), Some(case.1));
+    }
+    assert_eq!(char_name('\u{E000}'), None);
+}
+
+#[test]
+fn test_lookup() {
+    let cases = vec![
+        ("BULLET", '\u{2022}'),
+        ("greek small letter alpha", 'α'),
+        ("LINE FEED", '\n'),
+        ("BOM", '\u{FEFF}'),
+        ("CJK UNIFIED IDEOGRAPH-4E00", '\u{4E00}'),
+        ("HANGUL SYLLABLE GAG", '\u{AC01}'),
+    ];
+    for case in cases {
+        assert_eq!(lookup(case.0), Some(case.1));
+    }
+    assert_eq!(lookup("NOT A CHARACTER"), None);
+    assert_eq!(lookup("CJK UNIFIED IDEOGRAPH-0041"), None);
+}
+
+#[test]
+fn test_code_point_report() {
+    let report = code_point_report(r"x\u{2011}y\x02\u{1F600}");
+    assert_eq!(report.len(), 2);
+    assert_eq!(report[0].range, 1..9);
+    assert_eq!(report[0].code_point, '\u{2011}');
+    assert_eq!(report[0].name.as_deref(), Some("NON-BREAKING HYPHEN"));
+    assert_eq!(report[1].name.as_deref(), Some("GRINNING FACE"));
+}
+
+#[test]
+fn test_decode_named_escapes() {
+    let cases = vec![
+        (r"\N{BULLET} item", "\u{2022} item"),
+        (
+            r"\N{GREEK SMALL LETTER ALPHA}\N{greek small letter beta}",
+            "αβ",
+        ),
+        (r"\N{LINE FEED}\N{CJK UNIFIED IDEOGRAPH-4E00}", "\n\u{4E00}"),
+    ];
+    for case in cases {
+        assert_eq!(decode(case.0).unwrap(), case.1);
+    }
+
+    let unknown_cases = vec![r"\N{NOT A CHARACTER}", r"\N{}"];
+    for case in unknown_cases {
+        assert!(
+            decode(case).unwrap_err().kind() == ErrorKind::UnknownCharacterName,
+            "{case}"
+        );
+    }
+    let unterminated_cases = vec![r"\N{BULLET", r"\NBULLET"];
+    for case in unterminated_cases {
+        assert!(
+            decode(case).unwrap_err().kind() == ErrorKind::UnterminatedUnicode,
+            "{case}"
+        );
+    }
+}
+
+#[test]
+fn test_dialect_named_escapes() {
+    let options = DecodeOptions::dialect(Dialect::Python);
+    assert_eq!(
+        decode_with(r"\N{GREEK SMALL LETTER ALPHA} \N{BOM}", &options).unwrap(),
+        "\u{3B1} \u{FEFF}"
+    );
+    assert!(decode_with(r"\N{NOT A CHARACTER}", &options).is_err());
+    let options = DecodeOptions::dialect(Dialect::Json);
+    assert!(decode_with(r"\N{BULLET}", &options).is_err());
+}
+
+#[test]
+fn test_encode_named_escapes() {
+    let options = EncodeOptions::new().named_escapes(true);
+    let input = "\u{2022} item\n\u{1B}[0m\u{200B}";
+    let encoded = encode_with(input, &options);
+    assert_eq!(encoded, r"• item\n\N{ESCAPE}[0m\N{ZERO WIDTH SPACE}");
+    assert_eq!(decode(&encoded).unwrap(), input);
+    // Characters without a name keep their Unicode escape sequence
+    assert_eq!(
+        encode_with("\u{FFFF}\u{2028}", &options),
+        r"\u{FFFF}\N{LINE SEPARATOR}"
+    );
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "unicode-normalization")]
+
+use unicode_escape::normalization::{normalize, NormalizationForm};
+use unicode_escape::{decode_with, DecodeOptions};
+
+#[test]
+fn test_normalize() {
+    // (input, NFC, NFD, NFKC, NFKD)
+    let cases = vec![
+        ("e\u{301}", "\u{E9}", "e\u{301}", "\u{E9}", "e\u{301}"),
+        ("\u{FB01}", "\u{FB01}", "\u{FB01}", "fi", "fi"),
+        (
+            "\u{D55C}",
+            "\u{D55C}",
+            "\u{1112}\u{1161}\u{11AB}",
+            "\u{D55C}",
+            "\u{1112}\u{1161}\u{11AB}",
+        ),
+        (
+            "a\u{302}\u{323}",
+            "\u{1EAD}",
+            "a\u{323}\u{302}",
+            "\u{1EAD}",
+            "a\u{323}\u{302}",
+        ),
+        ("\u{212B}", "\u{C5}", "A\u{30A}", "\u{C5}", "A\u{30A}"),
+        (
+            "\u{1E0B}\u{323}",
+            "\u{1E0D}\u{307}",
+            "d\u{323}\u{307}",
+            "\u{1E0D}\u{307}",
+            "d\u{323}\u{307}",
+        ),
+        (
+            "\u{2460}\u{301}",
+            "\u{2460}\u{301}",
+            "\u{2460}\u{301}",
+            "1\u{301}",
+            "1\u{301}",
+        ),
+    ];
+    for (input, nfc, nfd, nfkc, nfkd) in cases {
+        assert_eq!(normalize(input, NormalizationForm::Nfc), nfc);
+        assert_eq!(normalize(input, NormalizationForm::Nfd), nfd);
+        assert_eq!(normalize(input, NormalizationForm::Nfkc), nfkc);
+        assert_eq!(normalize(input, NormalizationForm::Nfkd), nfkd);
+    }
+}
+
+#[test]
+fn test_decode_normalized() {
+    let options = DecodeOptions::new().normalization(Some(NormalizationForm::Nfc));
+    assert_eq!(
+        decode_with(r"caf\u{65}\u{301}", &options).unwrap(),
+        "caf\u{E9}"
+    );
+    assert_eq!(
+        decode_with(r"caf\u{65}\u{301}", &DecodeOptions::new()).unwrap(),
+        "cafe\u{301}"
+    );
+
+    let options = options.normalization(Some(NormalizationForm::Nfd));
+    assert_eq!(decode_with(r"\u{E9}\t", &options).unwrap(), "e\u{301}\t");
+}
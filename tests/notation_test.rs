@@ -0,0 +1,54 @@
+use unicode_escape::{
+    encode_with, format_codepoint_notation, parse_codepoint_notation, EncodeOptions, ErrorKind,
+};
+
+#[test]
+fn test_parse_codepoint_notation() {
+    let cases = vec![
+        ("U+1F600", '\u{1F600}'),
+        ("U+00E9", '\u{E9}'),
+        ("u+00e9", '\u{E9}'),
+        ("U+10FFFF", '\u{10FFFF}'),
+    ];
+    for (notation, expected) in cases {
+        assert_eq!(parse_codepoint_notation(notation).unwrap(), expected);
+        assert_eq!(
+            parse_codepoint_notation(&format_codepoint_notation(expected)).unwrap(),
+            expected
+        );
+    }
+
+    let invalid_cases = vec![
+        "1F600",
+        "U+E9",
+        "U+1234567",
+        "U+D800",
+        "U+110000",
+        "U+12G4",
+        " U+0041",
+    ];
+    for case in invalid_cases {
+        assert!(parse_codepoint_notation(case).is_err(), "{}", case);
+    }
+
+    let kind = |notation| parse_codepoint_notation(notation).unwrap_err().kind();
+    assert_eq!(kind("1F600"), ErrorKind::MissingNotationPrefix);
+    assert_eq!(kind(" U+0041"), ErrorKind::MissingNotationPrefix);
+    assert_eq!(kind(""), ErrorKind::MissingNotationPrefix);
+    assert_eq!(kind("U+ZZ"), ErrorKind::InvalidHexDigit('Z'));
+    assert_eq!(kind("u+12G4"), ErrorKind::InvalidHexDigit('G'));
+    assert_eq!(kind("u+41"), ErrorKind::UnexpectedEof);
+    assert_eq!(kind("U+1234567"), ErrorKind::OverlongUnicode);
+}
+
+#[test]
+fn test_encode_codepoint_notation() {
+    assert_eq!(format_codepoint_notation('\u{E9}'), "U+00E9");
+    assert_eq!(format_codepoint_notation('\u{1F600}'), "U+1F600");
+
+    let options = EncodeOptions::new().codepoint_notation(true);
+    assert_eq!(
+        encode_with("zero\u{200B}width \"\\\"\t\u{E9}", &options),
+        "zeroU+200Bwidth \"\\\"U+0009\u{E9}"
+    );
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "parallel")]
+
+use unicode_escape::{
+    decode_parallel, decode_with, BomPolicy, DecodeOptions, ErrorKind, LineContinuation,
+};
+
+/// Returns `lines` lines of escaped log output, about 48 bytes each.
+fn dump(lines: usize) -> String {
+    (0..lines)
+        .map(|i| format!("{i:08} \\x02 65480\\tLGM \\u{{21B5}} caf\\u{{E9}} ok\\\\\n"))
+        .collect()
+}
+
+#[test]
+fn test_decode_parallel() {
+    let input = dump(100_000);
+    let options = DecodeOptions::new();
+    let expected = decode_with(&input, &options).unwrap();
+    for threads in [0, 1, 2, 3, 8] {
+        assert_eq!(
+            decode_parallel(&input, &options, threads).unwrap(),
+            expected
+        );
+    }
+    assert_eq!(decode_parallel("short", &options, 4).unwrap(), "short");
+}
+
+#[test]
+fn test_decode_parallel_errors() {
+    let mut input = dump(100_000);
+    let offset = input[..input.len() - 1].rfind('\n').unwrap() + 1;
+    input.insert_str(offset, r"\q");
+    let options = DecodeOptions::new();
+    let error = decode_parallel(&input, &options, 4).unwrap_err();
+    assert_eq!(error, decode_with(&input, &options).unwrap_err());
+    assert_eq!(error.kind(), ErrorKind::UnknownEscape('q'));
+    assert_eq!(error.offset(), Some(offset));
+}
+
+#[test]
+fn test_decode_parallel_options() {
+    // A byte order mark decoded at the start of a later part is not at the start of the result
+    let input = dump(50_000) + r"\u{FEFF}" + &dump(50_000);
+    let options = DecodeOptions::new().bom(BomPolicy::Reject);
+    assert_eq!(
+        decode_parallel(&input, &options, 2).unwrap(),
+        decode_with(&input, &options).unwrap()
+    );
+    // Line continuations may join lines, so the input is not split
+    let input = dump(50_000).replace("ok\\\\\n", "ok\\\n  ");
+    let options = DecodeOptions::new().line_continuation(Some(LineContinuation::TrimWhitespace));
+    assert_eq!(
+        decode_parallel(&input, &options, 2).unwrap(),
+        decode_with(&input, &options).unwrap()
+    );
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "generators")]
+
+use unicode_escape::generators::EscapeGenerator;
+use unicode_escape::{
+    decode, decode_diff, decode_stats, decode_with_map, encode, escape_density, find_escapes,
+    is_canonical, lint, normalize, render_ansi, render_html, validate, validate_all,
+};
+
+const SEEDS: u64 = 2000;
+
+#[test]
+fn test_round_trip() {
+    for seed in 0..SEEDS {
+        let (escaped, decoded) = EscapeGenerator::new(seed).valid(16);
+        assert_eq!(decode(&escaped).unwrap(), decoded, "{:?}", escaped);
+        assert_eq!(decode(&encode(&decoded)).unwrap(), decoded);
+        assert_eq!(decode(&normalize(&escaped)).unwrap(), decoded);
+        assert!(is_canonical(&normalize(&escaped)));
+        assert!(validate(&escaped).is_ok());
+    }
+}
+
+#[test]
+fn test_invalid_input_is_rejected() {
+    for seed in 0..SEEDS {
+        let escaped = EscapeGenerator::new(seed).invalid(16);
+        assert!(decode(&escaped).is_err(), "{:?}", escaped);
+        assert!(!validate_all(&escaped).is_empty());
+    }
+}
+
+#[test]
+fn test_never_panics() {
+    for seed in 0..SEEDS {
+        let input = EscapeGenerator::new(seed).arbitrary(16);
+        let _ = decode(&input);
+        let _ = decode_stats(&input);
+        let _ = decode_with_map(&input);
+        let _ = decode_diff(&input).to_string();
+        let _ = escape_density(&input);
+        let _ = find_escapes(&input).count();
+        let _ = validate_all(&input);
+        let _ = lint(&input);
+        let _ = normalize(&input);
+        let _ = render_ansi(&input);
+        let _ = render_html(&input);
+    }
+}
@@ -0,0 +1,78 @@
+use unicode_escape::{decode, render_ansi, render_html, DecodeError, ErrorKind};
+
+#[test]
+fn test_render_ansi() {
+    let rendered = render_ansi(r"\x02 65480\u{21B5}\q");
+    let expected = concat!(
+        "\x1b[36m\\x02\x1b[0m\x1b[2m⟨STX⟩\x1b[0m",
+        " 65480",
+        "\x1b[35m\\u{21B5}\x1b[0m\x1b[2m⟨↵⟩\x1b[0m",
+        "\x1b[1;31m\\q\x1b[0m",
+    );
+    assert_eq!(rendered, expected);
+    assert_eq!(render_ansi("plain"), "plain");
+}
+
+#[test]
+fn test_render_html() {
+    let rendered = render_html(r"<\x02> \u{21B5}\q");
+    let expected = concat!(
+        "&lt;",
+        r#"<span class="escape escape-hex" title="U+0002 STX">\x02</span>"#,
+        "&gt; ",
+        r#"<span class="escape escape-unicode" title="U+21B5">\u{21B5}</span>"#,
+        r#"<span class="escape escape-invalid" title="invalid escape sequence">\q</span>"#,
+    );
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn test_render_error() {
+    let source = "name = ok\nvalue = 65480\\q LGM";
+    let error = decode(source).unwrap_err();
+    let expected = concat!(
+        "error: unknown escape sequence `\\q`\n",
+        " --> line 2, column 14\n",
+        "  |\n",
+        "2 | value = 65480\\q LGM\n",
+        "  |              ^^ help: write `\\\\` for a literal backslash\n",
+    );
+    assert_eq!(error.render(source), expected);
+    assert_eq!(
+        error.help(source),
+        Some(r"write `\\` for a literal backslash")
+    );
+
+    // Whether the opening or the closing brace is missing depends on the escape sequence
+    let source = r"code = \u{12";
+    let error = decode(source).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnterminatedUnicode);
+    assert_eq!(error.help(source), Some("add the closing `}`"));
+    let source = r"code = \u12";
+    let error = decode(source).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnterminatedUnicode);
+    assert_eq!(
+        error.help(source),
+        Some(r"enclose the code point in braces, e.g. `\u{41}`")
+    );
+    assert_eq!(ErrorKind::UnterminatedUnicode.help(), None);
+
+    // The carets and the quoted escape sequence cover all of a braced escape sequence
+    let source = r"sign = \u{110000}!";
+    let rendered = decode(source).unwrap_err().render(source);
+    let mut lines = rendered.lines();
+    assert_eq!(
+        lines.next(),
+        Some(r"error: `\u{110000}` produces the out of range value 0x110000")
+    );
+    assert_eq!(
+        lines.nth(3),
+        Some("  |        ^^^^^^^^^^ help: Unicode code points range from U+0000 to U+10FFFF")
+    );
+
+    assert_eq!(
+        DecodeError::from(ErrorKind::WriteFailed).render(source),
+        "error: the decoded text could not be written\n"
+    );
+    assert_eq!(ErrorKind::WriteFailed.help(), None);
+}
@@ -0,0 +1,75 @@
+use unicode_escape::{decode, decode_with, DecodeOptions, Dialect, ErrorKind};
+
+/// String literal bodies and how rustc decodes them, generated by `scripts/rustc_corpus.py`.
+const CORPUS: &str = include_str!("data/rustc_corpus.txt");
+
+#[test]
+fn test_rustc_corpus() {
+    let options = DecodeOptions::rustc_compat();
+    let mut cases = 0;
+    for line in CORPUS.lines().filter(|line| !line.starts_with('#')) {
+        let (input, expected) = line.split_once('\t').unwrap();
+        let decoded = decode_with(input, &options);
+        if expected == "error" {
+            assert!(decoded.is_err(), "{input:?} decoded to {decoded:?}");
+        } else {
+            let expected: String = expected
+                .split(' ')
+                .map(|code| char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap())
+                .collect();
+            assert_eq!(decoded.ok(), Some(expected), "{input:?}");
+        }
+        cases += 1;
+    }
+    assert!(cases > 1000);
+}
+
+#[test]
+fn test_rustc_compat() {
+    let options = DecodeOptions::rustc_compat();
+    let cases = vec![
+        (r"\x7F", "\x7F"),
+        (r"\u{10_FFFF}", "\u{10FFFF}"),
+        (r"\u{000041}", "A"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(decode_with(input, &options).unwrap(), expected);
+    }
+
+    let invalid_cases = vec![
+        (r"\x80", ErrorKind::CodePointOutOfRange(0x80)),
+        (r"\u{_41}", ErrorKind::InvalidHexDigit('_')),
+        (r"\u{0000041}", ErrorKind::OverlongUnicode),
+        (r"\u{D83D}\u{DE00}", ErrorKind::SurrogateCodePoint(0xD83D)),
+    ];
+    for (input, expected) in invalid_cases {
+        let error = decode_with(input, &options).unwrap_err();
+        assert_eq!(error.kind(), expected, "{input:?}");
+    }
+
+    assert_eq!(decode(r"\x80").unwrap(), "\u{80}");
+    assert_eq!(
+        decode_with(r"\x80", &DecodeOptions::new().ascii_hex(true))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::CodePointOutOfRange(0x80)
+    );
+}
+
+#[test]
+fn test_rust_dialect() {
+    let options = DecodeOptions::dialect(Dialect::Rust);
+    assert_eq!(
+        decode_with("a\\\n  \\t\n\n  b\\\n", &options).unwrap(),
+        "a\t\n\n  b"
+    );
+    assert_eq!(decode_with("x\\\n\\n y", &options).unwrap(), "x\n y");
+    assert!(decode_with("a\\\n", &DecodeOptions::rustc_compat()).is_err());
+    for (input, _) in CORPUS.lines().filter_map(|line| line.split_once('\t')) {
+        assert_eq!(
+            format!("{:?}", decode_with(input, &options)),
+            format!("{:?}", decode_with(input, &DecodeOptions::rustc_compat())),
+            "{input:?}"
+        );
+    }
+}
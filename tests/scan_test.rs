@@ -0,0 +1,58 @@
+use unicode_escape::{find_escapes, needs_decoding, EscapeKind, EscapeSpan};
+
+#[test]
+fn test_find_escapes() {
+    let spans: Vec<EscapeSpan> = find_escapes(r"\x02 65480 \u{21B5}LGM\r\n").collect();
+    let expected = vec![
+        EscapeSpan {
+            kind: EscapeKind::Hex,
+            range: 0..4,
+        },
+        EscapeSpan {
+            kind: EscapeKind::Unicode,
+            range: 11..19,
+        },
+        EscapeSpan {
+            kind: EscapeKind::Simple,
+            range: 22..24,
+        },
+        EscapeSpan {
+            kind: EscapeKind::Simple,
+            range: 24..26,
+        },
+    ];
+    assert_eq!(spans, expected);
+    assert_eq!(find_escapes("no escapes here").count(), 0);
+}
+
+#[test]
+fn test_find_invalid_escapes() {
+    let spans: Vec<EscapeSpan> = find_escapes(r"\q\xZZ\é\").collect();
+    let expected = vec![
+        EscapeSpan {
+            kind: EscapeKind::Invalid,
+            range: 0..2,
+        },
+        EscapeSpan {
+            kind: EscapeKind::Invalid,
+            range: 2..4,
+        },
+        EscapeSpan {
+            kind: EscapeKind::Invalid,
+            range: 6..9,
+        },
+        EscapeSpan {
+            kind: EscapeKind::Invalid,
+            range: 9..10,
+        },
+    ];
+    assert_eq!(spans, expected);
+}
+
+#[test]
+fn test_needs_decoding() {
+    assert!(needs_decoding(r"\x02 65480 LGM\r\n"));
+    assert!(needs_decoding(r"C:\temp\u{21B5}"));
+    assert!(!needs_decoding("plain text"));
+    assert!(!needs_decoding(r"C:\Program Files\"));
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "unicode-segmentation")]
+use unicode_escape::segmentation::{grapheme_boundaries, grapheme_report, SplitCluster};
+use unicode_escape::{
+    decode_with, encode, encode_with, DecodeOptions, EmojiSequences, EncodeOptions,
+};
+
+#[test]
+fn test_grapheme_boundaries() {
+    let cases = vec![
+        ("abc", vec![0, 1, 2]),
+        ("\r\na", vec![0, 2]),
+        ("e\u{301}x", vec![0, 3]),
+        ("\u{1F468}\u{200D}\u{1F4BB}!", vec![0, 11]),
+        ("\u{1F1E9}\u{1F1EA}\u{1F1EB}", vec![0, 8]),
+        ("\u{1100}\u{1161}\u{11A8}", vec![0]),
+        ("", vec![]),
+    ];
+    for case in cases {
+        assert_eq!(grapheme_boundaries(case.0), case.1, "{:?}", case.0);
+    }
+}
+
+#[test]
+fn test_grapheme_report() {
+    let report = grapheme_report(r"ae\u{301}\u{1F468}\u{200D}\u{1F4BB} x").unwrap();
+    assert_eq!(report.decoded, "ae\u{301}\u{1F468}\u{200D}\u{1F4BB} x");
+    assert_eq!(report.cluster_count(), 5);
+    assert_eq!(
+        report.split_clusters,
+        vec![
+            SplitCluster {
+                output: 1..4,
+                input: 1..9,
+            },
+            SplitCluster {
+                output: 4..15,
+                input: 9..35,
+            },
+        ]
+    );
+
+    let report = grapheme_report("e\u{301} plain").unwrap();
+    assert!(report.split_clusters.is_empty());
+    assert!(grapheme_report(r"\q").is_err());
+}
+
+#[test]
+fn test_encode_emoji_sequences() {
+    let input = "dev: \u{1F468}\u{200D}\u{1F4BB}\u{1F1EB}\u{1F1F7} \u{200D}\n";
+    assert_eq!(
+        encode(input),
+        "dev: \u{1F468}\\u{200D}\u{1F4BB}\u{1F1EB}\u{1F1F7} \\u{200D}\\n"
+    );
+
+    let keep = EncodeOptions::new().emoji_sequences(EmojiSequences::Keep);
+    assert_eq!(
+        encode_with(input, &keep),
+        "dev: \u{1F468}\u{200D}\u{1F4BB}\u{1F1EB}\u{1F1F7} \\u{200D}\\n"
+    );
+
+    let group = EncodeOptions::new().emoji_sequences(EmojiSequences::Group);
+    let encoded = encode_with(input, &group);
+    assert_eq!(
+        encoded,
+        "dev: \\u{1F468 200D 1F4BB}\u{1F1EB}\u{1F1F7} \\u{200D}\\n"
+    );
+    let options = DecodeOptions::new().code_point_groups(true);
+    assert_eq!(decode_with(&encoded, &options).unwrap(), input);
+}
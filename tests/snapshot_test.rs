@@ -0,0 +1,56 @@
+use std::{env, fs, path::Path};
+
+use unicode_escape::{render_ansi, render_html, render_report};
+
+// The example binary only runs `main`, which the tests do not need
+#[allow(dead_code)]
+#[path = "../examples/dialects.rs"]
+mod dialects;
+
+/// Compares `actual` against the stored snapshot `tests/snapshots/<name>.snap`.
+///
+/// Run the tests with `UPDATE_SNAPSHOTS=1` to write the current output as the new snapshots, then
+/// review the changes with `git diff`.
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.snap"));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing snapshot {path:?}, run the tests with UPDATE_SNAPSHOTS=1 to create it")
+    });
+    assert!(
+        expected == actual,
+        "snapshot {name} changed, run the tests with UPDATE_SNAPSHOTS=1 to accept\n\
+         --- expected\n{expected}\n--- actual\n{actual}"
+    );
+}
+
+const SAMPLE: &str = concat!(
+    r"name = \u{00e9}t\x41t\q",
+    "\n",
+    "\tpath = C:\\temp\\x4 \\u{D83D}\\u{DE00}\n",
+    r"emoji = \u{1F600} \u{110000}",
+);
+
+#[test]
+fn test_report_snapshot() {
+    assert_snapshot("report_sample", &render_report(SAMPLE));
+    assert_snapshot("report_multiline", &render_report("ok\n\n  \\z \\x0a"));
+    assert_eq!(render_report(r"canonical \n \u{E9}"), "");
+}
+
+#[test]
+fn test_render_snapshot() {
+    assert_snapshot("render_html_sample", &render_html(SAMPLE));
+    let ansi = render_ansi(SAMPLE).replace('\x1b', r"\e");
+    assert_snapshot("render_ansi_sample", &ansi);
+}
+
+#[test]
+fn test_dialects_snapshot() {
+    assert_snapshot("dialects", &dialects::report());
+}
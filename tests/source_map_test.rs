@@ -0,0 +1,27 @@
+use unicode_escape::decode_with_map;
+
+#[test]
+fn test_decode_with_map() {
+    let input = r"\x02 65↵ \u{21B5}\r\n";
+    let (decoded, map) = decode_with_map(input).unwrap();
+    assert_eq!(decoded, "\x02 65↵ ↵\r\n");
+    assert_eq!(map.len(), decoded.chars().count());
+
+    let offsets: Vec<usize> = (0..=map.len())
+        .map(|i| map.input_offset(i).unwrap())
+        .collect();
+    assert_eq!(offsets, vec![0, 4, 5, 6, 7, 10, 11, 19, 21, 23]);
+    assert_eq!(map.input_range(6), Some(11..19));
+    assert_eq!(map.input_range(4), Some(7..10));
+    assert_eq!(map.input_range(9), None);
+    assert_eq!(map.input_offset(10), None);
+}
+
+#[test]
+fn test_decode_with_map_empty() {
+    let (decoded, map) = decode_with_map("").unwrap();
+    assert!(decoded.is_empty());
+    assert!(map.is_empty());
+    assert_eq!(map.input_offset(0), Some(0));
+    assert!(decode_with_map(r"\q").is_err());
+}
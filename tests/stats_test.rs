@@ -0,0 +1,33 @@
+use unicode_escape::{decode_stats, escape_density, EscapeKind};
+
+#[test]
+fn test_decode_stats() {
+    let stats = decode_stats(r"\x02 65480 LGM\r\n ↵\u{21B5}").unwrap();
+    assert_eq!(stats.escapes(EscapeKind::Simple), 2);
+    assert_eq!(stats.escapes(EscapeKind::Hex), 1);
+    assert_eq!(stats.escapes(EscapeKind::Unicode), 1);
+    assert_eq!(stats.escapes(EscapeKind::Invalid), 0);
+    assert_eq!(stats.total_escapes(), 4);
+    assert_eq!(stats.decoded_chars, 16);
+    assert_eq!(stats.decoded_len, 20);
+    assert_eq!(stats.non_ascii_chars, 2);
+}
+
+#[test]
+fn test_decode_stats_invalid() {
+    assert!(decode_stats(r"\x02 \65480 LGM\r\n").is_err());
+}
+
+#[test]
+fn test_escape_density() {
+    let density = escape_density(r"\x02\u{1B}\q\x7");
+    assert_eq!(density.escapes_per_byte, 4.0 / 15.0);
+    assert_eq!(density.invalid_escapes_per_byte, 2.0 / 15.0);
+    assert_eq!(density.control_chars_per_byte, 2.0 / 15.0);
+    assert_eq!(density.unicode_escapes_per_byte, 1.0 / 15.0);
+
+    let density = escape_density("LGM\r\n");
+    assert_eq!(density.escapes_per_byte, 0.0);
+    assert_eq!(density.control_chars_per_byte, 2.0 / 5.0);
+    assert_eq!(escape_density(""), Default::default());
+}
@@ -0,0 +1,150 @@
+use unicode_escape::{
+    decode_with, BomPolicy, CaseFolding, DecodeError, DecodeOptions, Decoder, Dialect, ErrorKind,
+};
+
+/// Decodes `chunks` with a `Decoder`, concatenating its output.
+fn decode_chunks(chunks: &[&str], options: &DecodeOptions) -> Result<String, DecodeError> {
+    let mut decoder = Decoder::with_options(options.clone());
+    let mut result = String::new();
+    for chunk in chunks {
+        result.push_str(&decoder.feed(chunk)?);
+    }
+    result.push_str(&decoder.finish()?);
+    Ok(result)
+}
+
+/// Checks that splitting `input` at any one or two positions decodes like `decode_with`.
+fn assert_splits(input: &str, options: &DecodeOptions) {
+    let expected = decode_with(input, options).map_err(|error| format!("{error:?}"));
+    let boundaries: Vec<usize> = (0..=input.len())
+        .filter(|&i| input.is_char_boundary(i))
+        .collect();
+    for &i in &boundaries {
+        for &j in boundaries.iter().filter(|&&j| j >= i) {
+            let chunks = [&input[..i], &input[i..j], &input[j..]];
+            let actual = decode_chunks(&chunks, options).map_err(|error| format!("{error:?}"));
+            assert_eq!(actual, expected, "{chunks:?}");
+        }
+    }
+}
+
+const SAMPLES: [&str; 29] = [
+    r"\x02 65480 LGM\x03\r\n",
+    r"caf\u{E9} \u{1F600}!",
+    r"\u{D83D}\u{DE00} and \u{1_F600}",
+    r"\\\\\u{21B5}\\",
+    r"\u00E9\U0001F600",
+    r"\/\b\uD83D\uDE00\u00e9",
+    r"a\qb",
+    r"trailing \x4",
+    r"\u{110000}",
+    r"\u{1F468 200D 1F4BB} group",
+    r"\u{FEFF}bom",
+    "\u{FEFF}a\\q",
+    r"\x7B\'\x7D\x7B",
+    "\\101\\12\\q\\\r\n\\\r\\\n\\7",
+    r"\x41BC \x4 \x0041\?",
+    "a\\\n \t\n b\\\n\\t",
+    "a\\ \t\r\n\n b\\ c",
+    r"\cA\c\\\c?\u41\u00e9\U1F600\x4\12",
+    r"\uD83D\u{DE00} \u{D83D}\uDE00 \uD83Dx \400\8",
+    "a\\\u{2028}b\\\r\nc\\\rd",
+    "e\\u{301} \\u{212B}A\\u{30A}",
+    r"\101\377\0 \U0001F600\x41",
+    r"\uu0041\u005cn\u005c\u0031\101\\u0041\uD83D\uuDE00\u005c",
+    "\\65\\0651\\256 a\\z \n\t b\\\n\rc\\\r\nd\\\n",
+    r"it''s \' \x414 \q''",
+    r"\u{qAF} \u{1F6Q0}",
+    r"_\u{BULLET}\N{NOPE} x",
+    r"a\N{NOPE}\N{7x-}",
+    r"\u{41 4G 42} \u{110000",
+];
+
+#[test]
+fn test_decoder_chunks() {
+    let mut decoder = Decoder::new();
+    assert_eq!(decoder.feed(r"65480\u{1").unwrap(), "65480");
+    assert_eq!(decoder.pending(), r"\u{1");
+    assert_eq!(decoder.feed(r"F600} \").unwrap(), "\u{1F600} ");
+    assert_eq!(decoder.pending(), r"\");
+    assert_eq!(decoder.feed("t").unwrap(), "\t");
+    assert_eq!(decoder.pending(), "");
+    assert_eq!(decoder.finish().unwrap(), "");
+}
+
+#[test]
+fn test_decoder_errors() {
+    let mut decoder = Decoder::new();
+    assert_eq!(
+        decoder.feed(r"ok \q").unwrap_err().kind(),
+        ErrorKind::UnknownEscape('q')
+    );
+    assert_eq!(decoder.feed("again").unwrap(), "again");
+    // Spans refer to the input fed since the decoder started over, not to the last chunk
+    assert_eq!(decoder.feed(r"and \x4").unwrap(), "and ");
+    assert_eq!(decoder.feed(r"G").unwrap_err().span(), Some(9..12));
+
+    let mut decoder = Decoder::new();
+    assert_eq!(decoder.feed(r"end \x4").unwrap(), "end ");
+    assert_eq!(
+        decoder.finish().unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_decoder_matches_decode_with() {
+    let dialects = [
+        DecodeOptions::new(),
+        DecodeOptions::rustc_compat(),
+        DecodeOptions::new()
+            .fixed_unicode(true)
+            .long_unicode(true)
+            .digit_separators(true)
+            .code_point_groups(true),
+        DecodeOptions::new().message_format(true),
+        DecodeOptions::new().bom(BomPolicy::Strip),
+        DecodeOptions::new().bom(BomPolicy::Reject),
+        DecodeOptions::new().case_folding(Some(CaseFolding::Full)),
+        DecodeOptions::dialect(Dialect::Json),
+        DecodeOptions::dialect(Dialect::Python),
+        DecodeOptions::dialect(Dialect::C),
+        DecodeOptions::dialect(Dialect::Rust),
+        DecodeOptions::dialect(Dialect::Toml),
+        DecodeOptions::dialect(Dialect::Shell),
+        DecodeOptions::dialect(Dialect::JavaScript),
+        DecodeOptions::dialect(Dialect::Go),
+        DecodeOptions::dialect(Dialect::Java),
+        DecodeOptions::dialect(Dialect::CSharp),
+        DecodeOptions::dialect(Dialect::Lua),
+        DecodeOptions::dialect(Dialect::PostgreSql),
+        DecodeOptions::dialect(Dialect::Csv),
+        DecodeOptions::dialect(Dialect::Csv).backslash_escapes(true),
+        DecodeOptions::dialect(Dialect::MySql),
+    ];
+    for options in &dialects {
+        for input in SAMPLES {
+            assert_splits(input, options);
+        }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn test_decoder_normalization() {
+    use unicode_escape::normalization::NormalizationForm;
+
+    let options = DecodeOptions::new().normalization(Some(NormalizationForm::Nfc));
+    for input in SAMPLES {
+        assert_splits(input, &options);
+    }
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_decoder_named_escapes() {
+    assert_splits(
+        r"\N{BULLET} \N{GREEK SMALL LETTER ALPHA}",
+        &DecodeOptions::new(),
+    );
+}
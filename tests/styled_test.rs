@@ -0,0 +1,58 @@
+use unicode_escape::{
+    decode_styled, DecodeOptions, Dialect, EscapeKind, LineContinuation, StyledSegment,
+};
+
+#[test]
+fn test_decode_styled() {
+    let input = r"A\x41\u{41 42}\n";
+    let options = DecodeOptions::new().code_point_groups(true);
+    let styled = decode_styled(input, &options).unwrap();
+    assert_eq!(styled.decoded(), "AAAB\n");
+    assert_eq!(styled.encode(), input);
+    let segments = styled.segments();
+    assert_eq!(segments.len(), 5);
+    assert_eq!(segments[0], StyledSegment::Literal("A".to_string()));
+    assert_eq!(
+        segments[1],
+        StyledSegment::Escape {
+            source: r"\x41".to_string(),
+            kind: EscapeKind::Hex,
+            decoded: 'A',
+        }
+    );
+    assert!(matches!(&segments[2], StyledSegment::Escape { source, .. } if source == r"\u{41 "));
+    assert!(matches!(&segments[3], StyledSegment::Escape { source, .. } if source == "42}"));
+
+    assert!(decode_styled(r"ok\q", &DecodeOptions::new()).is_err());
+}
+
+#[test]
+fn test_decode_styled_substitutions() {
+    let options = DecodeOptions::dialect(Dialect::PostgreSql);
+    let styled = decode_styled(r"it''s\q", &options).unwrap();
+    assert_eq!(styled.decoded(), "it'sq");
+    assert_eq!(styled.encode(), r"it''s\q");
+    assert_eq!(
+        styled.segments()[1],
+        StyledSegment::Substituted {
+            source: "''".to_string(),
+            decoded: "'".to_string(),
+        }
+    );
+
+    let options = DecodeOptions::new().line_continuation(Some(LineContinuation::TrimWhitespace));
+    let input = "one \\\n    two";
+    let styled = decode_styled(input, &options).unwrap();
+    assert_eq!(styled.decoded(), "one two");
+    assert_eq!(styled.encode(), input);
+}
+
+#[test]
+fn test_modify_styled() {
+    let mut styled = decode_styled(r"\x48i\u{21}", &DecodeOptions::new()).unwrap();
+    let segments = styled.segments_mut();
+    segments[1] = StyledSegment::Text("o \"you\"".to_string());
+    segments.push(StyledSegment::Text("\t".to_string()));
+    assert_eq!(styled.decoded(), "Ho \"you\"!\t");
+    assert_eq!(styled.encode(), r#"\x48o \"you\"\u{21}\t"#);
+}
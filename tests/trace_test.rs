@@ -0,0 +1,122 @@
+#![cfg(feature = "trace")]
+
+use std::cell::RefCell;
+
+use unicode_escape::trace::{self, Fallback, TraceEvent};
+use unicode_escape::{
+    decode_lossy_with, decode_with, BomPolicy, DecodeOptions, EscapeKind, NoncharacterPolicy,
+};
+
+thread_local! {
+    static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(event: &TraceEvent) {
+    EVENTS.with(|events| events.borrow_mut().push(format!("{event:?}")));
+}
+
+/// Decodes `input` and returns the debug representations of the traced events.
+fn trace_decode(input: &str, options: &DecodeOptions) -> Vec<String> {
+    EVENTS.with(|events| events.borrow_mut().clear());
+    let _ = trace::with_hook(record, || decode_with(input, options));
+    EVENTS.with(|events| events.take())
+}
+
+#[test]
+fn test_trace_events() {
+    let events = trace_decode(r"a\x41\u{E9}", &DecodeOptions::new());
+    let expected = vec![
+        format!(
+            "{:?}",
+            TraceEvent::Started {
+                input: r"a\x41\u{E9}"
+            }
+        ),
+        format!(
+            "{:?}",
+            TraceEvent::Escape {
+                kind: EscapeKind::Hex,
+                range: 1..5,
+                value: 'A'
+            }
+        ),
+        format!(
+            "{:?}",
+            TraceEvent::Escape {
+                kind: EscapeKind::Unicode,
+                range: 5..11,
+                value: '\u{E9}'
+            }
+        ),
+        format!("{:?}", TraceEvent::Finished { output_len: 4 }),
+    ];
+    assert_eq!(events, expected);
+
+    let events = trace_decode(r"ok \q", &DecodeOptions::new());
+    assert_eq!(events.len(), 2);
+    assert!(
+        events[1].starts_with("Error { range: 3..5, error: DecodeError { kind: UnknownEscape('q')")
+    );
+}
+
+#[test]
+fn test_trace_fallbacks() {
+    let options = DecodeOptions::new()
+        .noncharacters(NoncharacterPolicy::Replace)
+        .bom(BomPolicy::Strip);
+    let events = trace_decode(r"\u{FEFF}\u{FFFE}", &options);
+    let fallback = |fallback| format!("{:?}", TraceEvent::Fallback(fallback));
+    // The byte order mark is stripped before the escape sequences following it are decoded
+    assert_eq!(events[2], fallback(Fallback::ByteOrderMarkStripped));
+    assert_eq!(
+        events[3],
+        fallback(Fallback::NoncharacterReplaced('\u{FFFE}'))
+    );
+    assert_eq!(events.len(), 6);
+}
+
+#[test]
+fn test_trace_hook_scope() {
+    assert!(trace::set_hook(None).is_none());
+    trace_decode(r"\n", &DecodeOptions::new());
+    assert!(trace::set_hook(None).is_none());
+
+    EVENTS.with(|events| events.borrow_mut().clear());
+    decode_with(r"\n", &DecodeOptions::new()).unwrap();
+    assert!(EVENTS.with(|events| events.borrow().is_empty()));
+}
+
+#[test]
+fn test_trace_lossy_replacement() {
+    EVENTS.with(|events| events.borrow_mut().clear());
+    let decoded = trace::with_hook(record, || {
+        decode_lossy_with(r"a\qb", &DecodeOptions::new(), "?")
+    });
+    assert_eq!(decoded, "a?b");
+    let events = EVENTS.with(|events| events.take());
+    assert_eq!(events.len(), 4);
+    assert!(
+        events[1].starts_with("Error { range: 1..3, error: DecodeError { kind: UnknownEscape('q')")
+    );
+    assert_eq!(
+        events[2],
+        format!("{:?}", TraceEvent::Fallback(Fallback::ErrorReplaced))
+    );
+    assert_eq!(
+        events[3],
+        format!("{:?}", TraceEvent::Finished { output_len: 3 })
+    );
+}
+
+#[test]
+fn test_trace_surrogate_replacement() {
+    EVENTS.with(|events| events.borrow_mut().clear());
+    let options = DecodeOptions::new().replace_lone_surrogates(true);
+    let decoded = trace::with_hook(record, || decode_with(r"\u{D83D}!", &options)).unwrap();
+    assert_eq!(decoded, "\u{FFFD}!");
+    let events = EVENTS.with(|events| events.take());
+    assert!(events.contains(&format!(
+        "{:?}",
+        TraceEvent::Fallback(Fallback::SurrogateReplaced(0xD83D))
+    )));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "transliteration")]
+
+use unicode_escape::transliteration::transliterate;
+use unicode_escape::{encode_with, EncodeOptions};
+
+#[test]
+fn test_transliterate() {
+    let cases = vec![
+        ('\u{E9}', Some("e")),
+        ('\u{C6}', Some("AE")),
+        ('\u{DF}', Some("ss")),
+        ('\u{141}', Some("L")),
+        ('\u{416}', Some("Zh")),
+        ('\u{3A9}', Some("O")),
+        ('\u{FB01}', Some("fi")),
+        ('\u{2460}', Some("1")),
+        ('\u{201C}', Some("\"")),
+        ('\u{20AC}', Some("EUR")),
+        ('\u{4E00}', None),
+        ('\u{1F600}', None),
+        ('a', None),
+    ];
+    for (c, expected) in cases {
+        assert_eq!(transliterate(c), expected, "{:?}", c);
+    }
+}
+
+#[test]
+fn test_encode_transliterated() {
+    let options = EncodeOptions::new().transliterate(true);
+    let cases = vec![
+        ("M\u{FC}nchen", "Munchen"),
+        (
+            "\u{201C}Stra\u{DF}e\u{201D}\u{A0}\u{2013} \u{41C}\u{43E}\u{441}\u{43A}\u{432}\u{430}",
+            r#"\"Strasse\" - Moskva"#,
+        ),
+        (
+            "\u{65E5}\u{672C}\t\u{1F600}",
+            r"\u{65E5}\u{672C}\t\u{1F600}",
+        ),
+    ];
+    for (input, expected) in cases {
+        let encoded = encode_with(input, &options);
+        assert_eq!(encoded, expected);
+        assert!(encoded.is_ascii());
+    }
+}
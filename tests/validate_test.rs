@@ -0,0 +1,49 @@
+use unicode_escape::{
+    decode_spanned, validate, validate_all, validate_spanned, DecodeOptions, ErrorKind,
+};
+
+#[test]
+fn test_validate() {
+    assert!(validate(r"\x02 65480 LGM\r\n").is_ok());
+    assert!(validate(r"\u{21B5} plain").is_ok());
+    assert_eq!(
+        validate(r"\x02 \65480 LGM\r\n").unwrap_err().kind(),
+        ErrorKind::UnknownEscape('6')
+    );
+    assert_eq!(
+        validate(r"\xG0").unwrap_err().kind(),
+        ErrorKind::InvalidHexDigit('G')
+    );
+    assert_eq!(
+        validate(r"\u{110000}").unwrap_err().kind(),
+        ErrorKind::CodePointOutOfRange(0x110000)
+    );
+}
+
+#[test]
+fn test_validate_spanned() {
+    assert!(validate_spanned(r"\x02 65480 LGM\r\n").is_ok());
+    let error = validate_spanned(r"é \x41 \xG0").unwrap_err();
+    assert_eq!(error.error.kind(), ErrorKind::InvalidHexDigit('G'));
+    assert_eq!(error.span, 8..10);
+    assert_eq!(error.char_index, 7);
+    let expected = decode_spanned(r"é \x41 \xG0", &DecodeOptions::new()).unwrap_err();
+    assert_eq!(error.span, expected.span);
+}
+
+#[test]
+fn test_validate_all() {
+    assert!(validate_all(r"\t\r\n Hello \0").is_empty());
+
+    let errors = validate_all(r"\q ok \xGG \u{ZZZZ}\");
+    let ranges: Vec<_> = errors.iter().map(|error| error.span().unwrap()).collect();
+    assert_eq!(ranges, vec![0..2, 6..8, 11..19, 19..20]);
+    assert_eq!(errors[0].kind(), ErrorKind::UnknownEscape('q'));
+    assert_eq!(errors[1].kind(), ErrorKind::InvalidHexDigit('G'));
+    assert_eq!(errors[2].kind(), ErrorKind::InvalidHexDigit('Z'));
+    assert_eq!(errors[3].kind(), ErrorKind::UnexpectedEof);
+    // The spans cover whole escape sequences
+    let errors = validate_all(r"\u{110000} \x4");
+    let ranges: Vec<_> = errors.iter().map(|error| error.span().unwrap()).collect();
+    assert_eq!(ranges, vec![0..10, 11..14]);
+}